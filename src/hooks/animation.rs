@@ -0,0 +1,336 @@
+//! Hooks for animating values
+
+use crate::hooks::raf::use_raf_loop;
+use yew::prelude::*;
+
+/// An easing function, mapping the linear progress of an animation.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Easing {
+    /// A constant speed.
+    Linear,
+    /// Starting slow, speeding up.
+    EaseIn,
+    /// Starting fast, slowing down.
+    EaseOut,
+    /// Starting and ending slow.
+    #[default]
+    EaseInOut,
+    /// A custom function, mapping the progress from `0..=1` to the eased progress.
+    Custom(fn(f64) -> f64),
+}
+
+impl Easing {
+    /// Apply the easing function to a progress in the range `0..=1`.
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = t.clamp(0f64, 1f64);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1f64 - (1f64 - t).powi(3),
+            Self::EaseInOut => match t < 0.5 {
+                true => 4f64 * t * t * t,
+                false => 1f64 - (-2f64 * t + 2f64).powi(3) / 2f64,
+            },
+            Self::Custom(f) => f(t),
+        }
+    }
+}
+
+/// Options for [`use_tween`].
+#[derive(Clone, Copy, Debug)]
+pub struct UseTweenOptions {
+    /// Duration of the animation, in milliseconds.
+    pub duration: u32,
+    /// The easing function, applied to the progress.
+    pub easing: Easing,
+}
+
+impl Default for UseTweenOptions {
+    fn default() -> Self {
+        Self {
+            duration: 300,
+            easing: Easing::default(),
+        }
+    }
+}
+
+struct Tween {
+    from: f64,
+    to: f64,
+    current: f64,
+    elapsed: f64,
+}
+
+/// Animate a value towards a target.
+///
+/// Returns a value, which, whenever the target changes, animates from its current value towards
+/// the new target, using the provided duration and easing. The animation is driven by animation
+/// frames and only runs while the value is moving.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   progress: f64,
+/// }
+///
+/// #[function_component(Progress)]
+/// fn progress(props: &Props) -> Html {
+///   let width = use_tween(props.progress, UseTweenOptions {
+///     duration: 500,
+///     easing: Easing::EaseOut,
+///   });
+///
+///   html!(<div style={format!("width: {width}%")}></div>)
+/// }
+/// ```
+#[hook]
+pub fn use_tween(target: f64, options: UseTweenOptions) -> f64 {
+    let value = use_state_eq(|| target);
+    let running = use_state_eq(|| false);
+    let tween = use_mut_ref(|| Tween {
+        from: target,
+        to: target,
+        current: target,
+        elapsed: 0f64,
+    });
+
+    {
+        let tween = tween.clone();
+        let running = running.clone();
+        use_effect_with(target, move |target| {
+            let mut tween = tween.borrow_mut();
+            if tween.to != *target {
+                tween.from = tween.current;
+                tween.to = *target;
+                tween.elapsed = 0f64;
+                running.set(true);
+            }
+        });
+    }
+
+    {
+        let value = value.clone();
+        let active = *running;
+        use_raf_loop(
+            move |delta| {
+                let mut tween = tween.borrow_mut();
+                tween.elapsed += delta;
+                let progress = match options.duration {
+                    0 => 1f64,
+                    duration => (tween.elapsed / duration as f64).min(1f64),
+                };
+                tween.current =
+                    tween.from + (tween.to - tween.from) * options.easing.apply(progress);
+                value.set(tween.current);
+                if progress >= 1f64 {
+                    running.set(false);
+                }
+            },
+            active,
+        );
+    }
+
+    *value
+}
+
+/// Configuration of a spring, for [`use_spring`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpringConfig {
+    /// The force pulling the spring towards the target, higher values move faster.
+    pub stiffness: f64,
+    /// The friction slowing the spring down, lower values oscillate more.
+    pub damping: f64,
+    /// The mass of the moving object, higher values move slower.
+    pub mass: f64,
+    /// Distance and velocity, below which the spring is considered at rest.
+    pub precision: f64,
+}
+
+impl SpringConfig {
+    /// A slow spring, with little oscillation.
+    pub const GENTLE: Self = Self::new(120f64, 14f64);
+    /// A fast spring, oscillating around the target.
+    pub const WOBBLY: Self = Self::new(180f64, 12f64);
+    /// A fast spring, with little oscillation.
+    pub const STIFF: Self = Self::new(210f64, 20f64);
+
+    /// Create a new configuration, using a mass of `1` and the default precision.
+    pub const fn new(stiffness: f64, damping: f64) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass: 1f64,
+            precision: 0.01,
+        }
+    }
+}
+
+impl Default for SpringConfig {
+    fn default() -> Self {
+        Self::new(170f64, 26f64)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Spring {
+    position: f64,
+    velocity: f64,
+}
+
+/// The maximum time step of a single integration, in seconds
+const MAX_STEP: f64 = 1f64 / 120f64;
+
+impl Spring {
+    /// Advance the spring by `delta` milliseconds, returning `true` if the spring came to rest.
+    fn step(&mut self, target: f64, delta: f64, config: &SpringConfig) -> bool {
+        let mut remaining = delta / 1000f64;
+        while remaining > 0f64 {
+            let dt = remaining.min(MAX_STEP);
+            remaining -= dt;
+
+            let force =
+                -config.stiffness * (self.position - target) - config.damping * self.velocity;
+            self.velocity += force / config.mass * dt;
+            self.position += self.velocity * dt;
+        }
+
+        let at_rest = (self.position - target).abs() < config.precision
+            && self.velocity.abs() < config.precision;
+        if at_rest {
+            self.position = target;
+            self.velocity = 0f64;
+        }
+        at_rest
+    }
+}
+
+/// Animate a value towards a target, using a spring.
+///
+/// Returns a value, which, whenever the target changes, moves towards the new target following
+/// the physics of a spring. Compared to [`use_tween`], the animation has no fixed duration, and
+/// changing the target during an animation keeps the current velocity, which results in smooth
+/// transitions (e.g. when releasing a dragged element).
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Drawer)]
+/// fn drawer() -> Html {
+///   let open = use_state_eq(|| false);
+///   let offset = use_spring(if *open { 0f64 } else { -300f64 }, SpringConfig::WOBBLY);
+///
+///   let onclick = use_callback(open.clone(), |_, open| open.set(!**open));
+///
+///   html!(
+///     <div {onclick} style={format!("transform: translateX({offset}px)")}>
+///       {"Menu"}
+///     </div>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_spring(target: f64, config: SpringConfig) -> f64 {
+    let value = use_state_eq(|| target);
+    let running = use_state_eq(|| false);
+    let spring = use_mut_ref(|| Spring {
+        position: target,
+        velocity: 0f64,
+    });
+
+    {
+        let spring = spring.clone();
+        let running = running.clone();
+        use_effect_with(target, move |target| {
+            if spring.borrow().position != *target {
+                running.set(true);
+            }
+        });
+    }
+
+    {
+        let value = value.clone();
+        let active = *running;
+        use_raf_loop(
+            move |delta| {
+                let mut spring = spring.borrow_mut();
+                let at_rest = spring.step(target, delta, &config);
+                value.set(spring.position);
+                if at_rest {
+                    running.set(false);
+                }
+            },
+            active,
+        );
+    }
+
+    *value
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_easing_bounds() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0f64), 0f64);
+            assert_eq!(easing.apply(1f64), 1f64);
+            // out of range values get clamped
+            assert_eq!(easing.apply(2f64), 1f64);
+        }
+    }
+
+    #[test]
+    fn test_ease_in_out() {
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert!(Easing::EaseInOut.apply(0.25) < 0.25);
+        assert!(Easing::EaseInOut.apply(0.75) > 0.75);
+    }
+
+    #[test]
+    fn test_spring_settles() {
+        let config = SpringConfig::default();
+        let mut spring = Spring {
+            position: 0f64,
+            velocity: 0f64,
+        };
+
+        let mut frames = 0;
+        while !spring.step(100f64, 16f64, &config) {
+            frames += 1;
+            assert!(frames < 1_000, "spring must settle");
+        }
+
+        assert_eq!(spring.position, 100f64);
+        assert_eq!(spring.velocity, 0f64);
+    }
+
+    #[test]
+    fn test_spring_overshoots() {
+        let config = SpringConfig::WOBBLY;
+        let mut spring = Spring {
+            position: 0f64,
+            velocity: 0f64,
+        };
+
+        let mut max = 0f64;
+        while !spring.step(100f64, 16f64, &config) {
+            max = max.max(spring.position);
+        }
+
+        assert!(max > 100f64);
+    }
+}
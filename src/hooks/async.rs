@@ -14,7 +14,7 @@ use std::{
 use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
 
-use yew_hooks::{use_mount, use_mut_latest};
+use crate::hooks::use_latest;
 
 static ID: AtomicU64 = AtomicU64::new(0);
 
@@ -80,19 +80,14 @@ impl<T, E> Deref for AsyncStateVersion<T, E> {
 }
 
 /// State for an async future.
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Default, PartialEq, Eq)]
 pub enum UseAsyncState<T, E> {
+    #[default]
     Pending,
     Processing,
     Ready(Result<T, E>),
 }
 
-impl<T, E> Default for UseAsyncState<T, E> {
-    fn default() -> Self {
-        Self::Pending
-    }
-}
-
 impl<T, E> UseAsyncState<T, E> {
     /// Checks if the task is processing
     ///
@@ -125,12 +120,20 @@ impl<T, E> UseAsyncState<T, E> {
 }
 
 /// State handle for the [`use_async`] hook.
-#[derive(Clone)]
 pub struct UseAsyncHandle<T, E> {
     inner: UseReducerHandle<AsyncStateVersion<T, E>>,
     run: Rc<dyn Fn()>,
 }
 
+impl<T, E> Clone for UseAsyncHandle<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            run: self.run.clone(),
+        }
+    }
+}
+
 impl<T, E> UseAsyncHandle<T, E> {
     /// Start to resolve the async future to a final value.
     pub fn run(&self) {
@@ -262,14 +265,13 @@ where
         state: UseAsyncState::default(),
         version: 0,
     });
-    let future_ref = use_mut_latest(Some(future));
+    let future_ref = use_latest(Some(future));
 
     let run = {
         let inner = inner.clone();
         Rc::new(move || {
             let inner = inner.clone();
-            let future_ref = future_ref.current();
-            let future = (*future_ref.borrow_mut()).take();
+            let future = future_ref.borrow_mut().take();
 
             if let Some(future) = future {
                 run_task(future, inner);
@@ -279,7 +281,7 @@ where
 
     {
         let run = run.clone();
-        use_mount(move || {
+        use_effect_with((), move |()| {
             if options.auto {
                 run();
             }
@@ -397,13 +399,12 @@ where
         version: 0,
     });
 
-    let factory_ref = use_mut_latest(Some(f));
+    let factory_ref = use_latest(Some(f));
 
     {
         let inner = inner.clone();
         use_effect_with(deps, move |deps| {
-            let factory_ref = factory_ref.current();
-            let factory = (*factory_ref.borrow_mut()).take();
+            let factory = factory_ref.borrow_mut().take();
 
             if let Some(factory) = factory {
                 run_task(factory(deps), inner.clone())
@@ -0,0 +1,273 @@
+//! Hooks for global, shared state
+//!
+//! An [`Atom`] is a piece of global state, defined as a `static`, with a key and a default value.
+//! Any component can read and write it using [`use_atom`]. When an atom changes, only the
+//! components using it re-render.
+
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+    ops::Deref,
+    rc::Rc,
+};
+use yew::prelude::*;
+
+/// A definition of global state.
+///
+/// ```rust
+/// use yew_more_hooks::prelude::*;
+///
+/// static COUNT: Atom<u32> = Atom::new("count", || 0);
+/// ```
+pub struct Atom<T> {
+    key: &'static str,
+    default: fn() -> T,
+}
+
+impl<T> Atom<T> {
+    /// Define an atom, identified by its key, which must be unique for atoms of the same type.
+    pub const fn new(key: &'static str, default: fn() -> T) -> Self {
+        Self { key, default }
+    }
+}
+
+impl<T> Atom<T>
+where
+    T: PartialEq + 'static,
+{
+    fn cell(&self) -> Rc<AtomCell<T>> {
+        ATOMS.with(|atoms| {
+            let cell = atoms
+                .borrow_mut()
+                .entry((self.key, TypeId::of::<T>()))
+                .or_insert_with(|| {
+                    Rc::new(AtomCell {
+                        value: RefCell::new(Rc::new((self.default)())),
+                        subscribers: Default::default(),
+                    })
+                })
+                .clone();
+            cell.downcast()
+                .expect("atoms are stored with the type they are keyed with")
+        })
+    }
+
+    /// Get the current value, also outside of components.
+    pub fn get(&self) -> Rc<T> {
+        self.cell().get()
+    }
+
+    /// Set a new value, also outside of components, re-rendering the components using it.
+    pub fn set(&self, value: T) {
+        self.cell().set(value);
+    }
+}
+
+/// The key of an atom, along with the type of its value.
+type AtomKey = (&'static str, TypeId);
+
+thread_local! {
+    static ATOMS: RefCell<HashMap<AtomKey, Rc<dyn Any>>> = Default::default();
+}
+
+type Subscriber = (usize, Rc<dyn Fn()>);
+
+struct AtomCell<T> {
+    value: RefCell<Rc<T>>,
+    subscribers: RefCell<(usize, Vec<Subscriber>)>,
+}
+
+impl<T> AtomCell<T>
+where
+    T: PartialEq,
+{
+    fn get(&self) -> Rc<T> {
+        self.value.borrow().clone()
+    }
+
+    fn set(&self, value: T) {
+        if **self.value.borrow() == value {
+            return;
+        }
+        *self.value.borrow_mut() = Rc::new(value);
+
+        // collect first, so that subscribers can unsubscribe when being notified
+        let subscribers = self
+            .subscribers
+            .borrow()
+            .1
+            .iter()
+            .map(|(_, subscriber)| subscriber.clone())
+            .collect::<Vec<_>>();
+        for subscriber in subscribers {
+            subscriber();
+        }
+    }
+
+    fn subscribe(&self, subscriber: Rc<dyn Fn()>) -> usize {
+        let mut subscribers = self.subscribers.borrow_mut();
+        let id = subscribers.0;
+        subscribers.0 += 1;
+        subscribers.1.push((id, subscriber));
+        id
+    }
+
+    fn unsubscribe(&self, id: usize) {
+        self.subscribers
+            .borrow_mut()
+            .1
+            .retain(|(subscriber, _)| *subscriber != id);
+    }
+}
+
+/// State handle for the [`use_atom`] hook.
+pub struct UseAtomHandle<T>
+where
+    T: PartialEq + 'static,
+{
+    value: Rc<T>,
+    cell: Rc<AtomCell<T>>,
+}
+
+impl<T> UseAtomHandle<T>
+where
+    T: PartialEq + 'static,
+{
+    /// Set a new value, re-rendering the components using the atom.
+    pub fn set(&self, value: T) {
+        self.cell.set(value);
+    }
+
+    /// Update the value, based on the latest value.
+    pub fn update<F>(&self, f: F)
+    where
+        F: FnOnce(&T) -> T,
+    {
+        let value = f(&self.cell.get());
+        self.cell.set(value);
+    }
+}
+
+impl<T> Clone for UseAtomHandle<T>
+where
+    T: PartialEq + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            value: self.value.clone(),
+            cell: self.cell.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseAtomHandle<T>
+where
+    T: PartialEq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.value, &other.value) && Rc::ptr_eq(&self.cell, &other.cell)
+    }
+}
+
+impl<T> Deref for UseAtomHandle<T>
+where
+    T: PartialEq + 'static,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// Read and write global state, defined by an [`Atom`].
+///
+/// The handle dereferences to the value of the atom. Setting a value re-renders all components
+/// using the atom, but no other components. The value is initialized from the default of the atom
+/// when it is first used.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// static THEME: Atom<String> = Atom::new("theme", || "light".to_string());
+///
+/// #[function_component(ThemeSwitch)]
+/// fn theme_switch() -> Html {
+///   let theme = use_atom(&THEME);
+///   let onclick = use_callback(theme.clone(), |_, theme| {
+///     theme.update(|theme| match theme.as_str() {
+///       "light" => "dark".to_string(),
+///       _ => "light".to_string(),
+///     });
+///   });
+///   html!(<button {onclick}>{ format!("Theme: {}", *theme) }</button>)
+/// }
+///
+/// #[function_component(Page)]
+/// fn page() -> Html {
+///   let theme = use_atom(&THEME);
+///   html!(<main class={(*theme).clone()}></main>)
+/// }
+/// ```
+#[hook]
+pub fn use_atom<T>(atom: &'static Atom<T>) -> UseAtomHandle<T>
+where
+    T: PartialEq + 'static,
+{
+    // atoms are statics, so their address identifies them
+    let address = atom as *const Atom<T> as usize;
+    let cell = use_memo(address, |_| atom.cell());
+    let cell = (*cell).clone();
+    let force_update = use_force_update();
+
+    let value = cell.get();
+
+    {
+        let cell = cell.clone();
+        let rendered = value.clone();
+        use_effect_with(address, move |_| {
+            // catch up with changes between rendering and subscribing
+            if !Rc::ptr_eq(&rendered, &cell.get()) {
+                force_update.force_update();
+            }
+            let id = cell.subscribe(Rc::new(move || force_update.force_update()));
+            move || cell.unsubscribe(id)
+        });
+    }
+
+    UseAtomHandle { value, cell }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    static COUNT: Atom<u32> = Atom::new("count", || 1);
+
+    #[test]
+    fn test_notify() {
+        assert_eq!(*COUNT.get(), 1);
+
+        let cell = COUNT.cell();
+        let notified = Rc::new(Cell::new(0));
+        let id = {
+            let notified = notified.clone();
+            cell.subscribe(Rc::new(move || notified.set(notified.get() + 1)))
+        };
+
+        COUNT.set(2);
+        // unchanged values don't notify
+        COUNT.set(2);
+        assert_eq!(notified.get(), 1);
+        assert_eq!(*cell.get(), 2);
+
+        cell.unsubscribe(id);
+        COUNT.set(3);
+        assert_eq!(notified.get(), 1);
+    }
+}
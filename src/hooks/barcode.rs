@@ -0,0 +1,295 @@
+//! Hooks for detecting barcodes in a video
+
+use crate::hooks::use_latest;
+use gloo_timers::callback::Interval;
+use std::{
+    cell::Cell,
+    fmt::{Display, Formatter},
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use yew::prelude::*;
+
+/// An error detecting barcodes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BarcodeDetectorError {
+    /// The Barcode Detection API is not supported by the browser.
+    Unsupported,
+    /// Detecting barcodes failed.
+    Failed(String),
+}
+
+impl Display for BarcodeDetectorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str("Barcode Detection API is not supported"),
+            Self::Failed(msg) => write!(f, "Failed to detect barcodes: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for BarcodeDetectorError {}
+
+impl From<JsValue> for BarcodeDetectorError {
+    fn from(err: JsValue) -> Self {
+        match err.dyn_ref::<web_sys::DomException>() {
+            Some(err) => Self::Failed(err.message()),
+            None => Self::Failed(format!("{err:?}")),
+        }
+    }
+}
+
+/// A barcode detected in a frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DetectedBarcode {
+    /// The decoded value.
+    pub raw_value: String,
+    /// The format, like `qr_code` or `ean_13`.
+    pub format: String,
+    /// The bounding box in the frame, as `(x, y, width, height)`.
+    pub bounding_box: (f64, f64, f64, f64),
+}
+
+impl From<JsValue> for DetectedBarcode {
+    fn from(value: JsValue) -> Self {
+        let get = |target: &JsValue, key: &str| js_sys::Reflect::get(target, &key.into()).ok();
+        let number = |target: &JsValue, key| {
+            get(target, key)
+                .and_then(|value| value.as_f64())
+                .unwrap_or_default()
+        };
+        let bounding_box = get(&value, "boundingBox").unwrap_or_default();
+        Self {
+            raw_value: get(&value, "rawValue")
+                .and_then(|value| value.as_string())
+                .unwrap_or_default(),
+            format: get(&value, "format")
+                .and_then(|value| value.as_string())
+                .unwrap_or_default(),
+            bounding_box: (
+                number(&bounding_box, "x"),
+                number(&bounding_box, "y"),
+                number(&bounding_box, "width"),
+                number(&bounding_box, "height"),
+            ),
+        }
+    }
+}
+
+/// Options for [`use_barcode_detector_with_options`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UseBarcodeDetectorOptions {
+    /// The formats to detect, all formats supported by the browser if empty.
+    pub formats: Vec<String>,
+    /// The interval between scanning frames, in milliseconds.
+    pub interval: u32,
+    /// Scan frames of the video.
+    pub enabled: bool,
+}
+
+impl Default for UseBarcodeDetectorOptions {
+    fn default() -> Self {
+        Self {
+            formats: vec![],
+            interval: 250,
+            enabled: true,
+        }
+    }
+}
+
+fn barcode_detector_class() -> Option<js_sys::Function> {
+    js_sys::Reflect::get(&gloo_utils::window(), &"BarcodeDetector".into())
+        .ok()?
+        .dyn_into()
+        .ok()
+}
+
+fn create_detector(formats: &[String]) -> Result<JsValue, BarcodeDetectorError> {
+    let class = barcode_detector_class().ok_or(BarcodeDetectorError::Unsupported)?;
+    let args = js_sys::Array::new();
+    if !formats.is_empty() {
+        let options = js_sys::Object::new();
+        let formats = formats
+            .iter()
+            .map(|format| JsValue::from_str(format))
+            .collect::<js_sys::Array>();
+        js_sys::Reflect::set(&options, &"formats".into(), &formats)?;
+        args.push(&options);
+    }
+    Ok(js_sys::Reflect::construct(&class, &args)?)
+}
+
+fn detect(
+    detector: &JsValue,
+    video: &web_sys::HtmlVideoElement,
+) -> Result<js_sys::Promise, BarcodeDetectorError> {
+    let detect: js_sys::Function = js_sys::Reflect::get(detector, &"detect".into())?.dyn_into()?;
+    Ok(detect.call1(detector, video)?.dyn_into()?)
+}
+
+/// State handle for the [`use_barcode_detector`] hook.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseBarcodeDetectorHandle {
+    supported: bool,
+    error: UseStateHandle<Option<BarcodeDetectorError>>,
+}
+
+impl UseBarcodeDetectorHandle {
+    /// Check if the browser supports the Barcode Detection API.
+    pub fn is_supported(&self) -> bool {
+        self.supported
+    }
+
+    /// The error of the last scan, if it failed.
+    pub fn error(&self) -> Option<&BarcodeDetectorError> {
+        self.error.as_ref()
+    }
+}
+
+/// Detect barcodes in a video.
+///
+/// See [`use_barcode_detector_with_options`] for more information.
+#[hook]
+pub fn use_barcode_detector<F>(video: NodeRef, ondetect: F) -> UseBarcodeDetectorHandle
+where
+    F: Fn(Vec<DetectedBarcode>) + 'static,
+{
+    use_barcode_detector_with_options(video, ondetect, UseBarcodeDetectorOptions::default())
+}
+
+/// Detect barcodes in a video, with options.
+///
+/// Periodically scans the current frame of the referenced `<video>` element using the
+/// [Barcode Detection API](https://developer.mozilla.org/en-US/docs/Web/API/Barcode_Detection_API),
+/// and calls the latest `ondetect` function with the barcodes found. Frames without any barcode
+/// are not reported. A frame is only scanned once the previous scan is finished.
+///
+/// Scanning stops when the component is unmounted, or disabled using the options.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(QrLogin)]
+/// fn qr_login() -> Html {
+///   let video = use_node_ref();
+///   let camera = use_user_media(UserMediaConstraints::video());
+///   let token = use_state_eq(|| None::<String>);
+///
+///   {
+///     let video = video.clone();
+///     use_effect_with(camera.data().cloned(), move |stream| {
+///       if let Some(video) = video.cast::<web_sys::HtmlVideoElement>() {
+///         video.set_src_object(stream.as_ref());
+///       }
+///     });
+///   }
+///
+///   let options = UseBarcodeDetectorOptions {
+///     formats: vec!["qr_code".into()],
+///     enabled: token.is_none(),
+///     ..Default::default()
+///   };
+///   let detector = {
+///     let token = token.clone();
+///     use_barcode_detector_with_options(
+///       video.clone(),
+///       move |codes| token.set(codes.into_iter().next().map(|code| code.raw_value)),
+///       options,
+///     )
+///   };
+///
+///   match (&*token, detector.is_supported()) {
+///     (Some(token), _) => html!(<p>{ format!("Logging in with {token}") }</p>),
+///     (None, true) => html!(<video ref={video} autoplay=true muted=true />),
+///     (None, false) => html!(<p>{"Scanning QR codes is not supported"}</p>),
+///   }
+/// }
+/// ```
+#[hook]
+pub fn use_barcode_detector_with_options<F>(
+    video: NodeRef,
+    ondetect: F,
+    options: UseBarcodeDetectorOptions,
+) -> UseBarcodeDetectorHandle
+where
+    F: Fn(Vec<DetectedBarcode>) + 'static,
+{
+    let ondetect = use_latest(ondetect);
+    let error = use_state_eq(|| None::<BarcodeDetectorError>);
+    let supported = *use_memo((), |()| barcode_detector_class().is_some());
+
+    {
+        let error = error.clone();
+        use_effect_with((video, options), move |(video, options)| {
+            // cleared on cleanup, ignoring scans still running
+            let active = Rc::new(Cell::new(true));
+
+            let interval = match options.enabled.then(|| create_detector(&options.formats)) {
+                Some(Ok(detector)) => {
+                    let video = video.clone();
+                    let active = active.clone();
+                    let busy = Rc::new(Cell::new(false));
+                    Some(Interval::new(options.interval, move || {
+                        let Some(video) = video.cast::<web_sys::HtmlVideoElement>() else {
+                            return;
+                        };
+                        if busy.get()
+                            || video.ready_state() < web_sys::HtmlMediaElement::HAVE_CURRENT_DATA
+                        {
+                            return;
+                        }
+                        let promise = match detect(&detector, &video) {
+                            Ok(promise) => promise,
+                            Err(err) => {
+                                error.set(Some(err));
+                                return;
+                            }
+                        };
+
+                        busy.set(true);
+                        let busy = busy.clone();
+                        let active = active.clone();
+                        let error = error.clone();
+                        let ondetect = ondetect.clone();
+                        spawn_local(async move {
+                            let result = JsFuture::from(promise).await;
+                            busy.set(false);
+                            if !active.get() {
+                                return;
+                            }
+                            match result {
+                                Ok(codes) => {
+                                    error.set(None);
+                                    let codes = js_sys::Array::from(&codes)
+                                        .iter()
+                                        .map(DetectedBarcode::from)
+                                        .collect::<Vec<_>>();
+                                    if !codes.is_empty() {
+                                        (ondetect.borrow())(codes);
+                                    }
+                                }
+                                Err(err) => error.set(Some(err.into())),
+                            }
+                        });
+                    }))
+                }
+                Some(Err(err)) => {
+                    error.set(Some(err));
+                    None
+                }
+                None => None,
+            };
+
+            move || {
+                active.set(false);
+                drop(interval);
+            }
+        });
+    }
+
+    UseBarcodeDetectorHandle { supported, error }
+}
@@ -0,0 +1,123 @@
+//! Hooks for the battery status of the device
+
+use gloo_events::EventListener;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use yew::prelude::*;
+
+/// The status of the battery.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BatteryStatus {
+    /// The charge level, in the range of `0..=1`.
+    pub level: f64,
+    /// Whether the battery is being charged.
+    pub charging: bool,
+    /// Seconds until the battery is fully charged, if known.
+    pub charging_time: Option<f64>,
+    /// Seconds until the battery is empty, if known.
+    pub discharging_time: Option<f64>,
+}
+
+fn finite(value: f64) -> Option<f64> {
+    value.is_finite().then_some(value)
+}
+
+impl From<&web_sys::BatteryManager> for BatteryStatus {
+    fn from(battery: &web_sys::BatteryManager) -> Self {
+        Self {
+            level: battery.level(),
+            charging: battery.charging(),
+            charging_time: finite(battery.charging_time()),
+            discharging_time: finite(battery.discharging_time()),
+        }
+    }
+}
+
+/// Request the battery manager, if supported.
+fn get_battery() -> Option<js_sys::Promise> {
+    let navigator = gloo_utils::window().navigator();
+    let get_battery = js_sys::Reflect::get(&navigator, &"getBattery".into())
+        .ok()?
+        .dyn_into::<js_sys::Function>()
+        .ok()?;
+    get_battery.call0(&navigator).ok()?.dyn_into().ok()
+}
+
+/// Track the status of the battery.
+///
+/// Uses the [Battery Status API](https://developer.mozilla.org/en-US/docs/Web/API/Battery_Status_API)
+/// to report the level and charging state of the battery, updating on every change. Returns `None`
+/// until the status is known, and on browsers not supporting the API.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   match use_battery() {
+///     Some(battery) => html!(format!(
+///       "{:.0}%{}",
+///       battery.level * 100f64,
+///       if battery.charging { ", charging" } else { "" }
+///     )),
+///     None => html!(),
+///   }
+/// }
+/// ```
+#[hook]
+pub fn use_battery() -> Option<BatteryStatus> {
+    let state = use_state_eq(|| None::<BatteryStatus>);
+
+    {
+        let state = state.clone();
+        use_effect_with((), move |()| {
+            let listeners = Rc::new(RefCell::new(Vec::<EventListener>::new()));
+            let cancelled = Rc::new(Cell::new(false));
+
+            if let Some(request) = get_battery() {
+                let listeners = listeners.clone();
+                let cancelled = cancelled.clone();
+                spawn_local(async move {
+                    let Ok(battery) = JsFuture::from(request).await else {
+                        return;
+                    };
+                    if cancelled.get() {
+                        return;
+                    }
+                    let battery = battery.unchecked_into::<web_sys::BatteryManager>();
+                    state.set(Some((&battery).into()));
+
+                    *listeners.borrow_mut() = [
+                        "chargingchange",
+                        "chargingtimechange",
+                        "dischargingtimechange",
+                        "levelchange",
+                    ]
+                    .into_iter()
+                    .map(|event_type| {
+                        let state = state.clone();
+                        let target = battery.clone();
+                        EventListener::new(&battery, event_type, move |_| {
+                            state.set(Some((&target).into()));
+                        })
+                    })
+                    .collect();
+                });
+            }
+
+            move || {
+                cancelled.set(true);
+                listeners.borrow_mut().clear();
+            }
+        });
+    }
+
+    *state
+}
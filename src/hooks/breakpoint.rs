@@ -90,7 +90,10 @@ pub trait Breakpoint: PartialEq + Sized {
 /// }
 /// ```
 #[hook]
-pub fn use_breakpoint<T: Breakpoint + 'static>() -> UseStateHandle<T> {
+pub fn use_breakpoint<T>() -> UseStateHandle<T>
+where
+    T: Breakpoint + 'static,
+{
     let state: UseStateHandle<T> = use_state_eq(Breakpoint::current);
     {
         let state = state.clone();
@@ -0,0 +1,160 @@
+//! Hooks for the caret position of text inputs
+
+use crate::hooks::use_event_listener;
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+/// The selected range of a text input, in UTF-16 code units.
+///
+/// Without a selection, `start` and `end` are the same: the position of the caret.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CaretPosition {
+    /// The start of the selection.
+    pub start: u32,
+    /// The end of the selection, exclusive.
+    pub end: u32,
+}
+
+impl CaretPosition {
+    /// A caret, without a selection.
+    pub const fn at(position: u32) -> Self {
+        Self {
+            start: position,
+            end: position,
+        }
+    }
+
+    /// Check if there is no selected text.
+    pub fn is_collapsed(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+fn get(element: &web_sys::Element) -> Option<CaretPosition> {
+    let (start, end) = if let Some(input) = element.dyn_ref::<web_sys::HtmlInputElement>() {
+        (input.selection_start(), input.selection_end())
+    } else if let Some(textarea) = element.dyn_ref::<web_sys::HtmlTextAreaElement>() {
+        (textarea.selection_start(), textarea.selection_end())
+    } else {
+        return None;
+    };
+
+    // inputs like `number` don't support selections
+    match (start.ok().flatten(), end.ok().flatten()) {
+        (Some(start), Some(end)) => Some(CaretPosition { start, end }),
+        _ => None,
+    }
+}
+
+fn set(element: &web_sys::Element, position: CaretPosition) {
+    if let Some(input) = element.dyn_ref::<web_sys::HtmlInputElement>() {
+        let _ = input.set_selection_range(position.start, position.end);
+    } else if let Some(textarea) = element.dyn_ref::<web_sys::HtmlTextAreaElement>() {
+        let _ = textarea.set_selection_range(position.start, position.end);
+    }
+}
+
+/// Handle for the [`use_caret_position`] hook.
+#[derive(Clone)]
+pub struct UseCaretPositionHandle {
+    node: NodeRef,
+    saved: Rc<RefCell<Option<CaretPosition>>>,
+}
+
+impl UseCaretPositionHandle {
+    /// Get the current position of the caret.
+    ///
+    /// Returns `None` if the element isn't rendered, or doesn't support selections.
+    pub fn get(&self) -> Option<CaretPosition> {
+        self.node
+            .cast::<web_sys::Element>()
+            .and_then(|element| get(&element))
+    }
+
+    /// Set the position of the caret, or select a range of text.
+    pub fn set(&self, position: CaretPosition) {
+        *self.saved.borrow_mut() = Some(position);
+        if let Some(element) = self.node.cast::<web_sys::Element>() {
+            set(&element, position);
+        }
+    }
+}
+
+impl PartialEq for UseCaretPositionHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node && Rc::ptr_eq(&self.saved, &other.saved)
+    }
+}
+
+/// Access and preserve the caret position of an `<input>` or `<textarea>`.
+///
+/// Setting the value of a controlled input, e.g. when transforming what the user typed, moves the
+/// caret to the end. This hook records the caret position (and selection) of the element
+/// referenced by `node` while the user interacts with it, and restores it after each render, as
+/// long as the element has the focus.
+///
+/// The returned handle allows reading the current position and setting a new one.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let node = use_node_ref();
+///   let value = use_state_eq(String::new);
+///   let caret = use_caret_position(node.clone());
+///
+///   let oninput = use_callback(value.clone(), |e: InputEvent, value| {
+///     let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+///     value.set(input.value().to_uppercase());
+///   });
+///   let onclick = use_callback(caret, |_, caret| caret.set(CaretPosition::at(0)));
+///
+///   html!(
+///     <>
+///       <input ref={node} value={(*value).clone()} {oninput} />
+///       <button {onclick}>{"To start"}</button>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_caret_position(node: NodeRef) -> UseCaretPositionHandle {
+    let saved = use_mut_ref(|| None::<CaretPosition>);
+
+    let onchange = use_callback(
+        (node.clone(), saved.clone()),
+        |_: web_sys::Event, (node, saved)| {
+            if let Some(element) = node.cast::<web_sys::Element>() {
+                *saved.borrow_mut() = get(&element);
+            }
+        },
+    );
+
+    use_event_listener(node.clone(), "input", onchange.clone());
+    use_event_listener(node.clone(), "select", onchange.clone());
+    use_event_listener(node.clone(), "selectionchange", onchange.clone());
+    use_event_listener(node.clone(), "keyup", onchange.clone());
+    use_event_listener(node.clone(), "pointerup", onchange);
+
+    {
+        let node = node.clone();
+        let saved = saved.clone();
+        use_effect(move || {
+            let element = node.cast::<web_sys::Element>();
+            let position = *saved.borrow();
+            if let (Some(element), Some(position)) = (element, position) {
+                let focused = gloo_utils::document().active_element().as_ref() == Some(&element);
+                if focused && get(&element) != Some(position) {
+                    set(&element, position);
+                }
+            }
+        });
+    }
+
+    UseCaretPositionHandle { node, saved }
+}
@@ -0,0 +1,69 @@
+//! Hooks for detecting clicks outside of elements
+
+use crate::hooks::{use_event_listener_with_options, ListenerTarget, UseEventListenerOptions};
+use yew::prelude::*;
+
+/// Detect clicks outside of a set of elements.
+///
+/// The callback will be called when a pointer goes down (mouse click, touch, or pen) outside all
+/// of the elements referenced by `nodes`. Elements which are not rendered are ignored.
+///
+/// Content which is rendered elsewhere in the DOM (like portals), but should be considered inside,
+/// can be added as an additional node reference. As the check uses the composed path of the event,
+/// it also works for elements inside of shadow roots.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Dropdown)]
+/// fn dropdown() -> Html {
+///   let open = use_state_eq(|| false);
+///   let toggle = use_node_ref();
+///   let menu = use_node_ref();
+///
+///   let onoutside = use_callback(open.clone(), |_, open| open.set(false));
+///   use_click_outside([toggle.clone(), menu.clone()], onoutside);
+///
+///   let onclick = use_callback(open.clone(), |_, open| open.set(!**open));
+///
+///   html!(
+///     <>
+///       <button ref={toggle} {onclick}>{"Menu"}</button>
+///       if *open {
+///         <ul ref={menu}><li>{"Item"}</li></ul>
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_click_outside<N>(nodes: N, callback: Callback<web_sys::Event>)
+where
+    N: IntoIterator<Item = NodeRef>,
+{
+    let nodes = nodes.into_iter().collect::<Vec<_>>();
+
+    let listener = use_callback(
+        (nodes, callback),
+        |event: web_sys::Event, (nodes, callback)| {
+            let path = event.composed_path();
+            let inside = nodes
+                .iter()
+                .filter_map(NodeRef::get)
+                .any(|node| path.includes(&node, 0));
+            if !inside {
+                callback.emit(event);
+            }
+        },
+    );
+
+    use_event_listener_with_options(
+        ListenerTarget::Document,
+        "pointerdown",
+        listener,
+        UseEventListenerOptions::enable_capture(),
+    );
+}
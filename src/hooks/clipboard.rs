@@ -0,0 +1,271 @@
+//! Hooks for the clipboard
+
+use gloo_timers::callback::Timeout;
+use std::{
+    cell::RefCell,
+    fmt::{Display, Formatter},
+    ops::Deref,
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use yew::prelude::*;
+
+/// An error accessing the clipboard.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ClipboardError {
+    /// The Clipboard API is not available, e.g. when not running in a secure context.
+    Unsupported,
+    /// The user, or the browser, denied access to the clipboard.
+    PermissionDenied(String),
+    /// Accessing the clipboard failed for another reason.
+    Failed(String),
+}
+
+impl Display for ClipboardError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str("Clipboard API is not supported"),
+            Self::PermissionDenied(msg) => write!(f, "Permission denied: {msg}"),
+            Self::Failed(msg) => write!(f, "Failed to access clipboard: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ClipboardError {}
+
+impl From<JsValue> for ClipboardError {
+    fn from(err: JsValue) -> Self {
+        match err.dyn_ref::<web_sys::DomException>() {
+            Some(err) if err.name() == "NotAllowedError" => Self::PermissionDenied(err.message()),
+            Some(err) => Self::Failed(err.message()),
+            None => Self::Failed(format!("{err:?}")),
+        }
+    }
+}
+
+fn clipboard() -> Result<web_sys::Clipboard, ClipboardError> {
+    let navigator = gloo_utils::window().navigator();
+    // not available in insecure contexts
+    match js_sys::Reflect::get(&navigator, &"clipboard".into()) {
+        Ok(clipboard) if !clipboard.is_undefined() => Ok(clipboard.unchecked_into()),
+        _ => Err(ClipboardError::Unsupported),
+    }
+}
+
+async fn write_text(text: String) -> Result<(), ClipboardError> {
+    JsFuture::from(clipboard()?.write_text(&text)).await?;
+    Ok(())
+}
+
+async fn write_bytes(mime: String, bytes: Vec<u8>) -> Result<(), ClipboardError> {
+    let clipboard = clipboard()?;
+
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(&mime);
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes.as_slice()));
+    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)?;
+
+    let record = js_sys::Object::new();
+    js_sys::Reflect::set(&record, &mime.into(), &blob)?;
+    let item = web_sys::ClipboardItem::new_with_record_from_str_to_blob_promise(&record)?;
+
+    JsFuture::from(clipboard.write(&js_sys::Array::of1(&item))).await?;
+    Ok(())
+}
+
+/// Read the text content of the clipboard.
+///
+/// Browsers might ask the user for permission first.
+pub async fn read_clipboard_text() -> Result<String, ClipboardError> {
+    let text = JsFuture::from(clipboard()?.read_text()).await?;
+    Ok(text.as_string().unwrap_or_default())
+}
+
+/// Options for [`use_clipboard_with_options`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UseClipboardOptions {
+    /// The time, in milliseconds, after which the `copied` state resets.
+    pub reset_after: u32,
+}
+
+impl Default for UseClipboardOptions {
+    fn default() -> Self {
+        Self { reset_after: 2_000 }
+    }
+}
+
+/// The state of the [`use_clipboard`] hook.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClipboardState {
+    /// Content was copied recently.
+    pub copied: bool,
+    /// The error of the last operation, if it failed.
+    pub error: Option<ClipboardError>,
+}
+
+/// State handle for the [`use_clipboard`] hook.
+#[derive(Clone)]
+pub struct UseClipboardHandle {
+    state: UseStateHandle<ClipboardState>,
+    timeout: Rc<RefCell<Option<Timeout>>>,
+    reset_after: u32,
+}
+
+impl UseClipboardHandle {
+    fn run<F>(&self, f: F)
+    where
+        F: std::future::Future<Output = Result<(), ClipboardError>> + 'static,
+    {
+        let state = self.state.clone();
+        let timeout = self.timeout.clone();
+        let reset_after = self.reset_after;
+        spawn_local(async move {
+            match f.await {
+                Ok(()) => {
+                    state.set(ClipboardState {
+                        copied: true,
+                        error: None,
+                    });
+                    let reset = state.clone();
+                    // replacing an existing timeout will cancel it
+                    *timeout.borrow_mut() = Some(Timeout::new(reset_after, move || {
+                        reset.set(ClipboardState::default());
+                    }));
+                }
+                Err(err) => {
+                    timeout.borrow_mut().take();
+                    state.set(ClipboardState {
+                        copied: false,
+                        error: Some(err),
+                    });
+                }
+            }
+        });
+    }
+
+    /// Copy text to the clipboard.
+    pub fn copy(&self, text: impl Into<String>) {
+        self.run(write_text(text.into()));
+    }
+
+    /// Copy binary content, of a MIME type (like `image/png`), to the clipboard.
+    ///
+    /// Browsers only support a limited set of types.
+    pub fn copy_bytes(&self, mime: impl Into<String>, bytes: impl Into<Vec<u8>>) {
+        self.run(write_bytes(mime.into(), bytes.into()));
+    }
+
+    /// Read the text content of the clipboard.
+    ///
+    /// Failing to read is reported through the result, as well as the state.
+    pub async fn read(&self) -> Result<String, ClipboardError> {
+        let result = read_clipboard_text().await;
+        if let Err(err) = &result {
+            self.state.set(ClipboardState {
+                copied: self.state.copied,
+                error: Some(err.clone()),
+            });
+        }
+        result
+    }
+}
+
+impl PartialEq for UseClipboardHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && self.reset_after == other.reset_after
+    }
+}
+
+impl Deref for UseClipboardHandle {
+    type Target = ClipboardState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// Access the clipboard.
+///
+/// This is [`use_clipboard_with_options`] using the default options.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let clipboard = use_clipboard();
+///   let onclick = use_callback(clipboard.clone(), |_, clipboard| clipboard.copy("Hello World"));
+///
+///   html!(
+///     <>
+///       <button {onclick}>{ if clipboard.copied { "Copied!" } else { "Copy" } }</button>
+///       if let Some(err) = &clipboard.error {
+///         <div class="error">{ err.to_string() }</div>
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_clipboard() -> UseClipboardHandle {
+    use_clipboard_with_options(UseClipboardOptions::default())
+}
+
+/// Access the clipboard, using options.
+///
+/// Copying content is performed asynchronously, using the
+/// [Clipboard API](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard_API). Once copied,
+/// the `copied` state is set, and automatically reset after the configured time. If copying
+/// failed, e.g. because the permission got denied, the error is set instead.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let text = use_state_eq(String::new);
+///   let clipboard = use_clipboard_with_options(UseClipboardOptions { reset_after: 500 });
+///
+///   let onclick = use_callback(
+///     (clipboard.clone(), text.clone()),
+///     |_, (clipboard, text)| {
+///       let clipboard = clipboard.clone();
+///       let text = text.clone();
+///       wasm_bindgen_futures::spawn_local(async move {
+///         if let Ok(content) = clipboard.read().await {
+///           text.set(content);
+///         }
+///       });
+///     },
+///   );
+///
+///   html!(<button {onclick}>{ format!("Paste: {}", *text) }</button>)
+/// }
+/// ```
+#[hook]
+pub fn use_clipboard_with_options(options: UseClipboardOptions) -> UseClipboardHandle {
+    let state = use_state_eq(ClipboardState::default);
+    let timeout = use_mut_ref(|| None::<Timeout>);
+
+    {
+        let timeout = timeout.clone();
+        use_effect_with((), move |()| {
+            move || {
+                timeout.borrow_mut().take();
+            }
+        });
+    }
+
+    UseClipboardHandle {
+        state,
+        timeout,
+        reset_after: options.reset_after,
+    }
+}
@@ -0,0 +1,643 @@
+//! Hooks for collection state
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Deref,
+    rc::Rc,
+};
+use yew::prelude::*;
+
+/// A mutation of a collection, returning `true` if it changed the collection.
+type Mutation<C> = Box<dyn FnOnce(&mut C) -> bool>;
+
+/// A collection as reducer, only creating a new state if a mutation changed it.
+#[derive(PartialEq)]
+struct Mutable<C>(C);
+
+impl<C> Reducible for Mutable<C>
+where
+    C: Clone + 'static,
+{
+    type Action = Mutation<C>;
+
+    fn reduce(self: Rc<Self>, mutation: Self::Action) -> Rc<Self> {
+        let mut next = self.0.clone();
+        match mutation(&mut next) {
+            true => Rc::new(Self(next)),
+            false => self,
+        }
+    }
+}
+
+fn move_item<T>(list: &mut Vec<T>, from: usize, to: usize) -> bool {
+    if from == to || from >= list.len() || to >= list.len() {
+        return false;
+    }
+    let item = list.remove(from);
+    list.insert(to, item);
+    true
+}
+
+/// State handle for the [`use_list`] hook.
+pub struct UseListHandle<T>
+where
+    T: Clone + 'static,
+{
+    state: UseReducerHandle<Mutable<Vec<T>>>,
+}
+
+impl<T> UseListHandle<T>
+where
+    T: Clone + 'static,
+{
+    fn mutate(&self, f: impl FnOnce(&mut Vec<T>) -> bool + 'static) {
+        self.state.dispatch(Box::new(f));
+    }
+
+    /// Replace the whole list.
+    pub fn set(&self, list: Vec<T>) {
+        self.mutate(move |current| {
+            *current = list;
+            true
+        });
+    }
+
+    /// Append an item.
+    pub fn push(&self, item: T) {
+        self.mutate(move |list| {
+            list.push(item);
+            true
+        });
+    }
+
+    /// Insert an item at a position, or append it if the position is past the end.
+    pub fn insert(&self, index: usize, item: T) {
+        self.mutate(move |list| {
+            list.insert(index.min(list.len()), item);
+            true
+        });
+    }
+
+    /// Remove the item at a position, ignoring positions out of bounds.
+    pub fn remove(&self, index: usize) {
+        self.mutate(move |list| {
+            if index >= list.len() {
+                return false;
+            }
+            list.remove(index);
+            true
+        });
+    }
+
+    /// Swap two items, ignoring positions out of bounds.
+    pub fn swap(&self, a: usize, b: usize) {
+        self.mutate(move |list| {
+            if a == b || a >= list.len() || b >= list.len() {
+                return false;
+            }
+            list.swap(a, b);
+            true
+        });
+    }
+
+    /// Move an item to another position, shifting the items in between.
+    ///
+    /// Positions out of bounds are ignored.
+    pub fn move_item(&self, from: usize, to: usize) {
+        self.mutate(move |list| move_item(list, from, to));
+    }
+
+    /// Update the item at a position, ignoring positions out of bounds.
+    pub fn update_at<F>(&self, index: usize, f: F)
+    where
+        F: FnOnce(&mut T) + 'static,
+    {
+        self.mutate(move |list| match list.get_mut(index) {
+            Some(item) => {
+                f(item);
+                true
+            }
+            None => false,
+        });
+    }
+
+    /// Keep only the items matching the predicate.
+    pub fn retain<F>(&self, f: F)
+    where
+        F: FnMut(&T) -> bool + 'static,
+    {
+        self.mutate(move |list| {
+            let len = list.len();
+            list.retain(f);
+            list.len() != len
+        });
+    }
+
+    /// Remove all items.
+    pub fn clear(&self) {
+        self.mutate(|list| {
+            let changed = !list.is_empty();
+            list.clear();
+            changed
+        });
+    }
+}
+
+impl<T> Clone for UseListHandle<T>
+where
+    T: Clone + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseListHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<T> Deref for UseListHandle<T>
+where
+    T: Clone + 'static,
+{
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state.0
+    }
+}
+
+/// List state, with helpers to modify it.
+///
+/// The handle dereferences to the current list, and provides methods for modifying it, without
+/// the need for cloning, modifying, and setting the list manually. Modifications which don't
+/// change the list, like removing an item out of bounds, don't trigger a re-render.
+///
+/// Modifications are applied in order, to the latest state, so calling several methods in a row
+/// works as expected.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Todos)]
+/// fn todos() -> Html {
+///   let todos = use_list(|| vec!["Write docs".to_string()]);
+///   let onadd = use_callback(todos.clone(), |_, todos| todos.push("New todo".into()));
+///
+///   html!(
+///     <>
+///       <ul>
+///         { for todos.iter().enumerate().map(|(index, todo)| {
+///           let todos = todos.clone();
+///           let onremove = Callback::from(move |_| todos.remove(index));
+///           html!(<li key={index}>{ todo } <button onclick={onremove}>{"Done"}</button></li>)
+///         }) }
+///       </ul>
+///       <button onclick={onadd}>{"Add"}</button>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_list<T, F>(init: F) -> UseListHandle<T>
+where
+    T: Clone + 'static,
+    F: FnOnce() -> Vec<T>,
+{
+    let state = use_reducer(|| Mutable(init()));
+    UseListHandle { state }
+}
+
+/// State handle for the [`use_hash_map`] hook.
+pub struct UseHashMapHandle<K, V>
+where
+    K: Clone + Eq + Hash + 'static,
+    V: Clone + PartialEq + 'static,
+{
+    state: UseReducerHandle<Mutable<HashMap<K, V>>>,
+}
+
+impl<K, V> UseHashMapHandle<K, V>
+where
+    K: Clone + Eq + Hash + 'static,
+    V: Clone + PartialEq + 'static,
+{
+    fn mutate(&self, f: impl FnOnce(&mut HashMap<K, V>) -> bool + 'static) {
+        self.state.dispatch(Box::new(f));
+    }
+
+    /// Replace the whole map.
+    pub fn set(&self, map: HashMap<K, V>) {
+        self.mutate(move |current| {
+            let changed = *current != map;
+            *current = map;
+            changed
+        });
+    }
+
+    /// Insert a value, replacing the previous value of the key.
+    pub fn insert(&self, key: K, value: V) {
+        self.mutate(move |map| map.insert(key, value.clone()).as_ref() != Some(&value));
+    }
+
+    /// Remove the entry of a key.
+    pub fn remove(&self, key: K) {
+        self.mutate(move |map| map.remove(&key).is_some());
+    }
+
+    /// Update the entry of a key.
+    ///
+    /// The function gets the current value, if present, and returns the new value, or `None` to
+    /// remove the entry.
+    pub fn entry_update<F>(&self, key: K, f: F)
+    where
+        F: FnOnce(Option<&V>) -> Option<V> + 'static,
+    {
+        self.mutate(move |map| {
+            let value = f(map.get(&key));
+            if map.get(&key) == value.as_ref() {
+                return false;
+            }
+            match value {
+                Some(value) => map.insert(key, value),
+                None => map.remove(&key),
+            };
+            true
+        });
+    }
+
+    /// Remove all entries.
+    pub fn clear(&self) {
+        self.mutate(|map| {
+            let changed = !map.is_empty();
+            map.clear();
+            changed
+        });
+    }
+}
+
+impl<K, V> Clone for UseHashMapHandle<K, V>
+where
+    K: Clone + Eq + Hash + 'static,
+    V: Clone + PartialEq + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<K, V> PartialEq for UseHashMapHandle<K, V>
+where
+    K: Clone + Eq + Hash + 'static,
+    V: Clone + PartialEq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<K, V> Deref for UseHashMapHandle<K, V>
+where
+    K: Clone + Eq + Hash + 'static,
+    V: Clone + PartialEq + 'static,
+{
+    type Target = HashMap<K, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state.0
+    }
+}
+
+/// Map state, with helpers to modify it.
+///
+/// Like [`use_list`], but for a [`HashMap`], which is useful for keyed state, like which rows of
+/// a table are expanded. Modifications which don't change the map, like inserting the value a
+/// key already has, don't trigger a re-render.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   rows: Vec<String>,
+/// }
+///
+/// #[function_component(Table)]
+/// fn table(props: &Props) -> Html {
+///   let expanded = use_hash_map(std::collections::HashMap::<String, bool>::new);
+///
+///   html!(
+///     <table>
+///       { for props.rows.iter().map(|row| {
+///         let is_expanded = expanded.get(row).copied().unwrap_or_default();
+///         let onclick = {
+///           let expanded = expanded.clone();
+///           let row = row.clone();
+///           Callback::from(move |_| expanded.entry_update(row.clone(), |value| Some(!value.copied().unwrap_or_default())))
+///         };
+///         html!(
+///           <tr key={row.clone()} {onclick}>
+///             <td>{ if is_expanded { "▼" } else { "▶" } }</td>
+///             <td>{ row }</td>
+///           </tr>
+///         )
+///       }) }
+///     </table>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_hash_map<K, V, F>(init: F) -> UseHashMapHandle<K, V>
+where
+    K: Clone + Eq + Hash + 'static,
+    V: Clone + PartialEq + 'static,
+    F: FnOnce() -> HashMap<K, V>,
+{
+    let state = use_reducer(|| Mutable(init()));
+    UseHashMapHandle { state }
+}
+
+/// State handle for the [`use_hash_set`] hook.
+pub struct UseHashSetHandle<T>
+where
+    T: Clone + Eq + Hash + 'static,
+{
+    state: UseReducerHandle<Mutable<HashSet<T>>>,
+}
+
+impl<T> UseHashSetHandle<T>
+where
+    T: Clone + Eq + Hash + 'static,
+{
+    fn mutate(&self, f: impl FnOnce(&mut HashSet<T>) -> bool + 'static) {
+        self.state.dispatch(Box::new(f));
+    }
+
+    /// Replace the whole set.
+    pub fn set(&self, set: HashSet<T>) {
+        self.mutate(move |current| {
+            let changed = *current != set;
+            *current = set;
+            changed
+        });
+    }
+
+    /// Insert a value.
+    pub fn insert(&self, value: T) {
+        self.mutate(move |set| set.insert(value));
+    }
+
+    /// Remove a value.
+    pub fn remove(&self, value: T) {
+        self.mutate(move |set| set.remove(&value));
+    }
+
+    /// Insert a value if missing, remove it otherwise.
+    pub fn toggle(&self, value: T) {
+        self.mutate(move |set| {
+            if !set.remove(&value) {
+                set.insert(value);
+            }
+            true
+        });
+    }
+
+    /// Remove all values.
+    pub fn clear(&self) {
+        self.mutate(|set| {
+            let changed = !set.is_empty();
+            set.clear();
+            changed
+        });
+    }
+}
+
+impl<T> Clone for UseHashSetHandle<T>
+where
+    T: Clone + Eq + Hash + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseHashSetHandle<T>
+where
+    T: Clone + Eq + Hash + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<T> Deref for UseHashSetHandle<T>
+where
+    T: Clone + Eq + Hash + 'static,
+{
+    type Target = HashSet<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state.0
+    }
+}
+
+/// Set state, with helpers to modify it.
+///
+/// Like [`use_list`], but for a [`HashSet`], which is useful for multi-select state, like
+/// checkboxes or tag pickers. Modifications which don't change the set, like inserting a value
+/// which is already present, don't trigger a re-render.
+///
+/// The handle dereferences to the current set, providing methods like `contains`.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(TagPicker)]
+/// fn tag_picker() -> Html {
+///   let selected = use_hash_set(std::collections::HashSet::<&'static str>::new);
+///
+///   html!(
+///     <>
+///       { for ["rust", "yew", "wasm"].into_iter().map(|tag| {
+///         let onchange = {
+///           let selected = selected.clone();
+///           Callback::from(move |_| selected.toggle(tag))
+///         };
+///         html!(
+///           <label key={tag}>
+///             <input type="checkbox" checked={selected.contains(tag)} {onchange} />
+///             { tag }
+///           </label>
+///         )
+///       }) }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_hash_set<T, F>(init: F) -> UseHashSetHandle<T>
+where
+    T: Clone + Eq + Hash + 'static,
+    F: FnOnce() -> HashSet<T>,
+{
+    let state = use_reducer(|| Mutable(init()));
+    UseHashSetHandle { state }
+}
+
+/// State handle for the [`use_queue`] hook.
+pub struct UseQueueHandle<T>
+where
+    T: Clone + 'static,
+{
+    state: UseReducerHandle<Mutable<VecDeque<T>>>,
+}
+
+impl<T> UseQueueHandle<T>
+where
+    T: Clone + 'static,
+{
+    fn mutate(&self, f: impl FnOnce(&mut VecDeque<T>) -> bool + 'static) {
+        self.state.dispatch(Box::new(f));
+    }
+
+    /// Add an item to the end of the queue.
+    pub fn push_back(&self, item: T) {
+        self.mutate(move |queue| {
+            queue.push_back(item);
+            true
+        });
+    }
+
+    /// Remove the item at the front of the queue.
+    ///
+    /// The item can be inspected beforehand, using [`Self::peek`].
+    pub fn pop_front(&self) {
+        self.mutate(|queue| queue.pop_front().is_some());
+    }
+
+    /// The item at the front of the queue.
+    pub fn peek(&self) -> Option<&T> {
+        self.state.0.front()
+    }
+
+    /// Remove all items.
+    pub fn clear(&self) {
+        self.mutate(|queue| {
+            let changed = !queue.is_empty();
+            queue.clear();
+            changed
+        });
+    }
+}
+
+impl<T> Clone for UseQueueHandle<T>
+where
+    T: Clone + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseQueueHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<T> Deref for UseQueueHandle<T>
+where
+    T: Clone + 'static,
+{
+    type Target = VecDeque<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state.0
+    }
+}
+
+/// Queue state, with helpers to modify it.
+///
+/// Like [`use_list`], but for a first-in, first-out queue, like notifications shown one after
+/// another. The handle dereferences to the current queue.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Notifications)]
+/// fn notifications() -> Html {
+///   let queue = use_queue(std::collections::VecDeque::<String>::new);
+///   let onnotify = use_callback(queue.clone(), |_, queue| queue.push_back("Saved".into()));
+///   let ondismiss = use_callback(queue.clone(), |_, queue| queue.pop_front());
+///
+///   html!(
+///     <>
+///       <button onclick={onnotify}>{"Save"}</button>
+///       if let Some(message) = queue.peek() {
+///         <div class="notification">
+///           { message }
+///           if queue.len() > 1 {
+///             { format!(" (+{} more)", queue.len() - 1) }
+///           }
+///           <button onclick={ondismiss}>{"Dismiss"}</button>
+///         </div>
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_queue<T, F>(init: F) -> UseQueueHandle<T>
+where
+    T: Clone + 'static,
+    F: FnOnce() -> VecDeque<T>,
+{
+    let state = use_reducer(|| Mutable(init()));
+    UseQueueHandle { state }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_move_item() {
+        let mut list = vec![1, 2, 3, 4];
+        assert!(move_item(&mut list, 0, 2));
+        assert_eq!(list, vec![2, 3, 1, 4]);
+        assert!(move_item(&mut list, 3, 0));
+        assert_eq!(list, vec![4, 2, 3, 1]);
+        assert!(!move_item(&mut list, 1, 1));
+        assert!(!move_item(&mut list, 1, 4));
+        assert_eq!(list, vec![4, 2, 3, 1]);
+    }
+}
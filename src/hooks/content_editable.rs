@@ -0,0 +1,153 @@
+//! Hooks for binding editable content
+
+use crate::hooks::use_event_listener;
+use std::ops::Deref;
+use yew::prelude::*;
+
+/// What content of an editable element to bind.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ContentEditableMode {
+    /// The text content.
+    #[default]
+    Text,
+    /// The HTML content.
+    Html,
+}
+
+impl ContentEditableMode {
+    fn read(&self, element: &web_sys::Element) -> String {
+        match self {
+            Self::Text => element.text_content().unwrap_or_default(),
+            Self::Html => element.inner_html(),
+        }
+    }
+
+    fn write(&self, element: &web_sys::Element, value: &str) {
+        match self {
+            Self::Text => element.set_text_content(Some(value)),
+            Self::Html => element.set_inner_html(value),
+        }
+    }
+}
+
+/// Options for [`use_content_editable`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UseContentEditableOptions {
+    /// Whether to edit the text or the HTML content.
+    pub mode: ContentEditableMode,
+    /// Sanitize the content, after it was edited.
+    ///
+    /// If the sanitized content differs, it will be written back to the element. This moves the
+    /// caret to the start of the element.
+    pub sanitize: Option<Callback<String, String>>,
+}
+
+/// State handle for the [`use_content_editable`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseContentEditableHandle {
+    value: UseStateHandle<String>,
+}
+
+impl UseContentEditableHandle {
+    /// Replace the content.
+    pub fn set(&self, value: impl Into<String>) {
+        self.value.set(value.into());
+    }
+}
+
+impl Deref for UseContentEditableHandle {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// Bind the content of a `contenteditable` element to a state.
+///
+/// The content of the element referenced by `node` is kept in sync with the state: edits update
+/// the state, and setting the state updates the element. The element must not have any children
+/// rendered by Yew.
+///
+/// The content is only written to the element when it differs from the state, so editing doesn't
+/// re-write the content, which would make the caret jump.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let node = use_node_ref();
+///   let sanitize = use_callback((), |value: String, ()| value.replace('\n', " "));
+///   let content = use_content_editable(
+///     node.clone(),
+///     || "Edit me".to_string(),
+///     UseContentEditableOptions {
+///       sanitize: Some(sanitize),
+///       ..Default::default()
+///     },
+///   );
+///
+///   let onclick = use_callback(content.clone(), |_, content| content.set(""));
+///
+///   html!(
+///     <>
+///       <div ref={node} contenteditable="true" />
+///       <div>{ format!("{} characters", content.chars().count()) }</div>
+///       <button {onclick}>{"Clear"}</button>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_content_editable<F>(
+    node: NodeRef,
+    init: F,
+    options: UseContentEditableOptions,
+) -> UseContentEditableHandle
+where
+    F: FnOnce() -> String,
+{
+    let value = use_state_eq(init);
+
+    let oninput = use_callback(
+        (node.clone(), value.clone(), options.clone()),
+        |_: web_sys::InputEvent, (node, value, options)| {
+            let Some(element) = node.cast::<web_sys::Element>() else {
+                return;
+            };
+
+            let content = options.mode.read(&element);
+            let content = match &options.sanitize {
+                Some(sanitize) => {
+                    let sanitized = sanitize.emit(content.clone());
+                    if sanitized != content {
+                        options.mode.write(&element, &sanitized);
+                    }
+                    sanitized
+                }
+                None => content,
+            };
+
+            value.set(content);
+        },
+    );
+
+    use_event_listener(node.clone(), "input", oninput);
+
+    use_effect_with(
+        (node, (*value).clone(), options.mode),
+        |(node, value, mode)| {
+            if let Some(element) = node.cast::<web_sys::Element>() {
+                if mode.read(&element) != *value {
+                    mode.write(&element, value);
+                }
+            }
+        },
+    );
+
+    UseContentEditableHandle { value }
+}
@@ -0,0 +1,113 @@
+//! Hooks for state which can be controlled by a parent
+
+use std::ops::Deref;
+use yew::prelude::*;
+
+/// State handle for the [`use_controllable_state`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseControllableStateHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    value: T,
+    controlled: bool,
+    state: UseStateHandle<T>,
+    onchange: Option<Callback<T>>,
+}
+
+impl<T> UseControllableStateHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// Request a new value.
+    ///
+    /// When controlled, this only notifies the parent, which decides whether to pass the value
+    /// back. Otherwise, the value is stored, and the parent is notified as well.
+    pub fn set(&self, value: T) {
+        if !self.controlled {
+            self.state.set(value.clone());
+        }
+        if let Some(onchange) = &self.onchange {
+            onchange.emit(value);
+        }
+    }
+
+    /// Check if the value is controlled by the parent.
+    pub fn is_controlled(&self) -> bool {
+        self.controlled
+    }
+}
+
+impl<T> Deref for UseControllableStateHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// State, which can be controlled by a parent component.
+///
+/// This implements the pattern of controlled and uncontrolled components: if the parent provides
+/// a `value`, it is controlled by the parent, and changes are only reported through `onchange`.
+/// Otherwise, the component keeps the state itself, starting with the `default` value, and still
+/// reports changes.
+///
+/// When a component switches from controlled to uncontrolled, it continues with the last value
+/// it stored itself.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   #[prop_or_default]
+///   checked: Option<bool>,
+///   #[prop_or_default]
+///   default_checked: bool,
+///   #[prop_or_default]
+///   onchange: Option<Callback<bool>>,
+/// }
+///
+/// #[function_component(Switch)]
+/// fn switch(props: &Props) -> Html {
+///   let checked = use_controllable_state(
+///     props.checked,
+///     props.onchange.clone(),
+///     || props.default_checked,
+///   );
+///   let onclick = use_callback(checked.clone(), |_, checked| checked.set(!**checked));
+///
+///   html!(
+///     <button role="switch" aria-checked={checked.to_string()} {onclick}>
+///       { if *checked { "On" } else { "Off" } }
+///     </button>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_controllable_state<T, F>(
+    value: Option<T>,
+    onchange: Option<Callback<T>>,
+    default: F,
+) -> UseControllableStateHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+    F: FnOnce() -> T,
+{
+    let state = use_state_eq(default);
+    let controlled = value.is_some();
+
+    UseControllableStateHandle {
+        value: value.unwrap_or_else(|| (*state).clone()),
+        controlled,
+        state,
+        onchange,
+    }
+}
@@ -0,0 +1,211 @@
+//! Hooks for counting
+
+use std::{ops::Deref, rc::Rc};
+use yew::prelude::*;
+
+/// Options for [`use_counter_with_options`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UseCounterOptions {
+    /// The lower bound of the value.
+    pub min: Option<i64>,
+    /// The upper bound of the value.
+    pub max: Option<i64>,
+    /// The amount to increment or decrement by.
+    pub step: i64,
+}
+
+impl Default for UseCounterOptions {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            step: 1,
+        }
+    }
+}
+
+impl UseCounterOptions {
+    fn clamp(&self, value: i64) -> i64 {
+        let value = self.min.map_or(value, |min| value.max(min));
+        self.max.map_or(value, |max| value.min(max))
+    }
+}
+
+#[derive(PartialEq)]
+struct Counter(i64);
+
+enum Change {
+    By(i64),
+    To(i64),
+}
+
+struct CounterAction {
+    change: Change,
+    /// The options at the time of the change.
+    options: UseCounterOptions,
+}
+
+impl Reducible for Counter {
+    type Action = CounterAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let value = match action.change {
+            Change::By(delta) => self.0.saturating_add(delta),
+            Change::To(value) => value,
+        };
+        let value = action.options.clamp(value);
+        match value == self.0 {
+            true => self,
+            false => Rc::new(Self(value)),
+        }
+    }
+}
+
+/// State handle for the [`use_counter`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseCounterHandle {
+    state: UseReducerHandle<Counter>,
+    initial: i64,
+    options: UseCounterOptions,
+}
+
+impl UseCounterHandle {
+    fn change(&self, change: Change) {
+        self.state.dispatch(CounterAction {
+            change,
+            options: self.options.clone(),
+        });
+    }
+
+    /// Increment by one step.
+    pub fn inc(&self) {
+        self.change(Change::By(self.options.step));
+    }
+
+    /// Decrement by one step.
+    pub fn dec(&self) {
+        self.change(Change::By(-self.options.step));
+    }
+
+    /// Set a value, limited to the bounds.
+    pub fn set(&self, value: i64) {
+        self.change(Change::To(value));
+    }
+
+    /// Reset to the initial value.
+    pub fn reset(&self) {
+        self.change(Change::To(self.initial));
+    }
+
+    /// Check if the value can be incremented, without reaching the upper bound.
+    pub fn can_inc(&self) -> bool {
+        match self.options.max {
+            Some(max) => self.state.0 < max,
+            None => true,
+        }
+    }
+
+    /// Check if the value can be decremented, without reaching the lower bound.
+    pub fn can_dec(&self) -> bool {
+        match self.options.min {
+            Some(min) => self.state.0 > min,
+            None => true,
+        }
+    }
+}
+
+impl Deref for UseCounterHandle {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state.0
+    }
+}
+
+/// A counter.
+///
+/// See [`use_counter_with_options`] for bounds and steps.
+#[hook]
+pub fn use_counter(initial: i64) -> UseCounterHandle {
+    use_counter_with_options(initial, UseCounterOptions::default())
+}
+
+/// A counter, with options.
+///
+/// The handle dereferences to the current value, and provides methods for changing it. The
+/// methods always operate on the latest value, so calling them repeatedly from the same callback
+/// works as expected. When bounds are set, the value gets clamped to them whenever it is changed.
+///
+/// The initial value is only evaluated on the first render, but is the value restored when
+/// resetting the counter.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Quantity)]
+/// fn quantity() -> Html {
+///   let quantity = use_counter_with_options(1, UseCounterOptions {
+///     min: Some(1),
+///     max: Some(10),
+///     ..Default::default()
+///   });
+///
+///   let ondec = use_callback(quantity.clone(), |_, quantity| quantity.dec());
+///   let oninc = use_callback(quantity.clone(), |_, quantity| quantity.inc());
+///
+///   html!(
+///     <>
+///       <button onclick={ondec} disabled={!quantity.can_dec()}>{"-"}</button>
+///       { *quantity }
+///       <button onclick={oninc} disabled={!quantity.can_inc()}>{"+"}</button>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_counter_with_options(initial: i64, options: UseCounterOptions) -> UseCounterHandle {
+    let initial = *use_memo((), |()| options.clamp(initial));
+    let state = use_reducer(|| Counter(initial));
+
+    UseCounterHandle {
+        state,
+        initial,
+        options,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn reduce(state: Rc<Counter>, change: Change, options: &UseCounterOptions) -> Rc<Counter> {
+        state.reduce(CounterAction {
+            change,
+            options: options.clone(),
+        })
+    }
+
+    #[test]
+    fn test_clamp() {
+        let options = UseCounterOptions {
+            min: Some(0),
+            max: Some(10),
+            step: 4,
+        };
+
+        let state = Rc::new(Counter(0));
+        let state = reduce(state, Change::By(options.step), &options);
+        let state = reduce(state, Change::By(options.step), &options);
+        let state = reduce(state, Change::By(options.step), &options);
+        assert_eq!(state.0, 10);
+
+        let state = reduce(state, Change::To(-5), &options);
+        assert_eq!(state.0, 0);
+
+        let unchanged = reduce(state.clone(), Change::By(-options.step), &options);
+        assert!(Rc::ptr_eq(&state, &unchanged));
+    }
+}
@@ -0,0 +1,139 @@
+//! Hooks for debouncing values
+
+use gloo_timers::callback::Timeout;
+use std::ops::Deref;
+use yew::prelude::*;
+
+/// Debounce a value.
+///
+/// Returns a copy of the value, which only gets updated once the value didn't change for
+/// `millis` milliseconds. The initial value is returned right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let search = use_state_eq(String::new);
+///   let oninput = use_callback(search.clone(), |e: InputEvent, search| {
+///     let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+///     search.set(input.value());
+///   });
+///
+///   // only search once the user stopped typing
+///   let search = use_debounce((*search).clone(), 500);
+///   let result = use_async_with_cloned_deps(|search| async move {
+///     Ok::<_, String>(format!("Results for: {search}"))
+///   }, search);
+///
+///   html!(<input {oninput} />)
+/// }
+/// ```
+#[hook]
+pub fn use_debounce<T>(value: T, millis: u32) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    let debounced = use_state_eq(|| value.clone());
+
+    {
+        let debounced = debounced.clone();
+        use_effect_with((value, millis), move |(value, millis)| {
+            let value = value.clone();
+            let timeout = Timeout::new(*millis, move || debounced.set(value));
+            move || drop(timeout)
+        });
+    }
+
+    (*debounced).clone()
+}
+
+/// State handle for the [`use_debounced_state`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseDebouncedStateHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    state: UseStateHandle<T>,
+    debounced: T,
+}
+
+impl<T> UseDebouncedStateHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// Set a new value, which settles once it didn't change for the debounce time.
+    pub fn set(&self, value: T) {
+        self.state.set(value);
+    }
+
+    /// The settled value, which only changes once the value didn't change for the debounce
+    /// period.
+    pub fn debounced(&self) -> &T {
+        &self.debounced
+    }
+
+    /// Check if the settled value caught up with the current value.
+    pub fn is_settled(&self) -> bool {
+        *self.state == self.debounced
+    }
+}
+
+impl<T> Deref for UseDebouncedStateHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// State, with a debounced copy.
+///
+/// This combines [`use_state_eq`] and [`use_debounce`]: the handle dereferences to the current
+/// value, which is updated right away, for example to be shown in an input field. The settled
+/// value is only updated once the value didn't change for `millis` milliseconds, which is useful
+/// as dependency of expensive operations.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Search)]
+/// fn search() -> Html {
+///   let query = use_debounced_state(String::new, 300);
+///   let oninput = use_callback(query.clone(), |e: InputEvent, query| {
+///     query.set(e.target_unchecked_into::<web_sys::HtmlInputElement>().value());
+///   });
+///
+///   let results = use_async_with_cloned_deps(|query| async move {
+///     Ok::<_, String>(format!("Results for: {query}"))
+///   }, query.debounced().clone());
+///
+///   html!(
+///     <>
+///       <input value={(*query).clone()} {oninput} />
+///       if !query.is_settled() || results.is_processing() {
+///         <p>{"Searching…"}</p>
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_debounced_state<T, F>(init: F, millis: u32) -> UseDebouncedStateHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+    F: FnOnce() -> T,
+{
+    let state = use_state_eq(init);
+    let debounced = use_debounce((*state).clone(), millis);
+    UseDebouncedStateHandle { state, debounced }
+}
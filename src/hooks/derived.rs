@@ -0,0 +1,121 @@
+//! Hooks for state derived from other values
+
+use std::ops::Deref;
+use yew::prelude::*;
+
+/// State handle for the [`use_derived_state`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseDerivedStateHandle<S, T>
+where
+    S: Clone + PartialEq + 'static,
+    T: Clone + PartialEq + 'static,
+{
+    value: T,
+    source: S,
+    /// A local value, along with the source it overrides.
+    state: UseStateHandle<Option<(S, T)>>,
+}
+
+impl<S, T> UseDerivedStateHandle<S, T>
+where
+    S: Clone + PartialEq + 'static,
+    T: Clone + PartialEq + 'static,
+{
+    /// Override the derived value, until the source changes.
+    pub fn set(&self, value: T) {
+        self.state.set(Some((self.source.clone(), value)));
+    }
+
+    /// Drop the local value, going back to the derived value.
+    pub fn reset(&self) {
+        self.state.set(None);
+    }
+
+    /// Check if the derived value is overridden by a local value.
+    pub fn is_overridden(&self) -> bool {
+        matches!(&*self.state, Some((source, _)) if *source == self.source)
+    }
+}
+
+impl<S, T> Deref for UseDerivedStateHandle<S, T>
+where
+    S: Clone + PartialEq + 'static,
+    T: Clone + PartialEq + 'static,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// State, which is derived from a source, but can be overridden locally.
+///
+/// The value is created from the source using `f`, and re-created whenever the source changes.
+/// Setting a value overrides the derived value, until the source changes the next time. This is
+/// useful for editable fields, which are initialized from properties.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   name: String,
+///   onsave: Callback<String>,
+/// }
+///
+/// #[function_component(NameEditor)]
+/// fn name_editor(props: &Props) -> Html {
+///   // reset the input when the saved name changes
+///   let name = use_derived_state(props.name.clone(), |name| name.clone());
+///
+///   let oninput = use_callback(name.clone(), |e: InputEvent, name| {
+///     name.set(e.target_unchecked_into::<web_sys::HtmlInputElement>().value());
+///   });
+///   let onsave = use_callback(
+///     (name.clone(), props.onsave.clone()),
+///     |_, (name, onsave)| onsave.emit((**name).clone()),
+///   );
+///
+///   html!(
+///     <>
+///       <input value={(*name).clone()} {oninput} />
+///       <button onclick={onsave} disabled={!name.is_overridden()}>{"Save"}</button>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_derived_state<S, T, F>(source: S, f: F) -> UseDerivedStateHandle<S, T>
+where
+    S: Clone + PartialEq + 'static,
+    T: Clone + PartialEq + 'static,
+    F: FnOnce(&S) -> T,
+{
+    let state = use_state_eq(|| None::<(S, T)>);
+    let derived = use_memo(source.clone(), f);
+
+    {
+        // drop a stale override, so it doesn't apply again if the source changes back
+        let state = state.clone();
+        use_effect_with(source.clone(), move |source| {
+            if matches!(&*state, Some((overridden, _)) if overridden != source) {
+                state.set(None);
+            }
+        });
+    }
+
+    let value = match &*state {
+        Some((overridden, value)) if *overridden == source => value.clone(),
+        _ => (*derived).clone(),
+    };
+
+    UseDerivedStateHandle {
+        value,
+        source,
+        state,
+    }
+}
@@ -0,0 +1,285 @@
+//! Hooks for the motion sensors of the device
+//!
+//! ## Permissions
+//!
+//! Some browsers (like Safari on iOS) require the user to grant access to the sensors, before
+//! reporting any events. The permission must be requested in response to a user interaction, using
+//! `request_permission` of the returned handle. Other browsers report
+//! [`UsePermissionState::Granted`] right away.
+
+use crate::hooks::{
+    use_event_listener, use_raf_state, ListenerTarget, UsePermissionState, UseRafStateHandle,
+};
+use std::ops::Deref;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use yew::prelude::*;
+
+/// The orientation of the device, in degrees.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DeviceOrientation {
+    /// Rotation around the z axis, in the range of `0..360`.
+    pub alpha: Option<f64>,
+    /// Rotation around the x axis, in the range of `-180..180`.
+    pub beta: Option<f64>,
+    /// Rotation around the y axis, in the range of `-90..90`.
+    pub gamma: Option<f64>,
+    /// The orientation is relative to the earth, instead of an arbitrary frame.
+    pub absolute: bool,
+}
+
+impl From<web_sys::DeviceOrientationEvent> for DeviceOrientation {
+    fn from(event: web_sys::DeviceOrientationEvent) -> Self {
+        Self {
+            alpha: event.alpha(),
+            beta: event.beta(),
+            gamma: event.gamma(),
+            absolute: event.absolute(),
+        }
+    }
+}
+
+/// An acceleration, in meters per second squared.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Acceleration {
+    /// Along the horizontal axis of the screen, to the right.
+    pub x: Option<f64>,
+    /// Along the vertical axis of the screen, to the top.
+    pub y: Option<f64>,
+    /// Perpendicular to the screen, towards the viewer.
+    pub z: Option<f64>,
+}
+
+impl From<web_sys::DeviceAcceleration> for Acceleration {
+    fn from(acceleration: web_sys::DeviceAcceleration) -> Self {
+        Self {
+            x: acceleration.x(),
+            y: acceleration.y(),
+            z: acceleration.z(),
+        }
+    }
+}
+
+/// A rate of rotation, in degrees per second.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RotationRate {
+    /// Around the axis perpendicular to the screen.
+    pub alpha: Option<f64>,
+    /// Around the horizontal axis of the screen.
+    pub beta: Option<f64>,
+    /// Around the vertical axis of the screen.
+    pub gamma: Option<f64>,
+}
+
+impl From<web_sys::DeviceRotationRate> for RotationRate {
+    fn from(rate: web_sys::DeviceRotationRate) -> Self {
+        Self {
+            alpha: rate.alpha(),
+            beta: rate.beta(),
+            gamma: rate.gamma(),
+        }
+    }
+}
+
+/// The motion of the device.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DeviceMotion {
+    /// The acceleration, excluding gravity.
+    pub acceleration: Option<Acceleration>,
+    /// The acceleration, including gravity.
+    pub acceleration_including_gravity: Option<Acceleration>,
+    /// The rate of rotation.
+    pub rotation_rate: Option<RotationRate>,
+    /// The interval of measurements, in milliseconds.
+    pub interval: Option<f64>,
+}
+
+impl From<web_sys::DeviceMotionEvent> for DeviceMotion {
+    fn from(event: web_sys::DeviceMotionEvent) -> Self {
+        Self {
+            acceleration: event.acceleration().map(Into::into),
+            acceleration_including_gravity: event.acceleration_including_gravity().map(Into::into),
+            rotation_rate: event.rotation_rate().map(Into::into),
+            interval: event.interval(),
+        }
+    }
+}
+
+/// Get the `requestPermission` function of an event class, only present if required.
+fn request_permission_fn(class: &str) -> Option<(js_sys::Function, js_sys::Function)> {
+    let class = js_sys::Reflect::get(&gloo_utils::window(), &class.into())
+        .ok()?
+        .dyn_into::<js_sys::Function>()
+        .ok()?;
+    let request = js_sys::Reflect::get(&class, &"requestPermission".into())
+        .ok()?
+        .dyn_into::<js_sys::Function>()
+        .ok()?;
+    Some((class, request))
+}
+
+fn initial_permission(class: &str) -> UsePermissionState {
+    let supported = js_sys::Reflect::has(&gloo_utils::window(), &class.into()).unwrap_or_default();
+    match (supported, request_permission_fn(class).is_some()) {
+        (false, _) => UsePermissionState::Unsupported,
+        (true, true) => UsePermissionState::Prompt,
+        (true, false) => UsePermissionState::Granted,
+    }
+}
+
+/// State handle for the [`use_device_orientation`] and [`use_device_motion`] hooks.
+pub struct UseDeviceSensorHandle<T> {
+    state: UseRafStateHandle<Option<T>>,
+    permission: UseStateHandle<UsePermissionState>,
+    class: &'static str,
+}
+
+impl<T> UseDeviceSensorHandle<T> {
+    /// The permission to access the sensor.
+    pub fn permission(&self) -> UsePermissionState {
+        *self.permission
+    }
+
+    /// Request access to the sensor, if required by the browser.
+    ///
+    /// This must be called in response to a user interaction, like a click.
+    pub fn request_permission(&self) {
+        let Some((class, request)) = request_permission_fn(self.class) else {
+            return;
+        };
+        let Some(promise) = request
+            .call0(&class)
+            .ok()
+            .and_then(|promise| promise.dyn_into::<js_sys::Promise>().ok())
+        else {
+            return;
+        };
+
+        let permission = self.permission.clone();
+        spawn_local(async move {
+            let granted = JsFuture::from(promise)
+                .await
+                .ok()
+                .and_then(|result| result.as_string())
+                .is_some_and(|result| result == "granted");
+            permission.set(match granted {
+                true => UsePermissionState::Granted,
+                false => UsePermissionState::Denied,
+            });
+        });
+    }
+}
+
+impl<T> Clone for UseDeviceSensorHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            permission: self.permission.clone(),
+            class: self.class,
+        }
+    }
+}
+
+impl<T> PartialEq for UseDeviceSensorHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && self.permission == other.permission
+    }
+}
+
+impl<T> Deref for UseDeviceSensorHandle<T> {
+    type Target = Option<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+#[hook]
+fn use_device_sensor<E, T>(
+    class: &'static str,
+    event_type: &'static str,
+) -> UseDeviceSensorHandle<T>
+where
+    E: JsCast + 'static,
+    T: From<E> + 'static,
+{
+    let state = use_raf_state(|| None::<T>);
+    let permission = use_state_eq(|| initial_permission(class));
+
+    let onevent = {
+        let state = state.clone();
+        use_callback((), move |event: E, ()| state.set(Some(T::from(event))))
+    };
+    use_event_listener(ListenerTarget::Window, event_type, onevent);
+
+    UseDeviceSensorHandle {
+        state,
+        permission,
+        class,
+    }
+}
+
+/// Track the orientation of the device.
+///
+/// Returns the latest orientation reported by `deviceorientation` events, or `None` if none was
+/// reported yet. Updates are limited to one per animation frame. See the
+/// [module documentation](self) for requesting the permission.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let orientation = use_device_orientation();
+///   let onclick = use_callback(orientation.clone(), |_, orientation| {
+///     orientation.request_permission()
+///   });
+///
+///   match (orientation.permission(), *orientation) {
+///     (UsePermissionState::Prompt, _) => html!(<button {onclick}>{"Enable sensors"}</button>),
+///     (_, Some(orientation)) => html!(format!("Tilt: {:.0}°", orientation.gamma.unwrap_or_default())),
+///     _ => html!("No orientation"),
+///   }
+/// }
+/// ```
+#[hook]
+pub fn use_device_orientation() -> UseDeviceSensorHandle<DeviceOrientation> {
+    use_device_sensor::<web_sys::DeviceOrientationEvent, _>(
+        "DeviceOrientationEvent",
+        "deviceorientation",
+    )
+}
+
+/// Track the motion of the device.
+///
+/// Returns the latest motion reported by `devicemotion` events, or `None` if none was reported
+/// yet. Updates are limited to one per animation frame. See the [module documentation](self) for
+/// requesting the permission.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let motion = use_device_motion();
+///   let shaking = motion
+///     .and_then(|motion| motion.acceleration)
+///     .and_then(|acceleration| acceleration.x)
+///     .is_some_and(|x| x.abs() > 15f64);
+///
+///   html!(<p>{ if shaking { "Stop shaking me!" } else { "" } }</p>)
+/// }
+/// ```
+#[hook]
+pub fn use_device_motion() -> UseDeviceSensorHandle<DeviceMotion> {
+    use_device_sensor::<web_sys::DeviceMotionEvent, _>("DeviceMotionEvent", "devicemotion")
+}
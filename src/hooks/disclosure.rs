@@ -0,0 +1,169 @@
+//! Hooks for showing and hiding content, like modals, drawers, and dropdowns
+
+use crate::hooks::{use_click_outside, use_event_listener, ListenerTarget};
+use yew::prelude::*;
+
+/// Options for [`use_disclosure_with_options`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UseDisclosureOptions {
+    /// Start open.
+    pub initial: bool,
+    /// Close when the Escape key is pressed.
+    pub close_on_escape: bool,
+    /// Close when clicking outside of these elements, if any.
+    pub close_on_click_outside: Vec<NodeRef>,
+    /// Called when the content gets opened.
+    pub on_open: Option<Callback<()>>,
+    /// Called when the content gets closed.
+    pub on_close: Option<Callback<()>>,
+}
+
+/// State handle for the [`use_disclosure`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseDisclosureHandle {
+    state: UseStateHandle<bool>,
+    on_open: Option<Callback<()>>,
+    on_close: Option<Callback<()>>,
+}
+
+impl UseDisclosureHandle {
+    /// Check if the content is shown.
+    pub fn is_open(&self) -> bool {
+        *self.state
+    }
+
+    /// Show the content, notifying `on_open` if it was hidden.
+    pub fn open(&self) {
+        if !*self.state {
+            self.state.set(true);
+            if let Some(on_open) = &self.on_open {
+                on_open.emit(());
+            }
+        }
+    }
+
+    /// Hide the content, notifying `on_close` if it was shown.
+    pub fn close(&self) {
+        if *self.state {
+            self.state.set(false);
+            if let Some(on_close) = &self.on_close {
+                on_close.emit(());
+            }
+        }
+    }
+
+    /// Show the content if it is hidden, and hide it otherwise.
+    pub fn toggle(&self) {
+        match *self.state {
+            true => self.close(),
+            false => self.open(),
+        }
+    }
+
+    /// Create a click handler, opening the content.
+    pub fn onopen(&self) -> Callback<MouseEvent> {
+        let handle = self.clone();
+        Callback::from(move |_| handle.open())
+    }
+
+    /// Create a click handler, closing the content.
+    pub fn onclose(&self) -> Callback<MouseEvent> {
+        let handle = self.clone();
+        Callback::from(move |_| handle.close())
+    }
+
+    /// Create a click handler, toggling the content.
+    pub fn ontoggle(&self) -> Callback<MouseEvent> {
+        let handle = self.clone();
+        Callback::from(move |_| handle.toggle())
+    }
+}
+
+/// Open and close content, using default options.
+///
+/// See [`use_disclosure_with_options`].
+#[hook]
+pub fn use_disclosure() -> UseDisclosureHandle {
+    use_disclosure_with_options(UseDisclosureOptions::default())
+}
+
+/// Open and close content, like modals, drawers, and dropdowns.
+///
+/// The handle tracks if the content is open, and provides methods and click handlers for opening
+/// and closing it. Optionally, the content closes when pressing the Escape key, or when clicking
+/// outside of it.
+///
+/// The `on_open` and `on_close` callbacks are called when the state is changed through the
+/// handle, not for the initial state.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Dropdown)]
+/// fn dropdown() -> Html {
+///   let toggle = use_node_ref();
+///   let menu = use_node_ref();
+///   let disclosure = use_disclosure_with_options(UseDisclosureOptions {
+///     close_on_escape: true,
+///     close_on_click_outside: vec![toggle.clone(), menu.clone()],
+///     ..Default::default()
+///   });
+///
+///   html!(
+///     <>
+///       <button
+///         ref={toggle}
+///         aria-expanded={disclosure.is_open().to_string()}
+///         onclick={disclosure.ontoggle()}
+///       >
+///         {"Menu"}
+///       </button>
+///       if disclosure.is_open() {
+///         <ul ref={menu}>
+///           <li onclick={disclosure.onclose()}>{"Item"}</li>
+///         </ul>
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_disclosure_with_options(options: UseDisclosureOptions) -> UseDisclosureHandle {
+    let UseDisclosureOptions {
+        initial,
+        close_on_escape,
+        close_on_click_outside,
+        on_open,
+        on_close,
+    } = options;
+
+    let state = use_state_eq(|| initial);
+    let handle = UseDisclosureHandle {
+        state,
+        on_open,
+        on_close,
+    };
+
+    let onkeydown = use_callback(
+        (handle.clone(), close_on_escape),
+        |e: KeyboardEvent, (handle, close_on_escape)| {
+            if *close_on_escape && e.key() == "Escape" {
+                handle.close();
+            }
+        },
+    );
+    use_event_listener(ListenerTarget::Document, "keydown", onkeydown);
+
+    let enabled = !close_on_click_outside.is_empty();
+    let onoutside = use_callback((handle.clone(), enabled), |_, (handle, enabled)| {
+        if *enabled {
+            handle.close();
+        }
+    });
+    use_click_outside(close_on_click_outside, onoutside);
+
+    handle
+}
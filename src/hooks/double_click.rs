@@ -0,0 +1,102 @@
+//! Hooks for distinguishing single and double clicks
+
+use crate::hooks::{use_event_listener, use_latest};
+use gloo_timers::callback::Timeout;
+use std::{cell::Cell, rc::Rc};
+use yew::prelude::*;
+
+/// Options for [`use_double_click`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseDoubleClickOptions {
+    /// The maximum time, in milliseconds, between the two clicks of a double click.
+    pub interval: u32,
+    /// Called for a single click, once the interval passed without a second click.
+    pub onclick: Option<Callback<web_sys::MouseEvent>>,
+    /// Called for a double click, with the event of the second click.
+    pub ondoubleclick: Option<Callback<web_sys::MouseEvent>>,
+}
+
+impl Default for UseDoubleClickOptions {
+    fn default() -> Self {
+        Self {
+            interval: 250,
+            onclick: None,
+            ondoubleclick: None,
+        }
+    }
+}
+
+/// Distinguish single clicks from double clicks.
+///
+/// Clicks on the element referenced by `node` call either the `onclick` or the `ondoubleclick`
+/// callback, but never both: a single click is only reported after the interval passed without a
+/// second click, which delays it. The latest callbacks will always be used.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let node = use_node_ref();
+///   let action = use_state_eq(|| "none");
+///
+///   let onclick = use_callback(action.clone(), |_, action| action.set("select"));
+///   let ondoubleclick = use_callback(action.clone(), |_, action| action.set("open"));
+///   use_double_click(
+///     node.clone(),
+///     UseDoubleClickOptions {
+///       onclick: Some(onclick),
+///       ondoubleclick: Some(ondoubleclick),
+///       ..Default::default()
+///     },
+///   );
+///
+///   html!(<div ref={node}>{ *action }</div>)
+/// }
+/// ```
+#[hook]
+pub fn use_double_click(node: NodeRef, options: UseDoubleClickOptions) {
+    let options = use_latest(options);
+    let pending = use_mut_ref(|| None::<(Timeout, Rc<Cell<bool>>)>);
+
+    let onclick = {
+        let pending = pending.clone();
+        use_callback((), move |event: web_sys::MouseEvent, ()| {
+            // dropping a pending timeout cancels it
+            let first = pending.borrow_mut().take();
+            if first.is_some_and(|(_, fired)| !fired.get()) {
+                let ondoubleclick = options.borrow().ondoubleclick.clone();
+                if let Some(ondoubleclick) = ondoubleclick {
+                    ondoubleclick.emit(event);
+                }
+                return;
+            }
+
+            let fired = Rc::new(Cell::new(false));
+            let interval = options.borrow().interval;
+            let timeout = {
+                let options = options.clone();
+                let fired = fired.clone();
+                Timeout::new(interval, move || {
+                    fired.set(true);
+                    let onclick = options.borrow().onclick.clone();
+                    if let Some(onclick) = onclick {
+                        onclick.emit(event);
+                    }
+                })
+            };
+            *pending.borrow_mut() = Some((timeout, fired));
+        })
+    };
+
+    use_event_listener(node, "click", onclick);
+
+    use_effect_with((), move |()| {
+        move || {
+            pending.borrow_mut().take();
+        }
+    });
+}
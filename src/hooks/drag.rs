@@ -0,0 +1,240 @@
+//! Hooks for dragging elements
+
+use crate::hooks::{use_event_listener, use_latest};
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+/// Information about an ongoing drag operation.
+///
+/// Coordinates are client coordinates (relative to the viewport), in CSS pixels.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DragInfo {
+    /// The horizontal position, where the drag operation started.
+    pub start_x: f64,
+    /// The vertical position, where the drag operation started.
+    pub start_y: f64,
+    /// The current horizontal position of the pointer.
+    pub x: f64,
+    /// The current vertical position of the pointer.
+    pub y: f64,
+    /// Horizontal movement since the previous event.
+    pub movement_x: f64,
+    /// Vertical movement since the previous event.
+    pub movement_y: f64,
+}
+
+impl DragInfo {
+    fn new(x: f64, y: f64) -> Self {
+        Self {
+            start_x: x,
+            start_y: y,
+            x,
+            y,
+            movement_x: 0f64,
+            movement_y: 0f64,
+        }
+    }
+
+    fn moved(self, x: f64, y: f64) -> Self {
+        Self {
+            x,
+            y,
+            movement_x: x - self.x,
+            movement_y: y - self.y,
+            ..self
+        }
+    }
+
+    /// Movement since the start of the drag operation.
+    pub fn delta(&self) -> (f64, f64) {
+        (self.x - self.start_x, self.y - self.start_y)
+    }
+}
+
+/// Options for [`use_drag`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseDragOptions {
+    /// Allow dragging.
+    pub enabled: bool,
+    /// Called when dragging started.
+    pub onstart: Option<Callback<DragInfo>>,
+    /// Called when the pointer moved while dragging.
+    pub onmove: Option<Callback<DragInfo>>,
+    /// Called when dragging ended, or got cancelled.
+    pub onend: Option<Callback<DragInfo>>,
+}
+
+impl Default for UseDragOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            onstart: None,
+            onmove: None,
+            onend: None,
+        }
+    }
+}
+
+fn emit(callback: &Option<Callback<DragInfo>>, info: DragInfo) {
+    if let Some(callback) = callback {
+        callback.emit(info);
+    }
+}
+
+struct Active {
+    pointer_id: i32,
+    info: DragInfo,
+}
+
+/// Track dragging an element.
+///
+/// Dragging starts when the primary pointer button gets pressed on the element referenced by
+/// `node`. The pointer gets captured by the element, so that it keeps receiving events when the
+/// pointer leaves it. Returns the current [`DragInfo`] while dragging, `None` otherwise. The latest
+/// callbacks of the options will always be used.
+///
+/// For touch input, the element should use the CSS `touch-action: none`, so that the browser
+/// doesn't start scrolling instead.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(SplitPane)]
+/// fn split_pane() -> Html {
+///   let handle = use_node_ref();
+///   let width = use_state_eq(|| 300f64);
+///   let initial = use_mut_ref(|| 300f64);
+///
+///   let onstart = use_callback((width.clone(), initial.clone()), |_, (width, initial)| {
+///     *initial.borrow_mut() = **width;
+///   });
+///   let onmove = use_callback((width.clone(), initial), |info: DragInfo, (width, initial)| {
+///     width.set((*initial.borrow() + info.delta().0).max(100f64));
+///   });
+///   let dragging = use_drag(
+///     handle.clone(),
+///     UseDragOptions {
+///       onstart: Some(onstart),
+///       onmove: Some(onmove),
+///       ..Default::default()
+///     },
+///   );
+///
+///   html!(
+///     <div style="display: flex;">
+///       <div style={format!("width: {}px;", *width)}>{"Left"}</div>
+///       <div
+///         ref={handle}
+///         class={classes!("handle", dragging.is_some().then_some("dragging"))}
+///         style="touch-action: none;"
+///       />
+///       <div>{"Right"}</div>
+///     </div>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_drag(node: NodeRef, options: UseDragOptions) -> Option<DragInfo> {
+    let state = use_state_eq(|| None::<DragInfo>);
+    let active = use_mut_ref(|| None::<Active>);
+    let options = use_latest(options);
+
+    let onpointerdown = {
+        let active = active.clone();
+        let options = options.clone();
+        use_callback(state.clone(), move |event: web_sys::PointerEvent, state| {
+            let options = options.borrow().clone();
+            if !options.enabled || event.button() != 0 || active.borrow().is_some() {
+                return;
+            }
+
+            if let Some(element) = event
+                .current_target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+            {
+                let _ = element.set_pointer_capture(event.pointer_id());
+            }
+
+            let info = DragInfo::new(event.client_x().into(), event.client_y().into());
+            *active.borrow_mut() = Some(Active {
+                pointer_id: event.pointer_id(),
+                info,
+            });
+            state.set(Some(info));
+            emit(&options.onstart, info);
+        })
+    };
+
+    let onpointermove = {
+        let active = active.clone();
+        let options = options.clone();
+        use_callback(state.clone(), move |event: web_sys::PointerEvent, state| {
+            let info = {
+                let mut active = active.borrow_mut();
+                let Some(active) = active
+                    .as_mut()
+                    .filter(|active| active.pointer_id == event.pointer_id())
+                else {
+                    return;
+                };
+                active.info = active
+                    .info
+                    .moved(event.client_x().into(), event.client_y().into());
+                active.info
+            };
+
+            state.set(Some(info));
+            let onmove = options.borrow().onmove.clone();
+            emit(&onmove, info);
+        })
+    };
+
+    let onpointerup = use_callback(state.clone(), move |event: web_sys::PointerEvent, state| {
+        let ended = {
+            let mut active = active.borrow_mut();
+            match &*active {
+                Some(current) if current.pointer_id == event.pointer_id() => active.take(),
+                _ => None,
+            }
+        };
+        let Some(ended) = ended else {
+            return;
+        };
+
+        if let Some(element) = event
+            .current_target()
+            .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        {
+            let _ = element.release_pointer_capture(event.pointer_id());
+        }
+
+        state.set(None);
+        let onend = options.borrow().onend.clone();
+        emit(&onend, ended.info);
+    });
+
+    use_event_listener(node.clone(), "pointerdown", onpointerdown);
+    use_event_listener(node.clone(), "pointermove", onpointermove);
+    use_event_listener(node.clone(), "pointerup", onpointerup.clone());
+    use_event_listener(node, "pointercancel", onpointerup);
+
+    *state
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_moved() {
+        let info = DragInfo::new(10f64, 20f64).moved(15f64, 18f64);
+        assert_eq!((info.movement_x, info.movement_y), (5f64, -2f64));
+
+        let info = info.moved(25f64, 18f64);
+        assert_eq!((info.movement_x, info.movement_y), (10f64, 0f64));
+        assert_eq!(info.delta(), (15f64, -2f64));
+    }
+}
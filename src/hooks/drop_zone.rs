@@ -0,0 +1,189 @@
+//! Hooks for dropping files onto elements
+
+use crate::hooks::use_event_listener;
+use yew::prelude::*;
+
+/// Options for [`use_drop_zone_with_options`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UseDropZoneOptions {
+    /// MIME types of accepted files, like `image/png` or `image/*`. Accepts all files if empty.
+    pub accept: Vec<String>,
+}
+
+impl UseDropZoneOptions {
+    /// Only accept files of the provided MIME types
+    pub fn accept<I, S>(types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            accept: types.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Check if a MIME type is accepted by a list of (wildcard) MIME types.
+fn accepts(accept: &[String], mime: &str) -> bool {
+    accept.is_empty()
+        || accept
+            .iter()
+            .any(|pattern| match pattern.strip_suffix("/*") {
+                Some(prefix) => mime
+                    .split_once('/')
+                    .is_some_and(|(main, _)| main.eq_ignore_ascii_case(prefix)),
+                None => pattern.eq_ignore_ascii_case(mime),
+            })
+}
+
+/// Accept files being dropped onto an element.
+///
+/// This is [`use_drop_zone_with_options`] using the default options, accepting all files.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let node = use_node_ref();
+///   let names = use_state(Vec::<String>::new);
+///
+///   let ondrop = use_callback(names.clone(), |files: Vec<web_sys::File>, names| {
+///     names.set(files.iter().map(|file| file.name()).collect());
+///   });
+///   let over = use_drop_zone(node.clone(), ondrop);
+///
+///   html!(
+///     <div ref={node} class={classes!(over.then_some("drop-target"))}>
+///       { for names.iter().map(|name| html!(<div>{ name }</div>)) }
+///     </div>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_drop_zone(node: NodeRef, ondrop: Callback<Vec<web_sys::File>>) -> bool {
+    use_drop_zone_with_options(node, UseDropZoneOptions::default(), ondrop)
+}
+
+/// Accept files being dropped onto an element, using options.
+///
+/// Returns `true` while files are dragged over the element referenced by `node`. Dropped files,
+/// matching the accepted MIME types, are passed to the `ondrop` callback, unless none of them
+/// matched. The default behavior of the browser, opening the files, is prevented.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let node = use_node_ref();
+///   let count = use_state_eq(|| 0);
+///
+///   let ondrop = use_callback(count.clone(), |files: Vec<web_sys::File>, count| {
+///     count.set(**count + files.len());
+///   });
+///   let over = use_drop_zone_with_options(
+///     node.clone(),
+///     UseDropZoneOptions::accept(["image/*", "application/pdf"]),
+///     ondrop,
+///   );
+///
+///   html!(
+///     <div ref={node}>
+///       { if over { "Drop it!".to_string() } else { format!("{} files", *count) } }
+///     </div>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_drop_zone_with_options(
+    node: NodeRef,
+    options: UseDropZoneOptions,
+    ondrop: Callback<Vec<web_sys::File>>,
+) -> bool {
+    let over = use_state_eq(|| false);
+    // entering and leaving child elements reports events for those too
+    let depth = use_mut_ref(|| 0usize);
+
+    let ondragenter = {
+        let depth = depth.clone();
+        use_callback(over.clone(), move |event: web_sys::DragEvent, over| {
+            event.prevent_default();
+            *depth.borrow_mut() += 1;
+            over.set(true);
+        })
+    };
+
+    let ondragover = use_callback((), |event: web_sys::DragEvent, ()| {
+        // required for allowing to drop
+        event.prevent_default();
+        if let Some(transfer) = event.data_transfer() {
+            transfer.set_drop_effect("copy");
+        }
+    });
+
+    let ondragleave = {
+        let depth = depth.clone();
+        use_callback(over.clone(), move |_: web_sys::DragEvent, over| {
+            let mut depth = depth.borrow_mut();
+            *depth = depth.saturating_sub(1);
+            if *depth == 0 {
+                over.set(false);
+            }
+        })
+    };
+
+    let ondrop = use_callback(
+        (over.clone(), options, ondrop),
+        move |event: web_sys::DragEvent, (over, options, ondrop)| {
+            event.prevent_default();
+            *depth.borrow_mut() = 0;
+            over.set(false);
+
+            let files = event
+                .data_transfer()
+                .and_then(|transfer| transfer.files())
+                .map(|files| {
+                    (0..files.length())
+                        .filter_map(|i| files.item(i))
+                        .filter(|file| accepts(&options.accept, &file.type_()))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+
+            if !files.is_empty() {
+                ondrop.emit(files);
+            }
+        },
+    );
+
+    use_event_listener(node.clone(), "dragenter", ondragenter);
+    use_event_listener(node.clone(), "dragover", ondragover);
+    use_event_listener(node.clone(), "dragleave", ondragleave);
+    use_event_listener(node, "drop", ondrop);
+
+    *over
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_accepts() {
+        assert!(accepts(&[], "text/plain"));
+
+        let accept = UseDropZoneOptions::accept(["image/*", "application/pdf"]).accept;
+        assert!(accepts(&accept, "image/png"));
+        assert!(accepts(&accept, "Application/PDF"));
+        assert!(!accepts(&accept, "text/plain"));
+        assert!(!accepts(&accept, "imagefoo"));
+        assert!(!accepts(&accept, ""));
+    }
+}
@@ -0,0 +1,158 @@
+//! Hooks for tracking the scroll position of elements
+
+use crate::hooks::{
+    use_event_listener_with_options, use_raf_state, UseEventListenerOptions, UseRafStateHandle,
+};
+use yew::prelude::*;
+
+/// The scroll position of an element.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ElementScroll {
+    /// Horizontal scroll position, in pixels.
+    pub x: i32,
+    /// Vertical scroll position, in pixels.
+    pub y: i32,
+    /// Scrolled to the top.
+    pub at_top: bool,
+    /// Scrolled to the bottom.
+    pub at_bottom: bool,
+    /// Scrolled to the left edge.
+    pub at_left: bool,
+    /// Scrolled to the right edge.
+    pub at_right: bool,
+}
+
+/// Dimensions of a scroll container.
+struct Metrics {
+    scroll_left: i32,
+    scroll_top: i32,
+    scroll_width: i32,
+    scroll_height: i32,
+    client_width: i32,
+    client_height: i32,
+}
+
+impl From<&web_sys::Element> for Metrics {
+    fn from(element: &web_sys::Element) -> Self {
+        Self {
+            scroll_left: element.scroll_left(),
+            scroll_top: element.scroll_top(),
+            scroll_width: element.scroll_width(),
+            scroll_height: element.scroll_height(),
+            client_width: element.client_width(),
+            client_height: element.client_height(),
+        }
+    }
+}
+
+impl From<Metrics> for ElementScroll {
+    fn from(metrics: Metrics) -> Self {
+        // positions may be fractional on high DPI screens, but get rounded
+        const TOLERANCE: i32 = 1;
+
+        // with right-to-left content, horizontal positions are negative
+        let x = metrics.scroll_left.abs();
+        let y = metrics.scroll_top;
+
+        Self {
+            x: metrics.scroll_left,
+            y,
+            at_top: y <= TOLERANCE,
+            at_bottom: y + metrics.client_height >= metrics.scroll_height - TOLERANCE,
+            at_left: x <= TOLERANCE,
+            at_right: x + metrics.client_width >= metrics.scroll_width - TOLERANCE,
+        }
+    }
+}
+
+fn update(node: &NodeRef, scroll: &UseRafStateHandle<ElementScroll>) {
+    if let Some(element) = node.cast::<web_sys::Element>() {
+        scroll.set(Metrics::from(&element).into());
+    }
+}
+
+/// Track the scroll position of an element.
+///
+/// Returns the scroll position of the scrollable element referenced by `node`, and if it is
+/// scrolled to one of its edges. Updates are limited to one per animation frame.
+///
+/// The position is read once the component got rendered, and on every `scroll` event. Changes of
+/// the size of the content alone, which might change the edge flags, are not tracked.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Chat)]
+/// fn chat() -> Html {
+///   let node = use_node_ref();
+///   let scroll = use_element_scroll(node.clone());
+///
+///   html!(
+///     <>
+///       <div ref={node} style="overflow: auto; height: 400px;">
+///         // messages
+///       </div>
+///       if !scroll.at_bottom {
+///         <button>{"Jump to latest"}</button>
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_element_scroll(node: NodeRef) -> ElementScroll {
+    let scroll = use_raf_state(ElementScroll::default);
+
+    let onscroll = use_callback(
+        (node.clone(), scroll.clone()),
+        |_: web_sys::Event, (node, scroll)| update(node, scroll),
+    );
+
+    {
+        let scroll = scroll.clone();
+        // read the initial position
+        use_effect_with(node.clone(), move |node| update(node, &scroll));
+    }
+
+    use_event_listener_with_options(
+        node,
+        "scroll",
+        onscroll,
+        UseEventListenerOptions::enable_passive(),
+    );
+
+    *scroll
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn scroll(top: i32) -> ElementScroll {
+        Metrics {
+            scroll_left: 0,
+            scroll_top: top,
+            scroll_width: 100,
+            scroll_height: 1_000,
+            client_width: 100,
+            client_height: 200,
+        }
+        .into()
+    }
+
+    #[test]
+    fn test_edges() {
+        let state = scroll(0);
+        assert!(state.at_top && !state.at_bottom);
+        assert!(state.at_left && state.at_right);
+
+        let state = scroll(400);
+        assert!(!state.at_top && !state.at_bottom);
+
+        let state = scroll(799);
+        assert!(!state.at_top && state.at_bottom);
+    }
+}
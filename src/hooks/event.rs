@@ -0,0 +1,79 @@
+//! Hooks for handling events
+
+use yew::prelude::*;
+
+/// Prevent the default action of an event, before forwarding it.
+///
+/// This wraps a callback, calling [`web_sys::Event::prevent_default`] on the event before
+/// forwarding it to the original callback. A changed instance is only returned when the original
+/// callback changes.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let onsubmit = use_callback((), |_: SubmitEvent, ()| {
+///     // handle the form, without the browser submitting it
+///   });
+///   let onsubmit = use_prevent_default(onsubmit);
+///   html!(<form {onsubmit}></form>)
+/// }
+/// ```
+#[hook]
+pub fn use_prevent_default<E, OUT>(callback: Callback<E, OUT>) -> Callback<E, OUT>
+where
+    E: AsRef<web_sys::Event> + 'static,
+    OUT: 'static,
+{
+    use_wrap_event(callback, web_sys::Event::prevent_default)
+}
+
+/// Stop the propagation of an event, before forwarding it.
+///
+/// This wraps a callback, calling [`web_sys::Event::stop_propagation`] on the event before
+/// forwarding it to the original callback. A changed instance is only returned when the original
+/// callback changes.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let onclick = use_callback((), |_: MouseEvent, ()| {
+///     // the parent will not see this click
+///   });
+///   let onclick = use_stop_propagation(onclick);
+///   html!(<button {onclick}>{"Click me"}</button>)
+/// }
+/// ```
+#[hook]
+pub fn use_stop_propagation<E, OUT>(callback: Callback<E, OUT>) -> Callback<E, OUT>
+where
+    E: AsRef<web_sys::Event> + 'static,
+    OUT: 'static,
+{
+    use_wrap_event(callback, web_sys::Event::stop_propagation)
+}
+
+#[hook]
+fn use_wrap_event<E, OUT>(callback: Callback<E, OUT>, f: fn(&web_sys::Event)) -> Callback<E, OUT>
+where
+    E: AsRef<web_sys::Event> + 'static,
+    OUT: 'static,
+{
+    (*use_memo(callback, |callback| {
+        let callback = callback.clone();
+        Callback::from(move |event: E| {
+            f(event.as_ref());
+            callback.emit(event)
+        })
+    }))
+    .clone()
+}
@@ -0,0 +1,227 @@
+//! Hooks for publishing and subscribing to typed events
+//!
+//! An [`EventBusProvider`] provides an event bus to the components below it. Components publish
+//! events using [`use_event_publisher`], and receive all events of a type using
+//! [`use_event_subscriber`], without passing callbacks through the components in between.
+
+use crate::hooks::use_latest;
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+};
+use yew::prelude::*;
+
+/// A subscriber, holding a `Callback<T>` of the type of its events.
+type Subscriber = (usize, Rc<dyn Any>);
+
+#[derive(Default)]
+struct Subscribers {
+    next_id: usize,
+    subscribers: HashMap<TypeId, Vec<Subscriber>>,
+}
+
+/// An event bus, dispatching events to the subscribers of their type.
+#[derive(Clone, Default)]
+pub struct EventBus {
+    inner: Rc<RefCell<Subscribers>>,
+}
+
+impl PartialEq for EventBus {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl EventBus {
+    /// Send an event to all subscribers of its type.
+    pub fn publish<T>(&self, event: T)
+    where
+        T: Clone + 'static,
+    {
+        // collect first, so that subscribers can subscribe or unsubscribe when handling the event
+        let subscribers = self
+            .inner
+            .borrow()
+            .subscribers
+            .get(&TypeId::of::<T>())
+            .map(|subscribers| {
+                subscribers
+                    .iter()
+                    .filter_map(|(_, callback)| callback.downcast_ref::<Callback<T>>().cloned())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        for subscriber in subscribers {
+            subscriber.emit(event.clone());
+        }
+    }
+
+    /// Receive all events of a type, until the subscription is dropped.
+    pub fn subscribe<T>(&self, callback: Callback<T>) -> EventSubscription
+    where
+        T: 'static,
+    {
+        let mut inner = self.inner.borrow_mut();
+        let id = inner.next_id;
+        inner.next_id += 1;
+        inner
+            .subscribers
+            .entry(TypeId::of::<T>())
+            .or_default()
+            .push((id, Rc::new(callback)));
+
+        EventSubscription {
+            bus: self.clone(),
+            type_id: TypeId::of::<T>(),
+            id,
+        }
+    }
+}
+
+/// A subscription of an [`EventBus`], unsubscribing when dropped.
+pub struct EventSubscription {
+    bus: EventBus,
+    type_id: TypeId,
+    id: usize,
+}
+
+impl Drop for EventSubscription {
+    fn drop(&mut self) {
+        let mut inner = self.bus.inner.borrow_mut();
+        if let Some(subscribers) = inner.subscribers.get_mut(&self.type_id) {
+            subscribers.retain(|(id, _)| *id != self.id);
+            if subscribers.is_empty() {
+                inner.subscribers.remove(&self.type_id);
+            }
+        }
+    }
+}
+
+/// Properties of the [`EventBusProvider`].
+#[derive(PartialEq, Properties)]
+pub struct EventBusProviderProperties {
+    /// The components, which can use the event bus.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Provide an event bus to the components below it.
+#[function_component(EventBusProvider)]
+pub fn event_bus_provider(props: &EventBusProviderProperties) -> Html {
+    let context = (*use_memo((), |()| EventBus::default())).clone();
+
+    html!(
+        <ContextProvider<EventBus> {context}>
+            { props.children.clone() }
+        </ContextProvider<EventBus>>
+    )
+}
+
+/// Get the event bus of the nearest [`EventBusProvider`].
+///
+/// This panics if there is no provider further up the component tree.
+#[hook]
+pub fn use_event_bus() -> EventBus {
+    use_context::<EventBus>().expect("use_event_bus requires an EventBusProvider")
+}
+
+/// Publish events of a type to the event bus.
+///
+/// Returns a stable callback, sending events to all subscribers of the type. See
+/// [`use_event_subscriber`] for an example.
+#[hook]
+pub fn use_event_publisher<T>() -> Callback<T>
+where
+    T: Clone + 'static,
+{
+    let bus = use_event_bus();
+    use_callback(bus, |event: T, bus| bus.publish(event))
+}
+
+/// Subscribe to events of a type on the event bus.
+///
+/// The handler receives all events of the type, published while the component is mounted.
+/// Changing the handler doesn't re-subscribe, the latest handler will always be used.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(Clone)]
+/// struct ItemAdded(String);
+///
+/// #[function_component(AddItem)]
+/// fn add_item() -> Html {
+///   let publish = use_event_publisher::<ItemAdded>();
+///   let onclick = use_callback(publish, |_, publish| publish.emit(ItemAdded("Item".into())));
+///   html!(<button {onclick}>{"Add"}</button>)
+/// }
+///
+/// #[function_component(ItemCount)]
+/// fn item_count() -> Html {
+///   let count = use_state_eq(|| 0);
+///   {
+///     let count = count.clone();
+///     use_event_subscriber(move |_: ItemAdded| count.set(*count + 1));
+///   }
+///   html!({ *count })
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///   html!(
+///     <EventBusProvider>
+///       <AddItem />
+///       <ItemCount />
+///     </EventBusProvider>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_event_subscriber<T, F>(handler: F)
+where
+    T: 'static,
+    F: Fn(T) + 'static,
+{
+    let bus = use_event_bus();
+    let handler = use_latest(handler);
+
+    use_effect_with(bus, move |bus| {
+        let subscription = bus.subscribe(Callback::from(move |event: T| (handler.borrow())(event)));
+        move || drop(subscription)
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_publish() {
+        let bus = EventBus::default();
+        let received = Rc::new(Cell::new(0));
+
+        let subscription = {
+            let received = received.clone();
+            bus.subscribe(Callback::from(move |n: u32| {
+                received.set(received.get() + n)
+            }))
+        };
+
+        bus.publish(1u32);
+        // other types are not received
+        bus.publish(2u64);
+        assert_eq!(received.get(), 1);
+
+        drop(subscription);
+        bus.publish(1u32);
+        assert_eq!(received.get(), 1);
+        assert!(bus.inner.borrow().subscribers.is_empty());
+    }
+}
@@ -0,0 +1,226 @@
+//! Listen to events
+//!
+//! ## Lifecycle
+//!
+//! The listener is attached once the target is available (after the first render, in the case of
+//! a [`NodeRef`]). It gets re-attached when the target node, the event type, or the options
+//! change. It will be removed when the component gets unmounted.
+//!
+//! Changing the callback does not re-attach the listener, the latest callback will always be
+//! used.
+
+use crate::hooks::use_latest;
+use gloo_events::{EventListener, EventListenerOptions, EventListenerPhase};
+use std::borrow::Cow;
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+/// The target of an event listener.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum ListenerTarget {
+    /// A node, referenced by a [`NodeRef`].
+    Node(NodeRef),
+    /// The window.
+    #[default]
+    Window,
+    /// The document.
+    Document,
+}
+
+impl ListenerTarget {
+    /// Get the current event target, if it is available.
+    pub fn get(&self) -> Option<web_sys::EventTarget> {
+        match self {
+            Self::Node(node) => node.get().map(Into::into),
+            Self::Window => Some(gloo_utils::window().into()),
+            Self::Document => Some(gloo_utils::document().into()),
+        }
+    }
+}
+
+impl From<NodeRef> for ListenerTarget {
+    fn from(node: NodeRef) -> Self {
+        Self::Node(node)
+    }
+}
+
+impl From<&NodeRef> for ListenerTarget {
+    fn from(node: &NodeRef) -> Self {
+        Self::Node(node.clone())
+    }
+}
+
+/// Options for [`use_event_listener_with_options`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UseEventListenerOptions {
+    /// Listen during the capture phase, instead of the bubble phase.
+    pub capture: bool,
+    /// Mark the listener as passive, promising not to call `prevent_default`.
+    pub passive: bool,
+}
+
+impl UseEventListenerOptions {
+    /// Listen during the capture phase
+    pub const fn enable_capture() -> Self {
+        Self {
+            capture: true,
+            passive: false,
+        }
+    }
+
+    /// Register as passive listener
+    pub const fn enable_passive() -> Self {
+        Self {
+            capture: false,
+            passive: true,
+        }
+    }
+}
+
+impl From<UseEventListenerOptions> for EventListenerOptions {
+    fn from(options: UseEventListenerOptions) -> Self {
+        Self {
+            phase: match options.capture {
+                true => EventListenerPhase::Capture,
+                false => EventListenerPhase::Bubble,
+            },
+            passive: options.passive,
+        }
+    }
+}
+
+struct Listener {
+    target: web_sys::EventTarget,
+    event_type: Cow<'static, str>,
+    options: UseEventListenerOptions,
+    _listener: EventListener,
+}
+
+/// Listen to events of a target, forwarding them to a callback.
+///
+/// This is [`use_event_listener_with_options`] using the default options: not passive, listening
+/// during the bubble phase.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let node = use_node_ref();
+///   let onclick = use_callback((), |_: MouseEvent, ()| {
+///     // handle click
+///   });
+///   use_event_listener(node.clone(), "click", onclick);
+///
+///   html!(<div ref={node}></div>)
+/// }
+/// ```
+#[hook]
+pub fn use_event_listener<T, S, E>(target: T, event_type: S, callback: Callback<E>)
+where
+    T: Into<ListenerTarget>,
+    S: Into<Cow<'static, str>>,
+    E: JsCast + 'static,
+{
+    use_event_listener_with_options(
+        target,
+        event_type,
+        callback,
+        UseEventListenerOptions::default(),
+    )
+}
+
+/// Listen to events of a target, forwarding them to a callback, using options.
+///
+/// The event will be cast to the type `E` of the callback. Events which cannot be cast will be
+/// ignored. See the [module documentation](self) for the lifecycle of the listener.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let onscroll = use_callback((), |_: Event, ()| {
+///     // handle scroll
+///   });
+///   use_event_listener_with_options(
+///     ListenerTarget::Window,
+///     "scroll",
+///     onscroll,
+///     UseEventListenerOptions::enable_passive(),
+///   );
+///
+///   html!()
+/// }
+/// ```
+#[hook]
+pub fn use_event_listener_with_options<T, S, E>(
+    target: T,
+    event_type: S,
+    callback: Callback<E>,
+    options: UseEventListenerOptions,
+) where
+    T: Into<ListenerTarget>,
+    S: Into<Cow<'static, str>>,
+    E: JsCast + 'static,
+{
+    let target = target.into();
+    let event_type = event_type.into();
+    let callback = use_latest(callback);
+    let listener = use_mut_ref(|| None::<Listener>);
+
+    {
+        let listener = listener.clone();
+        use_effect(move || {
+            let current = target.get();
+            let mut listener = listener.borrow_mut();
+
+            let unchanged = match (&*listener, &current) {
+                (Some(listener), Some(current)) => {
+                    listener.target == *current
+                        && listener.event_type == event_type
+                        && listener.options == options
+                }
+                (None, None) => true,
+                _ => false,
+            };
+
+            if !unchanged {
+                *listener = current.map(|current| {
+                    let _listener = EventListener::new_with_options(
+                        &current,
+                        event_type.clone(),
+                        options.into(),
+                        move |event| {
+                            if let Ok(event) = event.clone().dyn_into::<E>() {
+                                // release the borrow before emitting
+                                let callback = callback.borrow().clone();
+                                callback.emit(event);
+                            }
+                        },
+                    );
+                    Listener {
+                        target: current,
+                        event_type,
+                        options,
+                        _listener,
+                    }
+                });
+            }
+
+            || {}
+        });
+    }
+
+    use_effect_with((), move |()| {
+        move || {
+            listener.borrow_mut().take();
+        }
+    });
+}
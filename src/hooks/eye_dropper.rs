@@ -0,0 +1,190 @@
+//! Hooks for picking colors from the screen
+
+use crate::hooks::UseAsyncState;
+use std::{
+    cell::RefCell,
+    fmt::{Display, Formatter},
+    ops::Deref,
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use yew::prelude::*;
+
+/// An error picking a color.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EyeDropperError {
+    /// The EyeDropper API is not supported by the browser.
+    Unsupported,
+    /// Opening the eye dropper failed.
+    Failed(String),
+}
+
+impl Display for EyeDropperError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str("EyeDropper API is not supported"),
+            Self::Failed(msg) => write!(f, "Failed to pick a color: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for EyeDropperError {}
+
+fn eye_dropper_class() -> Option<js_sys::Function> {
+    js_sys::Reflect::get(&gloo_utils::window(), &"EyeDropper".into())
+        .ok()?
+        .dyn_into()
+        .ok()
+}
+
+/// Open the eye dropper, returning the picked color, or `None` if cancelled.
+async fn pick(signal: web_sys::AbortSignal) -> Result<Option<String>, EyeDropperError> {
+    let class = eye_dropper_class().ok_or(EyeDropperError::Unsupported)?;
+    let failed = |err: JsValue| match err.dyn_ref::<web_sys::DomException>() {
+        Some(err) => EyeDropperError::Failed(err.message()),
+        None => EyeDropperError::Failed(format!("{err:?}")),
+    };
+
+    let dropper = js_sys::Reflect::construct(&class, &js_sys::Array::new()).map_err(failed)?;
+    let open: js_sys::Function = js_sys::Reflect::get(&dropper, &"open".into())
+        .and_then(|open| open.dyn_into())
+        .map_err(failed)?;
+
+    let options = js_sys::Object::new();
+    js_sys::Reflect::set(&options, &"signal".into(), &signal).map_err(failed)?;
+    let promise: js_sys::Promise = open
+        .call1(&dropper, &options)
+        .and_then(|promise| promise.dyn_into())
+        .map_err(failed)?;
+
+    match JsFuture::from(promise).await {
+        Ok(result) => Ok(js_sys::Reflect::get(&result, &"sRGBHex".into())
+            .ok()
+            .and_then(|color| color.as_string())),
+        Err(err)
+            if err
+                .dyn_ref::<web_sys::DomException>()
+                .is_some_and(|err| err.name() == "AbortError") =>
+        {
+            Ok(None)
+        }
+        Err(err) => Err(failed(err)),
+    }
+}
+
+/// State handle for the [`use_eye_dropper`] hook.
+#[derive(Clone)]
+pub struct UseEyeDropperHandle {
+    state: UseStateHandle<UseAsyncState<String, EyeDropperError>>,
+    controller: Rc<RefCell<Option<web_sys::AbortController>>>,
+    supported: bool,
+}
+
+impl UseEyeDropperHandle {
+    /// Check if the browser supports the EyeDropper API.
+    pub fn is_supported(&self) -> bool {
+        self.supported
+    }
+
+    /// Open the eye dropper, cancelling a previously opened one.
+    ///
+    /// Browsers only allow this in response to a user interaction, like a click. Cancelling the
+    /// eye dropper keeps the previously picked color.
+    pub fn open(&self) {
+        let Ok(controller) = web_sys::AbortController::new() else {
+            return;
+        };
+        let signal = controller.signal();
+        if let Some(previous) = self.controller.replace(Some(controller)) {
+            previous.abort();
+        }
+
+        let previous = match &*self.state {
+            UseAsyncState::Processing => UseAsyncState::Pending,
+            state => state.clone(),
+        };
+        self.state.set(UseAsyncState::Processing);
+
+        let state = self.state.clone();
+        spawn_local(async move {
+            let result = pick(signal.clone()).await;
+            if signal.aborted() {
+                // replaced by another one, or unmounted
+                return;
+            }
+            match result {
+                Ok(Some(color)) => state.set(UseAsyncState::Ready(Ok(color))),
+                Ok(None) => state.set(previous),
+                Err(err) => state.set(UseAsyncState::Ready(Err(err))),
+            }
+        });
+    }
+}
+
+impl PartialEq for UseEyeDropperHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && Rc::ptr_eq(&self.controller, &other.controller)
+    }
+}
+
+impl Deref for UseEyeDropperHandle {
+    type Target = UseAsyncState<String, EyeDropperError>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// Pick a color from the screen.
+///
+/// Returns a handle for opening the eye dropper of the
+/// [EyeDropper API](https://developer.mozilla.org/en-US/docs/Web/API/EyeDropper_API), which
+/// dereferences to the state of picking a color. A picked color is reported in the hexadecimal
+/// sRGB format, like `#ff8800`. An open eye dropper is closed when the component is unmounted.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(ColorPicker)]
+/// fn color_picker() -> Html {
+///   let dropper = use_eye_dropper();
+///   let onclick = use_callback(dropper.clone(), |_, dropper| dropper.open());
+///   let color = dropper.data().cloned().unwrap_or_else(|| "#000000".into());
+///
+///   html!(
+///     <>
+///       <span style={format!("background: {color}")}>{ &color }</span>
+///       if dropper.is_supported() {
+///         <button {onclick} disabled={dropper.is_processing()}>{"Pick from screen"}</button>
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_eye_dropper() -> UseEyeDropperHandle {
+    let state = use_state_eq(UseAsyncState::<String, EyeDropperError>::default);
+    let controller = use_mut_ref(|| None::<web_sys::AbortController>);
+    let supported = *use_memo((), |()| eye_dropper_class().is_some());
+
+    {
+        let controller = controller.clone();
+        use_effect_with((), move |()| {
+            move || {
+                if let Some(controller) = controller.borrow_mut().take() {
+                    controller.abort();
+                }
+            }
+        });
+    }
+
+    UseEyeDropperHandle {
+        state,
+        controller,
+        supported,
+    }
+}
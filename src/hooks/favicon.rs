@@ -0,0 +1,90 @@
+//! Hooks for the favicon of the page
+
+use yew::prelude::*;
+
+/// Create a `data:` URL from an SVG image, which can be used as favicon.
+///
+/// This allows generating favicons, like a badge with the number of unread messages.
+pub fn svg_data_url(svg: &str) -> String {
+    format!(
+        "data:image/svg+xml,{}",
+        String::from(js_sys::encode_uri_component(svg))
+    )
+}
+
+/// Set the favicon of the page.
+///
+/// Replaces the location of the first existing icon link of the document, or adds a new one if
+/// there is none. The original favicon is restored when the component is unmounted.
+///
+/// The location can be a URL, or a `data:` URL, e.g. created using [`svg_data_url`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   unread: usize,
+/// }
+///
+/// #[function_component(Example)]
+/// fn example(props: &Props) -> Html {
+///   let favicon = use_memo(props.unread, |unread| match unread {
+///     0 => "/favicon.ico".to_string(),
+///     n => svg_data_url(&format!(
+///       r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 16 16"><circle cx="8" cy="8" r="8" fill="red"/><text x="8" y="12" font-size="10" text-anchor="middle" fill="white">{n}</text></svg>"#
+///     )),
+///   });
+///   use_favicon((*favicon).clone());
+///
+///   html!()
+/// }
+/// ```
+#[hook]
+pub fn use_favicon(href: impl Into<AttrValue>) {
+    use_effect_with(href.into(), |href| {
+        let document = gloo_utils::document();
+        let existing = document.query_selector("link[rel~='icon']").ok().flatten();
+
+        let (link, restore) = match existing {
+            Some(link) => {
+                let original = (link.get_attribute("href"), link.get_attribute("type"));
+                (Some(link), Some(original))
+            }
+            None => {
+                let link = document.create_element("link").ok();
+                if let (Some(link), Some(head)) = (&link, document.head()) {
+                    let _ = link.set_attribute("rel", "icon");
+                    let _ = head.append_child(link);
+                }
+                (link, None)
+            }
+        };
+
+        if let Some(link) = &link {
+            // the type of the original icon might not match
+            let _ = link.remove_attribute("type");
+            let _ = link.set_attribute("href", href);
+        }
+
+        move || {
+            let Some(link) = link else {
+                return;
+            };
+            match restore {
+                Some((href, r#type)) => {
+                    for (name, value) in [("href", href), ("type", r#type)] {
+                        let _ = match value {
+                            Some(value) => link.set_attribute(name, &value),
+                            None => link.remove_attribute(name),
+                        };
+                    }
+                }
+                None => link.remove(),
+            }
+        }
+    });
+}
@@ -0,0 +1,347 @@
+//! Hooks for single input fields, with validation
+
+use crate::hooks::use_latest;
+use std::{future::Future, ops::Deref, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+/// A validator of a field value, returning an error message for invalid values.
+pub type Validator = Rc<dyn Fn(&str) -> Result<(), String>>;
+
+/// The definition of a field.
+#[derive(Clone)]
+pub struct Field {
+    initial: String,
+    validators: Vec<Validator>,
+}
+
+impl Field {
+    /// Create a field with an initial value, without validators.
+    pub fn new(initial: impl Into<String>) -> Self {
+        Self {
+            initial: initial.into(),
+            validators: vec![],
+        }
+    }
+
+    /// Add a validator, which is checked after the ones added before.
+    pub fn validate<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + 'static,
+    {
+        self.validators.push(Rc::new(f));
+        self
+    }
+
+    /// Validate a value, returning the error of the first failing validator.
+    fn check(&self, value: &str) -> Option<String> {
+        self.validators
+            .iter()
+            .find_map(|validator| validator(value).err())
+    }
+}
+
+impl PartialEq for Field {
+    fn eq(&self, other: &Self) -> bool {
+        self.initial == other.initial
+            && self.validators.len() == other.validators.len()
+            && self
+                .validators
+                .iter()
+                .zip(&other.validators)
+                .all(|(a, b)| Rc::ptr_eq(a, b))
+    }
+}
+
+/// The state of a field.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldState {
+    /// The current value.
+    pub value: String,
+    /// The value the field is compared against, for checking if it's dirty.
+    pub initial: String,
+    /// If the field was visited by the user.
+    pub touched: bool,
+    /// The validation error of the current value.
+    pub error: Option<String>,
+}
+
+impl FieldState {
+    /// The initial state of a field.
+    pub fn new(field: &Field) -> Self {
+        Self {
+            value: field.initial.clone(),
+            initial: field.initial.clone(),
+            touched: false,
+            error: field.check(&field.initial),
+        }
+    }
+
+    /// Check if the value differs from the initial value.
+    pub fn is_dirty(&self) -> bool {
+        self.value != self.initial
+    }
+
+    /// Check if the current value passed validation.
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Set and validate a new value.
+    pub(crate) fn set(&mut self, field: &Field, value: String) {
+        self.error = field.check(&value);
+        self.value = value;
+    }
+
+    /// Go back to the initial value, and to not being touched.
+    pub(crate) fn reset(&mut self, field: &Field) {
+        self.value = self.initial.clone();
+        self.touched = false;
+        self.error = field.check(&self.value);
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct SingleField {
+    field: Field,
+    state: FieldState,
+    validating: bool,
+}
+
+enum FieldAction {
+    Set(String),
+    Touch,
+    Reset,
+    /// Start validating a value asynchronously.
+    Validating(String),
+    /// The result of validating a value asynchronously.
+    Validated(String, Option<String>),
+}
+
+impl Reducible for SingleField {
+    type Action = FieldAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut next = (*self).clone();
+        match action {
+            FieldAction::Set(value) => {
+                next.state.set(&next.field, value);
+                next.validating = false;
+            }
+            FieldAction::Touch => next.state.touched = true,
+            FieldAction::Reset => {
+                next.state.reset(&next.field);
+                next.validating = false;
+            }
+            // results for outdated values are ignored
+            FieldAction::Validating(value) if value == self.state.value => next.validating = true,
+            FieldAction::Validated(value, error) if value == self.state.value => {
+                next.state.error = error;
+                next.validating = false;
+            }
+            FieldAction::Validating(_) | FieldAction::Validated(..) => return self,
+        }
+
+        match next == *self {
+            true => self,
+            false => Rc::new(next),
+        }
+    }
+}
+
+/// State handle for the [`use_field`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseFieldHandle {
+    state: UseReducerHandle<SingleField>,
+}
+
+impl UseFieldHandle {
+    /// The error of the field, once it was touched.
+    pub fn error(&self) -> Option<&str> {
+        let state = &self.state.state;
+        state.error.as_deref().filter(|_| state.touched)
+    }
+
+    /// Check if the value is being validated asynchronously.
+    pub fn is_validating(&self) -> bool {
+        self.state.validating
+    }
+
+    /// Set and validate a new value.
+    pub fn set(&self, value: impl Into<String>) {
+        self.state.dispatch(FieldAction::Set(value.into()));
+    }
+
+    /// Mark the field as touched, showing its error.
+    pub fn touch(&self) {
+        self.state.dispatch(FieldAction::Touch);
+    }
+
+    /// Go back to the initial value.
+    pub fn reset(&self) {
+        self.state.dispatch(FieldAction::Reset);
+    }
+
+    /// Create an input handler, setting the value from an input element.
+    pub fn oninput(&self) -> Callback<InputEvent> {
+        let state = self.state.clone();
+        Callback::from(move |e: InputEvent| {
+            let value = e
+                .target_unchecked_into::<web_sys::HtmlInputElement>()
+                .value();
+            state.dispatch(FieldAction::Set(value));
+        })
+    }
+
+    /// Create a blur handler, marking the field as touched.
+    pub fn onblur(&self) -> Callback<FocusEvent> {
+        let state = self.state.clone();
+        Callback::from(move |_| state.dispatch(FieldAction::Touch))
+    }
+}
+
+impl Deref for UseFieldHandle {
+    type Target = FieldState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state.state
+    }
+}
+
+/// A single input field, with validation.
+///
+/// The handle dereferences to the state of the field, and provides input and blur handlers. Errors
+/// are only reported once the field was touched. The same field definition is used by
+/// [`use_form`](crate::hooks::use_form) for each of its fields.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Name)]
+/// fn name() -> Html {
+///   let name = use_field(|| {
+///     Field::new("").validate(|value| match value.trim().is_empty() {
+///       true => Err("Required".into()),
+///       false => Ok(()),
+///     })
+///   });
+///
+///   html!(
+///     <>
+///       <input value={name.value.clone()} oninput={name.oninput()} onblur={name.onblur()} />
+///       if let Some(error) = name.error() { <p>{ error }</p> }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_field<I>(init: I) -> UseFieldHandle
+where
+    I: FnOnce() -> Field,
+{
+    let state = use_reducer(move || {
+        let field = init();
+        SingleField {
+            state: FieldState::new(&field),
+            field,
+            validating: false,
+        }
+    });
+    UseFieldHandle { state }
+}
+
+/// A single input field, with an additional async validator.
+///
+/// This works like [`use_field`], but once a value passes the validators of the field, it is also
+/// validated by the async `validator`, like for checking with a server. Results of values which
+/// changed in the meantime are discarded.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// async fn check_available(username: String) -> Result<(), String> {
+///   // ask the server
+///   match username == "admin" {
+///     true => Err("Already taken".into()),
+///     false => Ok(()),
+///   }
+/// }
+///
+/// #[function_component(Username)]
+/// fn username() -> Html {
+///   let username = use_field_with_async_validator(|| Field::new(""), check_available);
+///
+///   html!(
+///     <>
+///       <input value={username.value.clone()} oninput={username.oninput()} onblur={username.onblur()} />
+///       if username.is_validating() { <p>{"Checking…"}</p> }
+///       if let Some(error) = username.error() { <p>{ error }</p> }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_field_with_async_validator<I, F, Fut>(init: I, validator: F) -> UseFieldHandle
+where
+    I: FnOnce() -> Field,
+    F: Fn(String) -> Fut + 'static,
+    Fut: Future<Output = Result<(), String>> + 'static,
+{
+    let handle = use_field(init);
+    let validator = use_latest(validator);
+
+    {
+        let state = handle.state.clone();
+        let value = handle.value.clone();
+        let valid = handle.is_valid();
+        use_effect_with((value, valid), move |(value, valid)| {
+            if *valid {
+                state.dispatch(FieldAction::Validating(value.clone()));
+                let future = (validator.borrow())(value.clone());
+                let value = value.clone();
+                spawn_local(async move {
+                    let error = future.await.err();
+                    state.dispatch(FieldAction::Validated(value, error));
+                });
+            }
+        });
+    }
+
+    handle
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_validated() {
+        let field = Field::new("");
+        let state = Rc::new(SingleField {
+            state: FieldState::new(&field),
+            field,
+            validating: false,
+        });
+
+        let state = state.reduce(FieldAction::Set("foo".into()));
+        let state = state.reduce(FieldAction::Validating("foo".into()));
+        assert!(state.validating);
+
+        // the result of an outdated value is ignored
+        let state = state.reduce(FieldAction::Set("bar".into()));
+        let unchanged = state
+            .clone()
+            .reduce(FieldAction::Validated("foo".into(), Some("Taken".into())));
+        assert!(Rc::ptr_eq(&state, &unchanged));
+
+        let state = state.reduce(FieldAction::Validated("bar".into(), Some("Taken".into())));
+        assert_eq!(state.state.error.as_deref(), Some("Taken"));
+        assert!(!state.validating);
+    }
+}
@@ -0,0 +1,395 @@
+//! Hooks for picking files
+//!
+//! Uses the [File System Access API](https://developer.mozilla.org/en-US/docs/Web/API/File_System_API)
+//! where available, which allows writing back to picked files. Other browsers fall back to an
+//! `<input type="file">` element for opening, and a download for saving files.
+
+use crate::hooks::{use_latest, UseAsyncState};
+use std::{
+    fmt::{Display, Formatter},
+    ops::Deref,
+};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use yew::prelude::*;
+
+/// An error picking, reading, or writing files.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FilePickerError {
+    /// The user cancelled the picker.
+    Cancelled,
+    /// Accessing the file failed.
+    Failed(String),
+}
+
+impl Display for FilePickerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cancelled => f.write_str("Cancelled by the user"),
+            Self::Failed(msg) => write!(f, "Failed to access file: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for FilePickerError {}
+
+impl From<JsValue> for FilePickerError {
+    fn from(err: JsValue) -> Self {
+        match err.dyn_ref::<web_sys::DomException>() {
+            Some(err) if err.name() == "AbortError" => Self::Cancelled,
+            Some(err) => Self::Failed(err.message()),
+            None => match err.dyn_ref::<js_sys::Error>() {
+                Some(err) => Self::Failed(err.message().into()),
+                None => Self::Failed(format!("{err:?}")),
+            },
+        }
+    }
+}
+
+/// A type of file, offered by the picker.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FilePickerType {
+    /// The description of the type, shown in the picker.
+    pub description: String,
+    /// MIME types, with their file extensions (including the dot), like
+    /// `("image/png", vec![".png"])`.
+    pub accept: Vec<(String, Vec<String>)>,
+}
+
+impl FilePickerType {
+    /// Create a type with a description, not accepting any files yet.
+    pub fn new(description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            accept: vec![],
+        }
+    }
+
+    /// Accept a MIME type, with its file extensions.
+    pub fn accept<I, S>(mut self, mime: impl Into<String>, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.accept.push((
+            mime.into(),
+            extensions.into_iter().map(Into::into).collect(),
+        ));
+        self
+    }
+}
+
+fn types_to_js(types: &[FilePickerType]) -> js_sys::Array {
+    types
+        .iter()
+        .map(|r#type| {
+            let accept = js_sys::Object::new();
+            for (mime, extensions) in &r#type.accept {
+                let extensions = extensions
+                    .iter()
+                    .map(|ext| JsValue::from_str(ext))
+                    .collect::<js_sys::Array>();
+                let _ = js_sys::Reflect::set(&accept, &mime.into(), &extensions);
+            }
+            let result = js_sys::Object::new();
+            let _ = js_sys::Reflect::set(
+                &result,
+                &"description".into(),
+                &(&r#type.description).into(),
+            );
+            let _ = js_sys::Reflect::set(&result, &"accept".into(), &accept);
+            JsValue::from(result)
+        })
+        .collect()
+}
+
+/// Options for opening files using [`use_file_picker`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OpenFilePickerOptions {
+    /// Allow picking multiple files.
+    pub multiple: bool,
+    /// The types of files to offer. If empty, all files are offered.
+    pub types: Vec<FilePickerType>,
+}
+
+impl OpenFilePickerOptions {
+    fn to_js(&self) -> js_sys::Object {
+        let result = js_sys::Object::new();
+        let _ = js_sys::Reflect::set(&result, &"multiple".into(), &self.multiple.into());
+        if !self.types.is_empty() {
+            let _ = js_sys::Reflect::set(&result, &"types".into(), &types_to_js(&self.types));
+        }
+        result
+    }
+
+    /// The value for the `accept` attribute of the fallback input.
+    fn to_accept(&self) -> String {
+        self.types
+            .iter()
+            .flat_map(|r#type| &r#type.accept)
+            .flat_map(|(mime, extensions)| std::iter::once(mime).chain(extensions))
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Options for [`save_file`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SaveFilePickerOptions {
+    /// The name of the file, suggested to the user.
+    pub suggested_name: Option<String>,
+    /// The types of files to offer.
+    pub types: Vec<FilePickerType>,
+}
+
+impl SaveFilePickerOptions {
+    fn to_js(&self) -> js_sys::Object {
+        let result = js_sys::Object::new();
+        if let Some(name) = &self.suggested_name {
+            let _ = js_sys::Reflect::set(&result, &"suggestedName".into(), &name.into());
+        }
+        if !self.types.is_empty() {
+            let _ = js_sys::Reflect::set(&result, &"types".into(), &types_to_js(&self.types));
+        }
+        result
+    }
+}
+
+fn picker(name: &str) -> Option<js_sys::Function> {
+    js_sys::Reflect::get(&gloo_utils::window(), &name.into())
+        .ok()?
+        .dyn_into()
+        .ok()
+}
+
+async fn call_method(
+    target: &JsValue,
+    name: &str,
+    args: &js_sys::Array,
+) -> Result<JsValue, JsValue> {
+    let method: js_sys::Function = js_sys::Reflect::get(target, &name.into())?.dyn_into()?;
+    let promise: js_sys::Promise = method.apply(target, args)?.dyn_into()?;
+    JsFuture::from(promise).await
+}
+
+/// A handle to a file in the file system, from the File System Access API.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileHandle {
+    handle: JsValue,
+}
+
+impl FileHandle {
+    /// The name of the file.
+    pub fn name(&self) -> String {
+        js_sys::Reflect::get(&self.handle, &"name".into())
+            .ok()
+            .and_then(|name| name.as_string())
+            .unwrap_or_default()
+    }
+
+    /// Read the current state of the file.
+    pub async fn file(&self) -> Result<web_sys::File, FilePickerError> {
+        let file = call_method(&self.handle, "getFile", &js_sys::Array::new()).await?;
+        Ok(file.unchecked_into())
+    }
+
+    /// Replace the content of the file.
+    ///
+    /// Browsers might ask the user for permission first.
+    pub async fn write(&self, data: &[u8]) -> Result<(), FilePickerError> {
+        let stream = call_method(&self.handle, "createWritable", &js_sys::Array::new()).await?;
+        let data = js_sys::Uint8Array::from(data);
+        call_method(&stream, "write", &js_sys::Array::of1(&data)).await?;
+        call_method(&stream, "close", &js_sys::Array::new()).await?;
+        Ok(())
+    }
+}
+
+/// A picked file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PickedFile {
+    /// The picked file, for reading its content.
+    pub file: web_sys::File,
+    /// The handle for writing back to the file, if supported by the browser.
+    pub handle: Option<FileHandle>,
+}
+
+async fn open_native(
+    picker: js_sys::Function,
+    options: js_sys::Object,
+) -> Result<Vec<PickedFile>, FilePickerError> {
+    let promise: js_sys::Promise = picker.call1(&gloo_utils::window(), &options)?.dyn_into()?;
+    let handles = JsFuture::from(promise).await?;
+
+    let mut result = vec![];
+    for handle in js_sys::Array::from(&handles) {
+        let handle = FileHandle { handle };
+        result.push(PickedFile {
+            file: handle.file().await?,
+            handle: Some(handle),
+        });
+    }
+    Ok(result)
+}
+
+fn open_fallback(options: &OpenFilePickerOptions, onpicked: Callback<Vec<PickedFile>>) {
+    let Ok(input) = gloo_utils::document().create_element("input") else {
+        return;
+    };
+    let input = input.unchecked_into::<web_sys::HtmlInputElement>();
+    input.set_type("file");
+    input.set_multiple(options.multiple);
+    input.set_accept(&options.to_accept());
+
+    // handed over to JavaScript, living as long as the input
+    let onchange = {
+        let input = input.clone();
+        Closure::once_into_js(move || {
+            let files = input
+                .files()
+                .map(|files| {
+                    (0..files.length())
+                        .filter_map(|i| files.get(i))
+                        .map(|file| PickedFile { file, handle: None })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            if !files.is_empty() {
+                onpicked.emit(files);
+            }
+        })
+    };
+    let _ = input.add_event_listener_with_callback("change", onchange.unchecked_ref());
+    input.click();
+}
+
+/// Save data to a file, picked by the user.
+///
+/// Returns the handle of the file, for writing to it again later. If the File System Access API
+/// isn't supported, the data is offered as download instead, and `None` is returned.
+pub async fn save_file(
+    options: SaveFilePickerOptions,
+    data: impl Into<Vec<u8>>,
+) -> Result<Option<FileHandle>, FilePickerError> {
+    let data = data.into();
+
+    if let Some(picker) = picker("showSaveFilePicker") {
+        let promise: js_sys::Promise = picker
+            .call1(&gloo_utils::window(), &options.to_js())?
+            .dyn_into()?;
+        let handle = FileHandle {
+            handle: JsFuture::from(promise).await?,
+        };
+        handle.write(&data).await?;
+        return Ok(Some(handle));
+    }
+
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(data.as_slice()));
+    let blob = web_sys::Blob::new_with_u8_array_sequence(&parts)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+
+    let anchor = gloo_utils::document()
+        .create_element("a")?
+        .unchecked_into::<web_sys::HtmlAnchorElement>();
+    anchor.set_href(&url);
+    anchor.set_download(options.suggested_name.as_deref().unwrap_or("download"));
+    anchor.click();
+
+    // revoking right away might cancel the download in some browsers
+    let revoke = Closure::once_into_js(move || {
+        let _ = web_sys::Url::revoke_object_url(&url);
+    });
+    gloo_utils::window().set_timeout_with_callback(revoke.unchecked_ref())?;
+    Ok(None)
+}
+
+/// State handle for the [`use_file_picker`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseFilePickerHandle {
+    state: UseStateHandle<UseAsyncState<Vec<PickedFile>, FilePickerError>>,
+    open: Callback<()>,
+}
+
+impl UseFilePickerHandle {
+    /// Open the picker.
+    ///
+    /// Browsers only allow this in response to a user interaction, like a click.
+    pub fn open(&self) {
+        self.open.emit(());
+    }
+}
+
+impl Deref for UseFilePickerHandle {
+    type Target = UseAsyncState<Vec<PickedFile>, FilePickerError>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// Let the user pick files to open.
+///
+/// Opens a picker using the latest options, and provides the picked files as state. Using the
+/// File System Access API, picked files come with a handle, which allows writing back to them.
+/// Otherwise, files are picked using an `<input type="file">` element.
+///
+/// The state is [`UseAsyncState::Processing`] while the picker is shown, which is only tracked
+/// with the File System Access API. Cancelling the picker keeps the previously picked files.
+///
+/// For saving files, see [`save_file`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let picker = use_file_picker(OpenFilePickerOptions {
+///     multiple: true,
+///     types: vec![FilePickerType::new("Images").accept("image/*", [".png", ".jpg"])],
+///   });
+///   let onclick = use_callback(picker.clone(), |_, picker| picker.open());
+///
+///   html!(
+///     <>
+///       <button {onclick}>{"Open images"}</button>
+///       <ul>
+///         { for picker.data().into_iter().flatten().map(|picked| html!(<li>{ picked.file.name() }</li>)) }
+///       </ul>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_file_picker(options: OpenFilePickerOptions) -> UseFilePickerHandle {
+    let state = use_state(UseAsyncState::<Vec<PickedFile>, FilePickerError>::default);
+    let options = use_latest(options);
+
+    let open = use_callback(state.clone(), move |(), state| {
+        let options = options.borrow().clone();
+
+        let Some(picker) = picker("showOpenFilePicker") else {
+            let state = state.clone();
+            open_fallback(
+                &options,
+                Callback::from(move |files| state.set(UseAsyncState::Ready(Ok(files)))),
+            );
+            return;
+        };
+
+        let previous = (**state).clone();
+        state.set(UseAsyncState::Processing);
+        let state = state.clone();
+        spawn_local(async move {
+            match open_native(picker, options.to_js()).await {
+                Err(FilePickerError::Cancelled) => state.set(previous),
+                result => state.set(UseAsyncState::Ready(result)),
+            }
+        });
+    });
+
+    UseFilePickerHandle { state, open }
+}
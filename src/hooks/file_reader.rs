@@ -0,0 +1,249 @@
+//! Hooks for reading files
+
+use crate::hooks::UseAsyncState;
+use gloo_events::EventListener;
+use std::{
+    fmt::{Display, Formatter},
+    ops::Deref,
+};
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+/// How to read the content of a file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReadAs {
+    /// As text, decoded using UTF-8.
+    #[default]
+    Text,
+    /// As raw bytes.
+    Bytes,
+    /// As a `data:` URL, which can e.g. be used as source of an image.
+    DataUrl,
+}
+
+/// The content of a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FileContent {
+    /// The text content.
+    Text(String),
+    /// The binary content.
+    Bytes(Vec<u8>),
+    /// The content as a `data:` URL.
+    DataUrl(String),
+}
+
+impl FileContent {
+    /// The content as text, if read as text or `data:` URL.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) | Self::DataUrl(text) => Some(text),
+            Self::Bytes(_) => None,
+        }
+    }
+
+    /// The content as bytes, if read as bytes.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+}
+
+/// An error reading a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FileReaderError {
+    /// Reading was aborted.
+    Aborted,
+    /// Reading failed, e.g. because the file was removed or can't be accessed.
+    Failed(String),
+}
+
+impl Display for FileReaderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Aborted => f.write_str("Reading the file was aborted"),
+            Self::Failed(msg) => write!(f, "Failed to read file: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for FileReaderError {}
+
+/// The progress of reading a file.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FileReaderProgress {
+    /// The number of bytes read.
+    pub loaded: f64,
+    /// The total number of bytes, if known.
+    pub total: Option<f64>,
+}
+
+impl FileReaderProgress {
+    /// The progress as a fraction, in the range of `0..=1`, if the total is known.
+    pub fn fraction(&self) -> Option<f64> {
+        match self.total {
+            Some(total) if total > 0f64 => Some((self.loaded / total).clamp(0f64, 1f64)),
+            _ => None,
+        }
+    }
+}
+
+fn content(reader: &web_sys::FileReader, read_as: ReadAs) -> Result<FileContent, FileReaderError> {
+    let result = reader
+        .result()
+        .map_err(|err| FileReaderError::Failed(format!("{err:?}")))?;
+    Ok(match read_as {
+        ReadAs::Text => FileContent::Text(result.as_string().unwrap_or_default()),
+        ReadAs::DataUrl => FileContent::DataUrl(result.as_string().unwrap_or_default()),
+        ReadAs::Bytes => FileContent::Bytes(js_sys::Uint8Array::new(&result).to_vec()),
+    })
+}
+
+fn error(reader: &web_sys::FileReader) -> FileReaderError {
+    match reader.error() {
+        Some(err) if err.name() == "AbortError" => FileReaderError::Aborted,
+        Some(err) => FileReaderError::Failed(err.message()),
+        None => FileReaderError::Failed("Unknown error".into()),
+    }
+}
+
+/// State handle for the [`use_file_reader`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseFileReaderHandle {
+    state: UseStateHandle<UseAsyncState<FileContent, FileReaderError>>,
+    progress: UseStateHandle<Option<FileReaderProgress>>,
+}
+
+impl UseFileReaderHandle {
+    /// The progress of reading the file, once started.
+    pub fn progress(&self) -> Option<FileReaderProgress> {
+        *self.progress
+    }
+}
+
+impl Deref for UseFileReaderHandle {
+    type Target = UseAsyncState<FileContent, FileReaderError>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// Read the content of a file.
+///
+/// Reads the file asynchronously using a
+/// [`FileReader`](https://developer.mozilla.org/en-US/docs/Web/API/FileReader), reporting the
+/// progress while reading, and the outcome once done. The file is read again when the file, or
+/// the way of reading it, changes. Reading is aborted when the component is unmounted.
+///
+/// Without a file, the state is [`UseAsyncState::Pending`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   file: Option<web_sys::File>,
+/// }
+///
+/// #[function_component(Example)]
+/// fn example(props: &Props) -> Html {
+///   let content = use_file_reader(props.file.clone(), ReadAs::Text);
+///
+///   match &*content {
+///     UseAsyncState::Pending => html!("No file selected"),
+///     UseAsyncState::Processing => html!(
+///       <progress value={content.progress().and_then(|p| p.fraction()).unwrap_or_default().to_string()} />
+///     ),
+///     UseAsyncState::Ready(Ok(content)) => html!(<pre>{ content.as_str().unwrap_or_default() }</pre>),
+///     UseAsyncState::Ready(Err(err)) => html!(err.to_string()),
+///   }
+/// }
+/// ```
+#[hook]
+pub fn use_file_reader(file: Option<web_sys::File>, read_as: ReadAs) -> UseFileReaderHandle {
+    let state = use_state(UseAsyncState::<FileContent, FileReaderError>::default);
+    let progress = use_state_eq(|| None::<FileReaderProgress>);
+
+    {
+        let state = state.clone();
+        let progress = progress.clone();
+        use_effect_with((file, read_as), move |(file, read_as)| {
+            let read_as = *read_as;
+            let mut reader = None;
+            let mut listeners = vec![];
+
+            match (file, web_sys::FileReader::new()) {
+                (None, _) => {
+                    state.set(UseAsyncState::Pending);
+                    progress.set(None);
+                }
+                (Some(_), Err(err)) => {
+                    state.set(UseAsyncState::Ready(Err(FileReaderError::Failed(format!(
+                        "{err:?}"
+                    )))));
+                    progress.set(None);
+                }
+                (Some(file), Ok(created)) => {
+                    state.set(UseAsyncState::Processing);
+                    progress.set(Some(FileReaderProgress::default()));
+
+                    listeners.push({
+                        let progress = progress.clone();
+                        EventListener::new(&created, "progress", move |event| {
+                            let event = event.unchecked_ref::<web_sys::ProgressEvent>();
+                            progress.set(Some(FileReaderProgress {
+                                loaded: event.loaded(),
+                                total: event.length_computable().then(|| event.total()),
+                            }));
+                        })
+                    });
+                    listeners.push({
+                        let reader = created.clone();
+                        let state = state.clone();
+                        EventListener::new(&created, "load", move |_| {
+                            state.set(UseAsyncState::Ready(content(&reader, read_as)));
+                        })
+                    });
+                    listeners.push({
+                        let reader = created.clone();
+                        let state = state.clone();
+                        EventListener::new(&created, "error", move |_| {
+                            state.set(UseAsyncState::Ready(Err(error(&reader))));
+                        })
+                    });
+
+                    let result = match read_as {
+                        ReadAs::Text => created.read_as_text(file),
+                        ReadAs::Bytes => created.read_as_array_buffer(file),
+                        ReadAs::DataUrl => created.read_as_data_url(file),
+                    };
+                    if let Err(err) = result {
+                        listeners.clear();
+                        state.set(UseAsyncState::Ready(Err(FileReaderError::Failed(format!(
+                            "{err:?}"
+                        )))));
+                    }
+
+                    reader = Some(created);
+                }
+            }
+
+            move || {
+                drop(listeners);
+                if let Some(reader) = reader {
+                    // still loading
+                    if reader.ready_state() == web_sys::FileReader::LOADING {
+                        reader.abort();
+                    }
+                }
+            }
+        });
+    }
+
+    UseFileReaderHandle { state, progress }
+}
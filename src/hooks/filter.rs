@@ -0,0 +1,202 @@
+//! Hooks for filtering lists and tables
+
+use crate::hooks::use_debounce;
+use std::{ops::Deref, rc::Rc};
+use yew::prelude::*;
+
+/// The criteria of a filter.
+pub trait Filter: Clone + PartialEq + 'static {
+    /// The number of criteria which are currently active, like for showing it as badge.
+    fn active_count(&self) -> usize;
+}
+
+/// State handle for the [`use_filter_state`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseFilterStateHandle<F>
+where
+    F: Filter,
+{
+    value: F,
+    applied: F,
+    initial: Rc<F>,
+    /// Stores a new value.
+    store: Callback<F>,
+}
+
+impl<F> UseFilterStateHandle<F>
+where
+    F: Filter,
+{
+    /// Set new criteria, which get applied once they settled.
+    pub fn set(&self, value: F) {
+        if value != self.value {
+            self.store.emit(value);
+        }
+    }
+
+    /// Modify the filter by modifying a copy of it.
+    pub fn modify<M>(&self, f: M)
+    where
+        M: FnOnce(&mut F),
+    {
+        let mut value = self.value.clone();
+        f(&mut value);
+        self.set(value);
+    }
+
+    /// Set a single field of the filter.
+    ///
+    /// ```rust
+    /// # use yew_more_hooks::prelude::*;
+    /// # #[derive(Clone, PartialEq)]
+    /// # struct Query { name: String }
+    /// # impl Filter for Query { fn active_count(&self) -> usize { 0 } }
+    /// # fn example(filter: UseFilterStateHandle<Query>) {
+    /// filter.set_field(|query| &mut query.name, "foo".to_string());
+    /// # }
+    /// ```
+    pub fn set_field<V, M>(&self, field: M, value: V)
+    where
+        M: FnOnce(&mut F) -> &mut V,
+    {
+        self.modify(|filter| *field(filter) = value);
+    }
+
+    /// Go back to the initial filter.
+    pub fn reset(&self) {
+        self.set((*self.initial).clone());
+    }
+
+    /// The number of active criteria of the current filter.
+    pub fn active_count(&self) -> usize {
+        self.value.active_count()
+    }
+
+    /// The applied filter, which only changes once the filter didn't change for the debounce
+    /// period.
+    pub fn applied(&self) -> &F {
+        &self.applied
+    }
+
+    /// Check if the applied filter caught up with the current filter.
+    pub fn is_applied(&self) -> bool {
+        self.value == self.applied
+    }
+
+    /// Check if the filter is still the initial filter.
+    pub fn is_initial(&self) -> bool {
+        self.value == *self.initial
+    }
+}
+
+impl<F> Deref for UseFilterStateHandle<F>
+where
+    F: Filter,
+{
+    type Target = F;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// State of a filter, with a debounced copy which gets applied.
+///
+/// The handle dereferences to the current filter, which is updated right away, for example to be
+/// shown in the inputs of a filter form. The applied filter is only updated once the filter
+/// didn't change for `millis` milliseconds, and can be used as dependency of a search, like with
+/// [`use_async_with_deps`](crate::hooks::use_async_with_deps).
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(Clone, Default, PartialEq)]
+/// struct Query {
+///   name: String,
+///   only_active: bool,
+/// }
+///
+/// impl Filter for Query {
+///   fn active_count(&self) -> usize {
+///     usize::from(!self.name.is_empty()) + usize::from(self.only_active)
+///   }
+/// }
+///
+/// #[function_component(Users)]
+/// fn users() -> Html {
+///   let filter = use_filter_state(Query::default, 300);
+///   let oninput = use_callback(filter.clone(), |e: InputEvent, filter| {
+///     let value = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
+///     filter.set_field(|query| &mut query.name, value);
+///   });
+///   let onreset = use_callback(filter.clone(), |_, filter| filter.reset());
+///
+///   let users = use_async_with_deps(
+///     |query| {
+///       let name = query.name.clone();
+///       async move { Ok::<_, String>(vec![name]) }
+///     },
+///     filter.applied().clone(),
+///   );
+///
+///   html!(
+///     <>
+///       <input value={filter.name.clone()} {oninput} />
+///       <button onclick={onreset} disabled={filter.is_initial()}>
+///         { format!("Reset ({})", filter.active_count()) }
+///       </button>
+///       if !filter.is_applied() || users.is_processing() {
+///         <p>{"Searching…"}</p>
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_filter_state<F, I>(init: I, millis: u32) -> UseFilterStateHandle<F>
+where
+    F: Filter,
+    I: FnOnce() -> F,
+{
+    let initial = use_memo((), move |()| init());
+    let state = use_state_eq(|| (*initial).clone());
+    let store = use_callback(state.setter(), |value, setter| setter.set(value));
+    let value = (*state).clone();
+    let applied = use_debounce(value.clone(), millis);
+
+    UseFilterStateHandle {
+        value,
+        applied,
+        initial,
+        store,
+    }
+}
+
+/// State of a filter, kept in the state of the current page.
+///
+/// This works like [`use_filter_state`], but stores the filter using
+/// [`use_page_state`](crate::hooks::use_page_state), so that it survives navigating back to the
+/// page.
+#[cfg(feature = "page_state")]
+#[hook]
+pub fn use_filter_state_with_page_state<F, I>(init: I, millis: u32) -> UseFilterStateHandle<F>
+where
+    for<'de> F: Filter + serde::Serialize + serde::Deserialize<'de>,
+    I: FnOnce() -> F,
+{
+    let initial = use_memo((), move |()| init());
+    let state = crate::hooks::use_page_state(|| (*initial).clone());
+    let store = use_callback(state.clone(), |value, state| state.set(value));
+    let value = (*state).clone();
+    let applied = use_debounce(value.clone(), millis);
+
+    UseFilterStateHandle {
+        value,
+        applied,
+        initial,
+        store,
+    }
+}
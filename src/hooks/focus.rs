@@ -0,0 +1,246 @@
+//! Hooks for managing the keyboard focus
+
+use crate::hooks::{use_event_listener, ListenerTarget};
+use gloo_events::{EventListener, EventListenerOptions};
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+/// Selector of elements which can receive the keyboard focus.
+const FOCUSABLE: &str = concat!(
+    "a[href], area[href], button:not([disabled]), input:not([disabled]), ",
+    "select:not([disabled]), textarea:not([disabled]), iframe, [contenteditable], ",
+    "[tabindex]:not([tabindex=\"-1\"])"
+);
+
+/// Get all focusable elements inside a container, in document order.
+fn focusable(container: &web_sys::Element) -> Vec<web_sys::HtmlElement> {
+    let Ok(list) = container.query_selector_all(FOCUSABLE) else {
+        return vec![];
+    };
+    (0..list.length())
+        .filter_map(|i| list.item(i))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect()
+}
+
+fn contains(container: &web_sys::Element, element: Option<&web_sys::Element>) -> bool {
+    element.is_some_and(|element| container.contains(Some(element)))
+}
+
+fn focus_first(container: &web_sys::Element) {
+    if let Some(first) = focusable(container).first() {
+        let _ = first.focus();
+    }
+}
+
+/// Keep the keyboard focus inside a container, while `active` is `true`.
+///
+/// When activated, the first focusable element of the container referenced by `node` gets
+/// focused, unless the focus already is inside the container. Tabbing past the last element moves
+/// the focus to the first one, and the other way round. Focus moving outside the container (e.g.
+/// by clicking) is moved back into it.
+///
+/// When deactivated, or when the component gets unmounted, the focus is restored to the element
+/// which was focused when the trap was activated. This is useful for modal dialogs.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   onclose: Callback<MouseEvent>,
+/// }
+///
+/// #[function_component(Dialog)]
+/// fn dialog(props: &Props) -> Html {
+///   let node = use_node_ref();
+///   use_focus_trap(node.clone(), true);
+///
+///   html!(
+///     <div ref={node} role="dialog" aria-modal="true">
+///       <input type="text" />
+///       <button onclick={props.onclose.clone()}>{"Close"}</button>
+///     </div>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_focus_trap(node: NodeRef, active: bool) {
+    use_effect_with((node, active), |(node, active)| {
+        let trap = node
+            .cast::<web_sys::Element>()
+            .filter(|_| *active)
+            .map(|container| {
+                let document = gloo_utils::document();
+                let previous = document.active_element();
+                if !contains(&container, previous.as_ref()) {
+                    focus_first(&container);
+                }
+
+                let keydown = {
+                    let container = container.clone();
+                    EventListener::new_with_options(
+                        &document,
+                        "keydown",
+                        EventListenerOptions::enable_prevent_default(),
+                        move |event| {
+                            let Some(event) = event.dyn_ref::<web_sys::KeyboardEvent>() else {
+                                return;
+                            };
+                            if event.key() != "Tab" {
+                                return;
+                            }
+
+                            let elements = focusable(&container);
+                            let (Some(first), Some(last)) = (elements.first(), elements.last())
+                            else {
+                                // nothing to focus, but also don't leave
+                                event.prevent_default();
+                                return;
+                            };
+
+                            let current = gloo_utils::document().active_element();
+                            let index = current.and_then(|current| {
+                                elements.iter().position(|element| **element == current)
+                            });
+
+                            let next = match (index, event.shift_key()) {
+                                (None, false) => Some(first),
+                                (None, true) | (Some(0), true) => Some(last),
+                                (Some(index), false) if index + 1 == elements.len() => Some(first),
+                                _ => None,
+                            };
+
+                            if let Some(next) = next {
+                                event.prevent_default();
+                                let _ = next.focus();
+                            }
+                        },
+                    )
+                };
+
+                let focusin = {
+                    let container = container.clone();
+                    EventListener::new(&document, "focusin", move |event| {
+                        let target = event
+                            .target()
+                            .and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+                        if !contains(&container, target.as_ref()) {
+                            focus_first(&container);
+                        }
+                    })
+                };
+
+                (previous, keydown, focusin)
+            });
+
+        move || {
+            if let Some((previous, keydown, focusin)) = trap {
+                drop(keydown);
+                drop(focusin);
+                if let Some(previous) =
+                    previous.and_then(|previous| previous.dyn_into::<web_sys::HtmlElement>().ok())
+                {
+                    let _ = previous.focus();
+                }
+            }
+        }
+    });
+}
+
+/// Check if the keyboard focus is anywhere inside an element.
+///
+/// Returns `true` while the element referenced by `node`, or any of its descendants, has the
+/// focus. This is useful for showing toolbars or controls only while a widget is being used.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Editor)]
+/// fn editor() -> Html {
+///   let node = use_node_ref();
+///   let active = use_focus_within(node.clone());
+///
+///   html!(
+///     <div ref={node}>
+///       if active {
+///         <div class="toolbar"><button>{"Bold"}</button></div>
+///       }
+///       <textarea />
+///     </div>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_focus_within(node: NodeRef) -> bool {
+    let within = use_state_eq(|| false);
+
+    let onfocusin = use_callback(within.clone(), |_: web_sys::FocusEvent, within| {
+        within.set(true)
+    });
+
+    let onfocusout = use_callback(
+        (node.clone(), within.clone()),
+        |event: web_sys::FocusEvent, (node, within)| {
+            // the element receiving the focus next, if any
+            let next = event
+                .related_target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok());
+            let inside = node
+                .cast::<web_sys::Element>()
+                .is_some_and(|element| contains(&element, next.as_ref()));
+            within.set(inside);
+        },
+    );
+
+    use_event_listener(node.clone(), "focusin", onfocusin);
+    use_event_listener(node, "focusout", onfocusout);
+
+    *within
+}
+
+/// Track the element which has the keyboard focus.
+///
+/// Returns the [`active element`](web_sys::Document::active_element) of the document, updating
+/// whenever the focus moves anywhere in the page. When no element has the focus, this typically
+/// is the body.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let active = use_active_element();
+///   let name = active.map(|element| element.tag_name()).unwrap_or_default();
+///
+///   html!(
+///     <>
+///       <input />
+///       <button>{"Button"}</button>
+///       <div>{ format!("Focused: {name}") }</div>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_active_element() -> Option<web_sys::Element> {
+    let active = use_state_eq(|| gloo_utils::document().active_element());
+
+    let onchange = use_callback(active.clone(), |_: web_sys::FocusEvent, active| {
+        active.set(gloo_utils::document().active_element());
+    });
+
+    use_event_listener(ListenerTarget::Document, "focusin", onchange.clone());
+    use_event_listener(ListenerTarget::Document, "focusout", onchange);
+
+    (*active).clone()
+}
@@ -0,0 +1,387 @@
+//! Hooks for forms, with validation
+
+use crate::hooks::{Field, FieldState};
+use std::{cell::Cell, collections::HashMap, future::Future, ops::Deref, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+/// A form, consisting of fields.
+#[derive(Clone, Default, PartialEq)]
+pub struct Form {
+    fields: Vec<(String, Field, FieldState)>,
+    submitting: bool,
+    submit_error: Option<String>,
+    /// The number of accepted submissions, shared by all versions of the form, so that the
+    /// handle can check if a submission was accepted.
+    submissions: Rc<Cell<usize>>,
+}
+
+impl Form {
+    /// Create a form without fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a field.
+    pub fn field(mut self, name: impl Into<String>, field: Field) -> Self {
+        let state = FieldState::new(&field);
+        self.fields.push((name.into(), field, state));
+        self
+    }
+
+    /// The state of a field.
+    pub fn state(&self, name: &str) -> Option<&FieldState> {
+        self.fields
+            .iter()
+            .find(|(field, ..)| field == name)
+            .map(|(.., state)| state)
+    }
+
+    /// The current values of all fields.
+    pub fn values(&self) -> HashMap<String, String> {
+        self.fields
+            .iter()
+            .map(|(name, _, state)| (name.clone(), state.value.clone()))
+            .collect()
+    }
+
+    /// Check if all fields are valid, including the ones not touched yet.
+    pub fn is_valid(&self) -> bool {
+        self.fields.iter().all(|(.., state)| state.is_valid())
+    }
+
+    /// Check if any field is dirty.
+    pub fn is_dirty(&self) -> bool {
+        self.fields.iter().any(|(.., state)| state.is_dirty())
+    }
+
+    /// Check if the values are being submitted.
+    pub fn is_submitting(&self) -> bool {
+        self.submitting
+    }
+
+    /// The error of the last submission.
+    pub fn submit_error(&self) -> Option<&str> {
+        self.submit_error.as_deref()
+    }
+
+    fn modify_field<F>(&self, name: &str, f: F) -> Self
+    where
+        F: FnOnce(&Field, &mut FieldState),
+    {
+        let mut next = self.clone();
+        if let Some((_, field, state)) = next.fields.iter_mut().find(|(field, ..)| field == name) {
+            f(field, state);
+        }
+        next
+    }
+}
+
+/// Actions of the [`Form`] reducer.
+pub enum FormAction {
+    /// Set the value of a field.
+    Set(String, String),
+    /// Mark a field as touched.
+    Touch(String),
+    /// Mark all fields as touched, showing all errors.
+    TouchAll,
+    /// Start submitting the values, unless they are already being submitted.
+    Submit,
+    /// Finish submitting. Successfully submitted values become the initial values.
+    Submitted(Result<(), String>),
+    /// Go back to the initial values.
+    Reset,
+}
+
+impl Reducible for Form {
+    type Action = FormAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let next = match action {
+            FormAction::Set(name, value) => {
+                self.modify_field(&name, |field, state| state.set(field, value))
+            }
+            FormAction::Touch(name) => self.modify_field(&name, |_, state| state.touched = true),
+            FormAction::TouchAll => {
+                let mut next = (*self).clone();
+                for (.., state) in &mut next.fields {
+                    state.touched = true;
+                }
+                next
+            }
+            FormAction::Submit => {
+                if self.submitting {
+                    return self;
+                }
+                self.submissions.set(self.submissions.get() + 1);
+                Self {
+                    submitting: true,
+                    submit_error: None,
+                    ..(*self).clone()
+                }
+            }
+            FormAction::Submitted(result) => {
+                let mut next = (*self).clone();
+                next.submitting = false;
+                match result {
+                    Ok(()) => {
+                        for (.., state) in &mut next.fields {
+                            state.initial = state.value.clone();
+                        }
+                    }
+                    Err(err) => next.submit_error = Some(err),
+                }
+                next
+            }
+            FormAction::Reset => {
+                let mut next = (*self).clone();
+                next.submit_error = None;
+                for (_, field, state) in &mut next.fields {
+                    state.reset(field);
+                }
+                next
+            }
+        };
+
+        match next == *self {
+            true => self,
+            false => Rc::new(next),
+        }
+    }
+}
+
+/// State handle for the [`use_form`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseFormHandle {
+    state: UseReducerHandle<Form>,
+}
+
+impl UseFormHandle {
+    /// The value of a field, or an empty string if there is no such field.
+    pub fn value(&self, name: &str) -> &str {
+        self.state
+            .state(name)
+            .map(|state| state.value.as_str())
+            .unwrap_or_default()
+    }
+
+    /// The error of a field, once it was touched.
+    pub fn error(&self, name: &str) -> Option<&str> {
+        self.state
+            .state(name)
+            .filter(|state| state.touched)
+            .and_then(|state| state.error.as_deref())
+    }
+
+    /// Set the value of a field.
+    pub fn set(&self, name: impl Into<String>, value: impl Into<String>) {
+        self.state
+            .dispatch(FormAction::Set(name.into(), value.into()));
+    }
+
+    /// Mark a field as touched, showing its error.
+    pub fn touch(&self, name: impl Into<String>) {
+        self.state.dispatch(FormAction::Touch(name.into()));
+    }
+
+    /// Go back to the initial values.
+    pub fn reset(&self) {
+        self.state.dispatch(FormAction::Reset);
+    }
+
+    /// Create an input handler, setting the value of a field from an input element.
+    pub fn oninput(&self, name: impl Into<String>) -> Callback<InputEvent> {
+        let name = name.into();
+        let state = self.state.clone();
+        Callback::from(move |e: InputEvent| {
+            let value = e
+                .target_unchecked_into::<web_sys::HtmlInputElement>()
+                .value();
+            state.dispatch(FormAction::Set(name.clone(), value));
+        })
+    }
+
+    /// Create a blur handler, marking a field as touched.
+    pub fn onblur(&self, name: impl Into<String>) -> Callback<FocusEvent> {
+        let name = name.into();
+        let state = self.state.clone();
+        Callback::from(move |_| state.dispatch(FormAction::Touch(name.clone())))
+    }
+
+    /// Validate and submit the values.
+    ///
+    /// If any field is invalid, all fields are marked as touched, showing their errors, and
+    /// nothing is submitted. Otherwise, `f` is called with the values, and the returned future is
+    /// awaited. While the form is submitting, further submissions are ignored.
+    pub fn submit<F, Fut>(&self, f: F)
+    where
+        F: FnOnce(HashMap<String, String>) -> Fut,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        if !self.state.is_valid() {
+            self.state.dispatch(FormAction::TouchAll);
+            return;
+        }
+
+        // the state of the handle might be outdated, only the reducer knows if it is submitting
+        let submissions = self.state.submissions.get();
+        self.state.dispatch(FormAction::Submit);
+        if self.state.submissions.get() == submissions {
+            return;
+        }
+
+        let future = f(self.state.values());
+        let state = self.state.clone();
+        spawn_local(async move {
+            state.dispatch(FormAction::Submitted(future.await));
+        });
+    }
+
+    /// Create a submit handler for a `<form>` element, preventing the default action.
+    ///
+    /// See [`Self::submit`].
+    pub fn onsubmit<F, Fut>(&self, f: F) -> Callback<SubmitEvent>
+    where
+        F: Fn(HashMap<String, String>) -> Fut + 'static,
+        Fut: Future<Output = Result<(), String>> + 'static,
+    {
+        let handle = self.clone();
+        Callback::from(move |e: SubmitEvent| {
+            e.prevent_default();
+            handle.submit(&f);
+        })
+    }
+}
+
+impl Deref for UseFormHandle {
+    type Target = Form;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// A form, with validation, dirty tracking, and submission.
+///
+/// The fields of the form are defined once, with their initial values and validators. The handle
+/// provides the values and errors of the fields, as well as input, blur, and submit handlers.
+/// Errors of a field are only reported once the field was touched, or submitting was attempted.
+///
+/// Submitting validates all fields first, and then runs an async function with the values. Once
+/// it succeeds, the submitted values become the new initial values, so the form is no longer
+/// dirty.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// fn required(value: &str) -> Result<(), String> {
+///   match value.is_empty() {
+///     true => Err("Required".into()),
+///     false => Ok(()),
+///   }
+/// }
+///
+/// #[function_component(Signup)]
+/// fn signup() -> Html {
+///   let form = use_form(|| {
+///     Form::new()
+///       .field("name", Field::new("").validate(required))
+///       .field(
+///         "email",
+///         Field::new("")
+///           .validate(required)
+///           .validate(|value| match value.contains('@') {
+///             true => Ok(()),
+///             false => Err("Not an email address".into()),
+///           }),
+///       )
+///   });
+///
+///   let onsubmit = form.onsubmit(|values| async move {
+///     // send the values to the server
+///     let _name = &values["name"];
+///     Ok(())
+///   });
+///
+///   html!(
+///     <form {onsubmit}>
+///       <input value={form.value("name").to_string()} oninput={form.oninput("name")} onblur={form.onblur("name")} />
+///       if let Some(error) = form.error("name") { <p>{ error }</p> }
+///       <input value={form.value("email").to_string()} oninput={form.oninput("email")} onblur={form.onblur("email")} />
+///       if let Some(error) = form.error("email") { <p>{ error }</p> }
+///       if let Some(error) = form.submit_error() { <p>{ error }</p> }
+///       <button type="submit" disabled={form.is_submitting()}>{"Sign up"}</button>
+///     </form>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_form<F>(init: F) -> UseFormHandle
+where
+    F: FnOnce() -> Form,
+{
+    let state = use_reducer(init);
+    UseFormHandle { state }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn form() -> Rc<Form> {
+        Rc::new(Form::new().field(
+            "name",
+            Field::new("").validate(|value| match value.is_empty() {
+                true => Err("Required".into()),
+                false => Ok(()),
+            }),
+        ))
+    }
+
+    #[test]
+    fn test_validate() {
+        let state = form();
+        assert!(!state.is_valid());
+        assert!(!state.state("name").unwrap().touched);
+
+        let state = state.reduce(FormAction::TouchAll);
+        assert!(state.state("name").unwrap().touched);
+
+        let state = state.reduce(FormAction::Set("name".into(), "foo".into()));
+        assert!(state.is_valid());
+        assert!(state.is_dirty());
+
+        let unchanged = state
+            .clone()
+            .reduce(FormAction::Set("unknown".into(), "".into()));
+        assert!(Rc::ptr_eq(&state, &unchanged));
+    }
+
+    #[test]
+    fn test_submit() {
+        let state = form().reduce(FormAction::Set("name".into(), "foo".into()));
+        let state = state.reduce(FormAction::Submit);
+        assert!(state.is_submitting());
+
+        // already submitting
+        let unchanged = state.clone().reduce(FormAction::Submit);
+        assert!(Rc::ptr_eq(&state, &unchanged));
+        assert_eq!(state.submissions.get(), 1);
+
+        let failed = state
+            .clone()
+            .reduce(FormAction::Submitted(Err("Failed".into())));
+        assert_eq!(failed.submit_error(), Some("Failed"));
+        assert!(failed.is_dirty());
+
+        // submitted values become the initial values
+        let state = state.reduce(FormAction::Submitted(Ok(())));
+        assert!(!state.is_submitting());
+        assert!(!state.is_dirty());
+        let state = state.reduce(FormAction::Reset);
+        assert_eq!(state.values()["name"], "foo");
+    }
+}
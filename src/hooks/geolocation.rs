@@ -0,0 +1,213 @@
+//! Hooks for the geolocation of the device
+
+use std::{
+    cell::Cell,
+    fmt::{Display, Formatter},
+    rc::Rc,
+};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use yew::prelude::*;
+
+/// A position, reported by the Geolocation API.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GeoPosition {
+    /// Latitude, in decimal degrees.
+    pub latitude: f64,
+    /// Longitude, in decimal degrees.
+    pub longitude: f64,
+    /// Accuracy of latitude and longitude, in meters.
+    pub accuracy: f64,
+    /// Altitude, in meters above the WGS84 ellipsoid.
+    pub altitude: Option<f64>,
+    /// Accuracy of the altitude, in meters.
+    pub altitude_accuracy: Option<f64>,
+    /// Direction of travel, in degrees clockwise from true north.
+    pub heading: Option<f64>,
+    /// Speed, in meters per second.
+    pub speed: Option<f64>,
+    /// Time of the measurement, in milliseconds since the Unix epoch.
+    pub timestamp: f64,
+}
+
+impl From<web_sys::Position> for GeoPosition {
+    fn from(position: web_sys::Position) -> Self {
+        let coords = position.coords();
+        Self {
+            latitude: coords.latitude(),
+            longitude: coords.longitude(),
+            accuracy: coords.accuracy(),
+            altitude: coords.altitude(),
+            altitude_accuracy: coords.altitude_accuracy(),
+            heading: coords.heading(),
+            speed: coords.speed(),
+            timestamp: position.timestamp(),
+        }
+    }
+}
+
+/// An error, reported by the Geolocation API.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GeolocationError {
+    /// The Geolocation API is not available.
+    Unsupported,
+    /// The user, or the browser, denied access to the location.
+    PermissionDenied(String),
+    /// The position could not be determined.
+    PositionUnavailable(String),
+    /// The position could not be determined in time.
+    Timeout(String),
+}
+
+impl Display for GeolocationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str("Geolocation API is not supported"),
+            Self::PermissionDenied(msg) => write!(f, "Permission denied: {msg}"),
+            Self::PositionUnavailable(msg) => write!(f, "Position unavailable: {msg}"),
+            Self::Timeout(msg) => write!(f, "Timeout: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for GeolocationError {}
+
+impl From<web_sys::PositionError> for GeolocationError {
+    fn from(err: web_sys::PositionError) -> Self {
+        match err.code() {
+            web_sys::PositionError::PERMISSION_DENIED => Self::PermissionDenied(err.message()),
+            web_sys::PositionError::TIMEOUT => Self::Timeout(err.message()),
+            _ => Self::PositionUnavailable(err.message()),
+        }
+    }
+}
+
+/// Options for [`use_geolocation_with_options`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UseGeolocationOptions {
+    /// Request the best possible results, which may be slower and use more power.
+    pub enable_high_accuracy: bool,
+    /// Accept cached positions up to this age, in milliseconds.
+    pub maximum_age: u32,
+    /// The maximum time, in milliseconds, to wait for a position.
+    pub timeout: Option<u32>,
+}
+
+impl UseGeolocationOptions {
+    fn to_options(self) -> web_sys::PositionOptions {
+        let options = web_sys::PositionOptions::new();
+        options.set_enable_high_accuracy(self.enable_high_accuracy);
+        options.set_maximum_age(self.maximum_age);
+        if let Some(timeout) = self.timeout {
+            options.set_timeout(timeout);
+        }
+        options
+    }
+}
+
+/// The state of the [`use_geolocation`] hook.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GeolocationState {
+    /// The latest position, if one was reported yet.
+    pub position: Option<GeoPosition>,
+    /// The latest error, cleared by the next position.
+    pub error: Option<GeolocationError>,
+}
+
+/// Watch the position of the device.
+///
+/// This is [`use_geolocation_with_options`] using the default options.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let location = use_geolocation();
+///
+///   match (&location.position, &location.error) {
+///     (_, Some(err)) => html!(err.to_string()),
+///     (Some(position), None) => html!(format!("{:.4} / {:.4}", position.latitude, position.longitude)),
+///     (None, None) => html!("Locating…"),
+///   }
+/// }
+/// ```
+#[hook]
+pub fn use_geolocation() -> GeolocationState {
+    use_geolocation_with_options(UseGeolocationOptions::default())
+}
+
+/// Watch the position of the device, using options.
+///
+/// Uses [`watchPosition()`](https://developer.mozilla.org/en-US/docs/Web/API/Geolocation/watchPosition)
+/// to report the position of the device, and changes of it. Browsers ask the user for permission
+/// first. The watcher is re-created when the options change, and cleared when the component gets
+/// unmounted.
+#[hook]
+pub fn use_geolocation_with_options(options: UseGeolocationOptions) -> GeolocationState {
+    let state = use_state_eq(GeolocationState::default);
+
+    {
+        let state = state.clone();
+        use_effect_with(options, move |options| {
+            // the handle captured by the closures doesn't see updates
+            let last = Rc::new(Cell::new(state.position));
+
+            let onposition = {
+                let state = state.clone();
+                let last = last.clone();
+                Closure::<dyn Fn(JsValue)>::new(move |position: JsValue| {
+                    let position = position.unchecked_into::<web_sys::Position>().into();
+                    last.set(Some(position));
+                    state.set(GeolocationState {
+                        position: Some(position),
+                        error: None,
+                    });
+                })
+            };
+            let onerror = {
+                let state = state.clone();
+                Closure::<dyn Fn(JsValue)>::new(move |err: JsValue| {
+                    state.set(GeolocationState {
+                        position: last.get(),
+                        error: Some(err.unchecked_into::<web_sys::PositionError>().into()),
+                    });
+                })
+            };
+
+            let watch = gloo_utils::window()
+                .navigator()
+                .geolocation()
+                .ok()
+                .and_then(|geolocation| {
+                    let id = geolocation
+                        .watch_position_with_error_callback_and_options(
+                            onposition.as_ref().unchecked_ref(),
+                            Some(onerror.as_ref().unchecked_ref()),
+                            &options.to_options(),
+                        )
+                        .ok()?;
+                    Some((geolocation, id))
+                });
+
+            if watch.is_none() {
+                state.set(GeolocationState {
+                    position: None,
+                    error: Some(GeolocationError::Unsupported),
+                });
+            }
+
+            move || {
+                if let Some((geolocation, id)) = watch {
+                    geolocation.clear_watch(id);
+                }
+                drop(onposition);
+                drop(onerror);
+            }
+        });
+    }
+
+    (*state).clone()
+}
@@ -0,0 +1,436 @@
+//! Hooks for keyboard shortcuts
+
+use crate::hooks::{use_event_listener, use_latest, ListenerTarget};
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+/// A key, in combination with modifiers, like `ctrl+k`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyCombo {
+    /// The key, normalized to lowercase (see [`KeyboardEvent::key`](web_sys::KeyboardEvent::key)).
+    pub key: String,
+    /// The <kbd>Ctrl</kbd> key is pressed.
+    pub ctrl: bool,
+    /// The <kbd>Alt</kbd> (or <kbd>Option</kbd>) key is pressed.
+    pub alt: bool,
+    /// The <kbd>Shift</kbd> key is pressed.
+    pub shift: bool,
+    /// The <kbd>Meta</kbd> (or <kbd>Cmd</kbd>) key is pressed.
+    pub meta: bool,
+}
+
+fn normalize_key(key: &str) -> String {
+    let key = key.to_lowercase();
+    match key.as_str() {
+        "esc" => "escape".into(),
+        "space" | "spacebar" => " ".into(),
+        "del" => "delete".into(),
+        "return" => "enter".into(),
+        "up" | "down" | "left" | "right" => format!("arrow{key}"),
+        "plus" => "+".into(),
+        _ => key,
+    }
+}
+
+impl KeyCombo {
+    /// Parse a combination of modifiers and a key, separated by `+`.
+    ///
+    /// Modifiers are `ctrl` (or `control`), `alt` (or `option`), `shift` and `meta` (or `cmd`,
+    /// `command`, `super`).
+    pub fn parse(combo: &str) -> Self {
+        let mut result = Self::default();
+
+        // a trailing `+` is the key itself, like `ctrl++`
+        let (modifiers, key) = match combo.strip_suffix("++") {
+            Some(modifiers) => (modifiers, "+"),
+            None if combo == "+" => ("", "+"),
+            None => combo.rsplit_once('+').unwrap_or(("", combo)),
+        };
+
+        for modifier in modifiers.split('+').map(str::trim) {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => result.ctrl = true,
+                "alt" | "option" => result.alt = true,
+                "shift" => result.shift = true,
+                "meta" | "cmd" | "command" | "super" => result.meta = true,
+                _ => {}
+            }
+        }
+
+        result.key = normalize_key(key.trim());
+        result
+    }
+
+    /// Get the combination of a keyboard event.
+    pub fn from_event(event: &web_sys::KeyboardEvent) -> Self {
+        Self {
+            key: normalize_key(&event.key()),
+            ctrl: event.ctrl_key(),
+            alt: event.alt_key(),
+            shift: event.shift_key(),
+            meta: event.meta_key(),
+        }
+    }
+
+    /// Check if a pressed combination matches this one.
+    ///
+    /// For punctuation keys (like `?`), the shift modifier is ignored, as it depends on the
+    /// keyboard layout if it is required to produce the key.
+    pub fn matches(&self, pressed: &KeyCombo) -> bool {
+        let mut chars = self.key.chars();
+        let punctuation =
+            matches!((chars.next(), chars.next()), (Some(c), None) if !c.is_alphanumeric());
+
+        self.key == pressed.key
+            && self.ctrl == pressed.ctrl
+            && self.alt == pressed.alt
+            && self.meta == pressed.meta
+            && (punctuation || self.shift == pressed.shift)
+    }
+
+    fn is_modifier(&self) -> bool {
+        matches!(
+            self.key.as_str(),
+            "control" | "alt" | "shift" | "meta" | "altgraph" | "capslock"
+        )
+    }
+}
+
+/// A set of alternative keyboard shortcuts.
+///
+/// Shortcuts are parsed from a string, separating alternatives by `,` and steps of a sequence by
+/// whitespace. For example, `ctrl+k, meta+k` matches either <kbd>Ctrl</kbd>+<kbd>K</kbd> or
+/// <kbd>Meta</kbd>+<kbd>K</kbd>, while `g d` matches pressing <kbd>G</kbd> followed by
+/// <kbd>D</kbd>.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Hotkeys(pub Vec<Vec<KeyCombo>>);
+
+impl Hotkeys {
+    /// Parse alternative shortcuts, separated by `,`, each being a sequence of whitespace
+    /// separated [key combinations](KeyCombo::parse).
+    pub fn parse(hotkeys: &str) -> Self {
+        Self(
+            hotkeys
+                .split(',')
+                .map(|sequence| {
+                    sequence
+                        .split_whitespace()
+                        .map(KeyCombo::parse)
+                        .collect::<Vec<_>>()
+                })
+                .filter(|sequence| !sequence.is_empty())
+                .collect(),
+        )
+    }
+}
+
+impl From<&str> for Hotkeys {
+    fn from(hotkeys: &str) -> Self {
+        Self::parse(hotkeys)
+    }
+}
+
+impl From<String> for Hotkeys {
+    fn from(hotkeys: String) -> Self {
+        Self::parse(&hotkeys)
+    }
+}
+
+/// The position in a sequence after pressing a key, with the first `position` keys matched.
+///
+/// On a mismatch, this falls back to the longest prefix of the sequence which was still pressed
+/// last, so that `g g d` matches pressing <kbd>G</kbd> <kbd>G</kbd> <kbd>G</kbd> <kbd>D</kbd>.
+fn next_position(sequence: &[KeyCombo], position: usize, pressed: &KeyCombo) -> usize {
+    let position = position.min(sequence.len());
+    (1..=(position + 1).min(sequence.len()))
+        .rev()
+        .find(|&len| {
+            sequence[len - 1].matches(pressed)
+                && sequence[..len - 1] == sequence[position + 1 - len..position]
+        })
+        .unwrap_or(0)
+}
+
+/// Tracks the progress of pressed keys through the sequences of [`Hotkeys`].
+#[derive(Clone, Debug, Default)]
+struct Progress {
+    /// The hotkeys the positions refer to.
+    hotkeys: Hotkeys,
+    positions: Vec<usize>,
+    last: f64,
+}
+
+impl Progress {
+    /// Advance with a pressed key, returning `true` if a sequence got completed.
+    fn advance(&mut self, hotkeys: &Hotkeys, pressed: &KeyCombo, now: f64, timeout: f64) -> bool {
+        if self.hotkeys != *hotkeys {
+            self.hotkeys = hotkeys.clone();
+            self.positions = vec![0; hotkeys.0.len()];
+        } else if now - self.last > timeout {
+            self.positions.fill(0);
+        }
+        self.last = now;
+
+        let mut completed = false;
+        for (sequence, position) in hotkeys.0.iter().zip(&mut self.positions) {
+            *position = next_position(sequence, *position, pressed);
+
+            if *position > 0 && *position == sequence.len() {
+                *position = 0;
+                completed = true;
+            }
+        }
+
+        if completed {
+            self.positions.fill(0);
+        }
+
+        completed
+    }
+}
+
+/// Options for [`use_hotkeys_with_options`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UseHotkeysOptions {
+    /// Listen for the shortcuts.
+    pub enabled: bool,
+    /// Also trigger while typing into inputs, text areas, selects, or editable content.
+    pub enable_on_inputs: bool,
+    /// Prevent the default action of the final key of a shortcut.
+    pub prevent_default: bool,
+    /// The maximum time, in milliseconds, between two keys of a sequence.
+    pub sequence_timeout: u32,
+}
+
+impl Default for UseHotkeysOptions {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            enable_on_inputs: false,
+            prevent_default: true,
+            sequence_timeout: 1_000,
+        }
+    }
+}
+
+fn is_editable(target: Option<web_sys::EventTarget>) -> bool {
+    let Some(element) = target.and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+    else {
+        return false;
+    };
+
+    matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+        || element.is_content_editable()
+}
+
+/// Register a keyboard shortcut.
+///
+/// This is [`use_hotkeys_with_options`] using the default options.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let search = use_state_eq(|| false);
+///
+///   let onsearch = use_callback(search.clone(), |_, search| search.set(true));
+///   use_hotkeys("ctrl+k, meta+k", onsearch);
+///
+///   html!(if *search { <input type="search" /> })
+/// }
+/// ```
+#[hook]
+pub fn use_hotkeys<H>(hotkeys: H, callback: Callback<web_sys::KeyboardEvent>)
+where
+    H: Into<Hotkeys>,
+{
+    use_hotkeys_with_options(hotkeys, callback, UseHotkeysOptions::default())
+}
+
+/// Register a keyboard shortcut, using options.
+///
+/// The callback will be called when one of the [`Hotkeys`] got pressed, with the event of the
+/// last key. By default, shortcuts are ignored while typing into an input, and the default action
+/// of the event is prevented.
+///
+/// The shortcut is registered on the window, and unregistered when the component gets unmounted.
+/// The latest shortcuts and callback will always be used.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let page = use_state_eq(|| "home");
+///
+///   let ondashboard = use_callback(page.clone(), |_, page| page.set("dashboard"));
+///   use_hotkeys_with_options(
+///     "g d",
+///     ondashboard,
+///     UseHotkeysOptions {
+///       sequence_timeout: 500,
+///       ..Default::default()
+///     },
+///   );
+///
+///   html!(*page)
+/// }
+/// ```
+#[hook]
+pub fn use_hotkeys_with_options<H>(
+    hotkeys: H,
+    callback: Callback<web_sys::KeyboardEvent>,
+    options: UseHotkeysOptions,
+) where
+    H: Into<Hotkeys>,
+{
+    let hotkeys = use_latest(hotkeys.into());
+    let progress = use_mut_ref(Progress::default);
+
+    let onkeydown = use_callback(
+        (callback, options),
+        move |event: web_sys::KeyboardEvent, (callback, options)| {
+            if !options.enabled
+                || event.repeat()
+                || (!options.enable_on_inputs && is_editable(event.target()))
+            {
+                return;
+            }
+
+            let pressed = KeyCombo::from_event(&event);
+            if pressed.is_modifier() {
+                // don't interrupt a sequence
+                return;
+            }
+
+            let completed = progress.borrow_mut().advance(
+                &hotkeys.borrow(),
+                &pressed,
+                js_sys::Date::now(),
+                options.sequence_timeout.into(),
+            );
+
+            if completed {
+                if options.prevent_default {
+                    event.prevent_default();
+                }
+                callback.emit(event);
+            }
+        },
+    );
+
+    use_event_listener(ListenerTarget::Window, "keydown", onkeydown);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn key(key: &str) -> KeyCombo {
+        KeyCombo::parse(key)
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            KeyCombo::parse("Ctrl+Shift+K"),
+            KeyCombo {
+                key: "k".into(),
+                ctrl: true,
+                shift: true,
+                ..Default::default()
+            }
+        );
+        assert_eq!(key("esc").key, "escape");
+        assert_eq!(key("+").key, "+");
+        assert_eq!(
+            key("cmd++"),
+            KeyCombo {
+                key: "+".into(),
+                meta: true,
+                ..Default::default()
+            }
+        );
+
+        let hotkeys = Hotkeys::parse("ctrl+k, g d,");
+        assert_eq!(
+            hotkeys.0,
+            vec![vec![key("ctrl+k")], vec![key("g"), key("d")]]
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(key("ctrl+k").matches(&key("ctrl+k")));
+        assert!(!key("ctrl+k").matches(&key("ctrl+shift+k")));
+        assert!(!key("k").matches(&key("ctrl+k")));
+        // shift is implied for punctuation
+        assert!(key("?").matches(&key("shift+?")));
+    }
+
+    #[test]
+    fn test_sequence() {
+        let hotkeys = Hotkeys::parse("g d, ctrl+k");
+        let mut progress = Progress::default();
+
+        assert!(!progress.advance(&hotkeys, &key("g"), 0f64, 1_000f64));
+        assert!(progress.advance(&hotkeys, &key("d"), 100f64, 1_000f64));
+
+        // restart the sequence
+        assert!(!progress.advance(&hotkeys, &key("g"), 200f64, 1_000f64));
+        assert!(!progress.advance(&hotkeys, &key("g"), 300f64, 1_000f64));
+        assert!(progress.advance(&hotkeys, &key("d"), 400f64, 1_000f64));
+
+        // too slow
+        assert!(!progress.advance(&hotkeys, &key("g"), 500f64, 1_000f64));
+        assert!(!progress.advance(&hotkeys, &key("d"), 2_000f64, 1_000f64));
+
+        assert!(progress.advance(&hotkeys, &key("ctrl+k"), 2_100f64, 1_000f64));
+    }
+
+    #[test]
+    fn test_changed_hotkeys() {
+        let mut progress = Progress::default();
+
+        assert!(!progress.advance(&Hotkeys::parse("g g d"), &key("g"), 0f64, 1_000f64));
+        assert!(!progress.advance(&Hotkeys::parse("g g d"), &key("g"), 100f64, 1_000f64));
+
+        // the same number of shorter sequences, starting over
+        let hotkeys = Hotkeys::parse("g d");
+        assert!(!progress.advance(&hotkeys, &key("d"), 200f64, 1_000f64));
+        assert!(!progress.advance(&hotkeys, &key("g"), 300f64, 1_000f64));
+        assert!(progress.advance(&hotkeys, &key("d"), 400f64, 1_000f64));
+
+        // empty sequences never match
+        let hotkeys = Hotkeys(vec![vec![]]);
+        assert!(!progress.advance(&hotkeys, &key("g"), 500f64, 1_000f64));
+    }
+
+    #[test]
+    fn test_repeated_prefix() {
+        let hotkeys = Hotkeys::parse("g g d, a b a c");
+        let mut progress = Progress::default();
+
+        assert!(!progress.advance(&hotkeys, &key("g"), 0f64, 1_000f64));
+        assert!(!progress.advance(&hotkeys, &key("g"), 100f64, 1_000f64));
+        assert!(!progress.advance(&hotkeys, &key("g"), 200f64, 1_000f64));
+        assert!(progress.advance(&hotkeys, &key("d"), 300f64, 1_000f64));
+
+        for (time, pressed) in ["a", "b", "a", "b", "a"].into_iter().enumerate() {
+            assert!(!progress.advance(
+                &hotkeys,
+                &key(pressed),
+                (400 + time * 100) as f64,
+                1_000f64
+            ));
+        }
+        assert!(progress.advance(&hotkeys, &key("c"), 900f64, 1_000f64));
+    }
+}
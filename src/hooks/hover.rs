@@ -0,0 +1,124 @@
+//! Hooks for tracking the hover state of elements
+
+use crate::hooks::use_event_listener;
+use gloo_timers::callback::Timeout;
+use yew::prelude::*;
+
+/// Options for [`use_hover_with_options`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UseHoverOptions {
+    /// Delay, in milliseconds, before reporting the element as hovered.
+    pub delay_enter: u32,
+    /// Delay, in milliseconds, before reporting the element as no longer hovered.
+    pub delay_leave: u32,
+    /// Also consider touch input as hovering.
+    pub touch: bool,
+}
+
+impl UseHoverOptions {
+    /// Use delays for entering and leaving the element
+    pub const fn with_delay(delay_enter: u32, delay_leave: u32) -> Self {
+        Self {
+            delay_enter,
+            delay_leave,
+            touch: false,
+        }
+    }
+}
+
+/// Track if an element is hovered.
+///
+/// This is [`use_hover_with_options`] using the default options.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let node = use_node_ref();
+///   let hovered = use_hover(node.clone());
+///
+///   html!(<div ref={node}>{ if hovered { "Hello" } else { "Hover me" } }</div>)
+/// }
+/// ```
+#[hook]
+pub fn use_hover(node: NodeRef) -> bool {
+    use_hover_with_options(node, UseHoverOptions::default())
+}
+
+/// Track if an element is hovered, using options.
+///
+/// Returns `true` while a pointer is over the element referenced by `node`. As touch input only
+/// emulates hovering, it is ignored by default. Using delays, short movements over the element can
+/// be ignored, which is useful for tooltips and preview cards.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Tooltip)]
+/// fn tooltip() -> Html {
+///   let node = use_node_ref();
+///   let show = use_hover_with_options(node.clone(), UseHoverOptions::with_delay(500, 200));
+///
+///   html!(
+///     <span ref={node}>
+///       {"Hover me"}
+///       if show {
+///         <div class="tooltip">{"Some details"}</div>
+///       }
+///     </span>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_hover_with_options(node: NodeRef, options: UseHoverOptions) -> bool {
+    let hovered = use_state_eq(|| false);
+    let timeout = use_mut_ref(|| None::<Timeout>);
+
+    let update = {
+        let timeout = timeout.clone();
+        use_callback(
+            (hovered.clone(), options),
+            move |(event, value): (web_sys::PointerEvent, bool), (hovered, options)| {
+                if !options.touch && event.pointer_type() == "touch" {
+                    return;
+                }
+
+                let delay = match value {
+                    true => options.delay_enter,
+                    false => options.delay_leave,
+                };
+
+                // replacing (or clearing) a pending timeout will cancel it
+                let mut timeout = timeout.borrow_mut();
+                if delay == 0 {
+                    *timeout = None;
+                    hovered.set(value);
+                } else {
+                    let hovered = hovered.clone();
+                    *timeout = Some(Timeout::new(delay, move || hovered.set(value)));
+                }
+            },
+        )
+    };
+
+    let onenter = use_callback(update.clone(), |event, update| update.emit((event, true)));
+    let onleave = use_callback(update, |event, update| update.emit((event, false)));
+
+    use_event_listener(node.clone(), "pointerenter", onenter);
+    use_event_listener(node, "pointerleave", onleave);
+
+    use_effect_with((), move |()| {
+        move || {
+            timeout.borrow_mut().take();
+        }
+    });
+
+    *hovered
+}
@@ -0,0 +1,346 @@
+//! Hooks for detecting idle users
+
+use crate::hooks::{use_permission, UsePermissionState};
+use gloo_events::EventListener;
+use gloo_timers::callback::Timeout;
+use std::{cell::RefCell, ops::Deref, rc::Rc};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use yew::prelude::*;
+
+/// The minimum threshold accepted by the Idle Detection API, in milliseconds.
+const MIN_NATIVE_THRESHOLD: u32 = 60_000;
+
+/// Events considered as user activity, when falling back to heuristics.
+const ACTIVITY_EVENTS: [&str; 6] = [
+    "pointermove",
+    "pointerdown",
+    "keydown",
+    "wheel",
+    "touchstart",
+    "scroll",
+];
+
+/// The idle state of the user.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IdleState {
+    /// The user did not interact for at least the configured threshold.
+    pub user_idle: bool,
+    /// The screen is locked.
+    ///
+    /// Only reported by the Idle Detection API, and always `false` when falling back to
+    /// heuristics.
+    pub screen_locked: bool,
+}
+
+impl IdleState {
+    fn from_detector(detector: &web_sys::EventTarget) -> Self {
+        let get = |name: &str| {
+            js_sys::Reflect::get(detector, &name.into())
+                .ok()
+                .and_then(|value| value.as_string())
+        };
+        Self {
+            user_idle: get("userState").as_deref() == Some("idle"),
+            screen_locked: get("screenState").as_deref() == Some("locked"),
+        }
+    }
+}
+
+/// Options for [`use_idle_detection_with_options`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UseIdleDetectionOptions {
+    /// The time without interaction, in milliseconds, after which the user is considered idle.
+    ///
+    /// The Idle Detection API requires at least one minute, shorter values are raised to that
+    /// when using it.
+    pub threshold: u32,
+}
+
+impl Default for UseIdleDetectionOptions {
+    fn default() -> Self {
+        Self {
+            threshold: MIN_NATIVE_THRESHOLD,
+        }
+    }
+}
+
+fn detector_class() -> Option<js_sys::Function> {
+    js_sys::Reflect::get(&gloo_utils::window(), &"IdleDetector".into())
+        .ok()?
+        .dyn_into()
+        .ok()
+}
+
+/// A running `IdleDetector`, stopped when dropped.
+struct Detector {
+    controller: web_sys::AbortController,
+    _listener: EventListener,
+}
+
+impl Drop for Detector {
+    fn drop(&mut self) {
+        self.controller.abort();
+    }
+}
+
+impl Detector {
+    fn start(threshold: u32, state: UseStateHandle<IdleState>) -> Option<Self> {
+        let detector: web_sys::EventTarget =
+            js_sys::Reflect::construct(&detector_class()?, &js_sys::Array::new())
+                .ok()?
+                .unchecked_into();
+        let start: js_sys::Function = js_sys::Reflect::get(&detector, &"start".into())
+            .ok()?
+            .dyn_into()
+            .ok()?;
+
+        let controller = web_sys::AbortController::new().ok()?;
+        let options = js_sys::Object::new();
+        js_sys::Reflect::set(
+            &options,
+            &"threshold".into(),
+            &threshold.max(MIN_NATIVE_THRESHOLD).into(),
+        )
+        .ok()?;
+        js_sys::Reflect::set(&options, &"signal".into(), &controller.signal()).ok()?;
+
+        let listener = {
+            let detector = detector.clone();
+            let state = state.clone();
+            EventListener::new(&detector.clone(), "change", move |_| {
+                state.set(IdleState::from_detector(&detector));
+            })
+        };
+
+        let promise: js_sys::Promise = start.call1(&detector, &options).ok()?.dyn_into().ok()?;
+        spawn_local(async move {
+            // starting fails when being aborted, which is expected
+            if JsFuture::from(promise).await.is_ok() {
+                state.set(IdleState::from_detector(&detector));
+            }
+        });
+
+        Some(Self {
+            controller,
+            _listener: listener,
+        })
+    }
+}
+
+/// Tracks user activity using events, as long as the Idle Detection API can't be used.
+struct Heuristic {
+    _listeners: Vec<EventListener>,
+    _timeout: Rc<RefCell<Option<Timeout>>>,
+}
+
+impl Heuristic {
+    fn start(threshold: u32, state: UseStateHandle<IdleState>) -> Self {
+        let timeout = Rc::new(RefCell::new(None::<Timeout>));
+
+        let idle = {
+            let state = state.clone();
+            move || {
+                state.set(IdleState {
+                    user_idle: true,
+                    screen_locked: false,
+                })
+            }
+        };
+
+        let active = {
+            let timeout = timeout.clone();
+            let idle = idle.clone();
+            Rc::new(move || {
+                state.set(IdleState::default());
+                // replacing an existing timeout will cancel it
+                *timeout.borrow_mut() = Some(Timeout::new(threshold, idle.clone()));
+            })
+        };
+        active();
+
+        let window = gloo_utils::window();
+        let mut listeners = ACTIVITY_EVENTS
+            .iter()
+            .map(|event| {
+                let active = active.clone();
+                EventListener::new(&window, *event, move |_| active())
+            })
+            .collect::<Vec<_>>();
+
+        // a hidden page counts as idle right away
+        let document = gloo_utils::document();
+        listeners.push({
+            let timeout = timeout.clone();
+            EventListener::new(&document.clone(), "visibilitychange", move |_| {
+                if document.hidden() {
+                    timeout.borrow_mut().take();
+                    idle();
+                } else {
+                    active();
+                }
+            })
+        });
+
+        Self {
+            _listeners: listeners,
+            _timeout: timeout,
+        }
+    }
+}
+
+/// State handle for the [`use_idle_detection`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseIdleDetectionHandle {
+    state: UseStateHandle<IdleState>,
+    permission: UseStateHandle<UsePermissionState>,
+    native: bool,
+}
+
+impl UseIdleDetectionHandle {
+    /// The permission to use the Idle Detection API.
+    pub fn permission(&self) -> UsePermissionState {
+        *self.permission
+    }
+
+    /// Check if the state is reported by the Idle Detection API, rather than heuristics.
+    pub fn is_native(&self) -> bool {
+        self.native
+    }
+
+    /// Ask the user for permission to use the Idle Detection API.
+    ///
+    /// Browsers only allow this in response to a user interaction, like a click.
+    pub fn request_permission(&self) {
+        let Some(class) = detector_class() else {
+            return;
+        };
+        let Some(promise) = js_sys::Reflect::get(&class, &"requestPermission".into())
+            .ok()
+            .and_then(|request| request.dyn_into::<js_sys::Function>().ok())
+            .and_then(|request| request.call0(&class).ok())
+            .and_then(|promise| promise.dyn_into::<js_sys::Promise>().ok())
+        else {
+            return;
+        };
+
+        let permission = self.permission.clone();
+        spawn_local(async move {
+            let granted = JsFuture::from(promise)
+                .await
+                .ok()
+                .and_then(|result| result.as_string())
+                .is_some_and(|result| result == "granted");
+            permission.set(match granted {
+                true => UsePermissionState::Granted,
+                false => UsePermissionState::Denied,
+            });
+        });
+    }
+}
+
+impl Deref for UseIdleDetectionHandle {
+    type Target = IdleState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// Detect if the user is idle.
+///
+/// This is [`use_idle_detection_with_options`] using the default options.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let idle = use_idle_detection();
+///   let onclick = use_callback(idle.clone(), |_, idle| idle.request_permission());
+///
+///   html!(
+///     <>
+///       if idle.user_idle { <p>{"Are you still there?"}</p> }
+///       if idle.permission() == UsePermissionState::Prompt {
+///         <button {onclick}>{"Enable idle detection"}</button>
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_idle_detection() -> UseIdleDetectionHandle {
+    use_idle_detection_with_options(UseIdleDetectionOptions::default())
+}
+
+/// Detect if the user is idle, using options.
+///
+/// Uses the [Idle Detection API](https://developer.mozilla.org/en-US/docs/Web/API/Idle_Detection_API)
+/// once the permission is granted, which also reports a locked screen. The permission must be
+/// requested in response to a user interaction, using `request_permission` of the returned handle.
+///
+/// As long as the API is not available, or the permission is not granted, user activity is
+/// tracked using pointer, keyboard, and scroll events of the window instead. A hidden page is
+/// considered idle.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let idle = use_idle_detection_with_options(UseIdleDetectionOptions { threshold: 5 * 60_000 });
+///
+///   html!(
+///     if idle.user_idle || idle.screen_locked {
+///       <p>{"Paused"}</p>
+///     } else {
+///       <p>{"Running"}</p>
+///     }
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_idle_detection_with_options(options: UseIdleDetectionOptions) -> UseIdleDetectionHandle {
+    let state = use_state_eq(IdleState::default);
+    let permission = use_state_eq(|| UsePermissionState::Pending);
+
+    let queried = use_permission("idle-detection");
+    {
+        let permission = permission.clone();
+        use_effect_with(queried, move |queried| permission.set(*queried));
+    }
+
+    let native = detector_class().is_some() && permission.is_granted();
+
+    {
+        let state = state.clone();
+        use_effect_with((native, options.threshold), move |(native, threshold)| {
+            let detector = match native {
+                true => Detector::start(*threshold, state.clone()),
+                false => None,
+            };
+            // also fall back if starting the detector failed
+            let heuristic = match detector {
+                Some(_) => None,
+                None => Some(Heuristic::start(*threshold, state)),
+            };
+            move || {
+                drop(detector);
+                drop(heuristic);
+            }
+        });
+    }
+
+    UseIdleDetectionHandle {
+        state,
+        permission,
+        native,
+    }
+}
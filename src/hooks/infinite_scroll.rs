@@ -0,0 +1,199 @@
+//! Hooks for loading more content while scrolling
+
+use crate::hooks::{use_element_visibility, use_latest};
+use std::{future::Future, ops::Deref, rc::Rc};
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+/// State of the [`use_infinite_scroll`] hook.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InfiniteScroll<E> {
+    /// A load operation is currently running.
+    pub loading: bool,
+    /// The last load operation reported that there is no more content.
+    pub exhausted: bool,
+    /// The error of the last load operation, if it failed.
+    pub error: Option<E>,
+}
+
+impl<E> Default for InfiniteScroll<E> {
+    fn default() -> Self {
+        Self {
+            loading: false,
+            exhausted: false,
+            error: None,
+        }
+    }
+}
+
+/// Actions of the [`InfiniteScroll`] reducer.
+pub enum InfiniteScrollAction<E> {
+    /// Start loading more items.
+    Start,
+    /// Finish loading, with the information if there are more items, or an error.
+    Done(Result<bool, E>),
+    /// Start over, allowing to load more items again.
+    Reset,
+}
+
+impl<E> Reducible for InfiniteScroll<E> {
+    type Action = InfiniteScrollAction<E>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        Rc::new(match action {
+            InfiniteScrollAction::Start => Self {
+                loading: true,
+                exhausted: self.exhausted,
+                error: None,
+            },
+            InfiniteScrollAction::Done(Ok(has_more)) => Self {
+                loading: false,
+                exhausted: !has_more,
+                error: None,
+            },
+            InfiniteScrollAction::Done(Err(err)) => Self {
+                loading: false,
+                exhausted: self.exhausted,
+                error: Some(err),
+            },
+            InfiniteScrollAction::Reset => Self::default(),
+        })
+    }
+}
+
+/// State handle for the [`use_infinite_scroll`] hook.
+pub struct UseInfiniteScrollHandle<E> {
+    state: UseReducerHandle<InfiniteScroll<E>>,
+    load_more: Callback<()>,
+}
+
+impl<E> UseInfiniteScrollHandle<E> {
+    /// Load more content now, unless a load operation is already running.
+    ///
+    /// This also loads content if the last load operation failed or the content was exhausted.
+    pub fn load_more(&self) {
+        self.load_more.emit(());
+    }
+
+    /// Reset the state, re-enabling loading when the sentinel is visible.
+    pub fn reset(&self) {
+        self.state.dispatch(InfiniteScrollAction::Reset);
+    }
+}
+
+impl<E> Clone for UseInfiniteScrollHandle<E> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            load_more: self.load_more.clone(),
+        }
+    }
+}
+
+impl<E> PartialEq for UseInfiniteScrollHandle<E>
+where
+    E: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<E> Deref for UseInfiniteScrollHandle<E> {
+    type Target = InfiniteScroll<E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// Load more content, when a sentinel element becomes visible.
+///
+/// Whenever the element referenced by `sentinel` (typically placed at the end of a list) becomes
+/// visible, the future created by `f` is run. The future resolves to `Ok(has_more)`, reporting if
+/// there is more content to load. Once exhausted, or when loading failed, no more content will be
+/// loaded automatically until the handle is [`reset`](UseInfiniteScrollHandle::reset).
+///
+/// Only one load operation runs at a time. If the sentinel is still visible after loading, more
+/// content will be loaded right away.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let items = use_state(Vec::<String>::new);
+///   let sentinel = use_node_ref();
+///
+///   let scroll = {
+///     let items = items.clone();
+///     use_infinite_scroll(sentinel.clone(), move || {
+///       let items = items.clone();
+///       async move {
+///         let page = fetch(items.len()).await?;
+///         let has_more = !page.is_empty();
+///         let mut next = (*items).clone();
+///         next.extend(page);
+///         items.set(next);
+///         Ok::<_, String>(has_more)
+///       }
+///     })
+///   };
+///
+///   html!(
+///     <ul>
+///       { for items.iter().map(|item| html!(<li>{ item }</li>)) }
+///       <li ref={sentinel}>{ if scroll.loading { "Loading…" } else { "" } }</li>
+///     </ul>
+///   )
+/// }
+///
+/// async fn fetch(offset: usize) -> Result<Vec<String>, String> {
+///     Ok((offset..offset + 10).map(|i| format!("Item {i}")).collect())
+/// }
+/// ```
+#[hook]
+pub fn use_infinite_scroll<F, Fut, E>(sentinel: NodeRef, f: F) -> UseInfiniteScrollHandle<E>
+where
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = Result<bool, E>> + 'static,
+    E: 'static,
+{
+    let state = use_reducer(InfiniteScroll::default);
+    let f = use_latest(f);
+    let running = use_mut_ref(|| false);
+    let visible = use_element_visibility(sentinel);
+
+    let load_more = {
+        let state = state.clone();
+        use_callback((), move |(), ()| {
+            if running.replace(true) {
+                return;
+            }
+            state.dispatch(InfiniteScrollAction::Start);
+            let future = (f.borrow())();
+            let state = state.clone();
+            let running = running.clone();
+            spawn_local(async move {
+                let result = future.await;
+                *running.borrow_mut() = false;
+                state.dispatch(InfiniteScrollAction::Done(result));
+            });
+        })
+    };
+
+    {
+        let load_more = load_more.clone();
+        let idle = !state.loading && !state.exhausted && state.error.is_none();
+        use_effect_with((visible, idle), move |(visible, idle)| {
+            if *visible && *idle {
+                load_more.emit(());
+            }
+        });
+    }
+
+    UseInfiniteScrollHandle { state, load_more }
+}
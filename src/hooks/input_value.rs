@@ -0,0 +1,173 @@
+//! Hooks for binding the value of inputs
+
+use std::{fmt::Display, str::FromStr};
+use wasm_bindgen::JsCast;
+use yew::prelude::*;
+
+/// Read the value of an `<input>`, `<textarea>`, or `<select>` element.
+fn read_value(event: &web_sys::InputEvent) -> Option<String> {
+    let target = event.target()?;
+    if let Some(input) = target.dyn_ref::<web_sys::HtmlInputElement>() {
+        Some(input.value())
+    } else if let Some(textarea) = target.dyn_ref::<web_sys::HtmlTextAreaElement>() {
+        Some(textarea.value())
+    } else {
+        target
+            .dyn_ref::<web_sys::HtmlSelectElement>()
+            .map(|select| select.value())
+    }
+}
+
+/// Bind the value of an input to a state.
+///
+/// Returns the state, and a callback to be used as `oninput` handler of an `<input>`,
+/// `<textarea>`, or `<select>` element, updating the state with the value of the element.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let (name, oninput) = use_input_value(String::new);
+///
+///   html!(
+///     <>
+///       <input value={(*name).clone()} {oninput} />
+///       <div>{ format!("Hello {}", *name) }</div>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_input_value<F>(init: F) -> (UseStateHandle<String>, Callback<web_sys::InputEvent>)
+where
+    F: FnOnce() -> String,
+{
+    let value = use_state_eq(init);
+
+    let oninput = use_callback(value.clone(), |event: web_sys::InputEvent, value| {
+        if let Some(next) = read_value(&event) {
+            value.set(next);
+        }
+    });
+
+    (value, oninput)
+}
+
+/// The text of an input, and the value parsed from it.
+#[derive(Clone, Debug, PartialEq)]
+struct Parsed<T> {
+    text: String,
+    value: Option<T>,
+}
+
+/// State handle for the [`use_parsed_input_value`] hook.
+pub struct UseParsedInputValueHandle<T> {
+    state: UseStateHandle<Parsed<T>>,
+    oninput: Callback<web_sys::InputEvent>,
+}
+
+impl<T> UseParsedInputValueHandle<T>
+where
+    T: Display,
+{
+    /// The text, which should be used as value of the input.
+    pub fn text(&self) -> &str {
+        &self.state.text
+    }
+
+    /// The parsed value, `None` if the text could not be parsed.
+    pub fn value(&self) -> Option<&T> {
+        self.state.value.as_ref()
+    }
+
+    /// The `oninput` handler for the input.
+    pub fn oninput(&self) -> Callback<web_sys::InputEvent> {
+        self.oninput.clone()
+    }
+
+    /// Set a new value, formatting it as text.
+    pub fn set(&self, value: T) {
+        self.state.set(Parsed {
+            text: value.to_string(),
+            value: Some(value),
+        });
+    }
+}
+
+impl<T> Clone for UseParsedInputValueHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            oninput: self.oninput.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseParsedInputValueHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+/// Bind the value of an input to a parsed state, like a number.
+///
+/// The text of the input is parsed using [`FromStr`], and values are formatted using [`Display`].
+/// The text is kept as typed, even if it can't be parsed (like an incomplete number), in which case
+/// the value is `None`.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let amount = use_parsed_input_value(|| 1.5f64);
+///
+///   let onclick = use_callback(amount.clone(), |_, amount| amount.set(0f64));
+///
+///   html!(
+///     <>
+///       <input type="number" value={amount.text().to_string()} oninput={amount.oninput()} />
+///       <button {onclick}>{"Reset"}</button>
+///       <div>
+///         { match amount.value() {
+///           Some(amount) => format!("Total: {:.2}", amount * 1.2),
+///           None => "Invalid amount".into(),
+///         } }
+///       </div>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_parsed_input_value<T, F>(init: F) -> UseParsedInputValueHandle<T>
+where
+    T: FromStr + Display + PartialEq + 'static,
+    F: FnOnce() -> T,
+{
+    let state = use_state_eq(move || {
+        let value = init();
+        Parsed {
+            text: value.to_string(),
+            value: Some(value),
+        }
+    });
+
+    let oninput = use_callback(state.clone(), |event: web_sys::InputEvent, state| {
+        if let Some(text) = read_value(&event) {
+            let value = text.trim().parse().ok();
+            state.set(Parsed { text, value });
+        }
+    });
+
+    UseParsedInputValueHandle { state, oninput }
+}
@@ -0,0 +1,210 @@
+//! Hooks for observing the intersection of elements
+//!
+//! ## Lifecycle
+//!
+//! The observer is created once the component got rendered, observing the element referenced by
+//! the [`NodeRef`]. It gets re-created when the node reference or the options change, and
+//! disconnected when the component gets unmounted.
+
+use crate::hooks::use_latest;
+use wasm_bindgen::{closure::Closure, JsCast};
+use yew::prelude::*;
+
+/// Options for [`use_intersection_observer`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UseIntersectionOptions {
+    /// The element used as viewport, defaults to the browser viewport.
+    pub root: Option<NodeRef>,
+    /// Margin around the root, like `10px 20px`.
+    pub root_margin: Option<String>,
+    /// Ratios of visibility, at which the callback should be notified.
+    pub threshold: Vec<f64>,
+}
+
+impl UseIntersectionOptions {
+    fn to_init(&self) -> web_sys::IntersectionObserverInit {
+        let init = web_sys::IntersectionObserverInit::new();
+        if let Some(root) = &self.root {
+            init.set_root(root.cast::<web_sys::Element>().as_ref());
+        }
+        if let Some(root_margin) = &self.root_margin {
+            init.set_root_margin(root_margin);
+        }
+        if !self.threshold.is_empty() {
+            let threshold: js_sys::Array = self
+                .threshold
+                .iter()
+                .map(|threshold| wasm_bindgen::JsValue::from_f64(*threshold))
+                .collect();
+            init.set_threshold(&threshold);
+        }
+        init
+    }
+}
+
+/// Observe the intersection of an element with the viewport.
+///
+/// Every entry reported by the
+/// [`IntersectionObserver`](https://developer.mozilla.org/en-US/docs/Web/API/IntersectionObserver)
+/// will be forwarded to the callback. The latest callback will always be used. See the
+/// [module documentation](self) for the lifecycle of the observer.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let node = use_node_ref();
+///   let ratio = use_state_eq(|| 0f64);
+///
+///   let onintersect = use_callback(ratio.clone(), |entry: web_sys::IntersectionObserverEntry, ratio| {
+///     ratio.set(entry.intersection_ratio());
+///   });
+///   use_intersection_observer(
+///     node.clone(),
+///     UseIntersectionOptions {
+///       threshold: vec![0f64, 0.5, 1f64],
+///       ..Default::default()
+///     },
+///     onintersect,
+///   );
+///
+///   html!(<div ref={node}>{ format!("{:.0}% visible", *ratio * 100f64) }</div>)
+/// }
+/// ```
+#[hook]
+pub fn use_intersection_observer(
+    node: NodeRef,
+    options: UseIntersectionOptions,
+    callback: Callback<web_sys::IntersectionObserverEntry>,
+) {
+    use_intersection_observer_active(node, options, callback, true);
+}
+
+/// Like [`use_intersection_observer`], but only observing while `active` is `true`.
+#[hook]
+pub(crate) fn use_intersection_observer_active(
+    node: NodeRef,
+    options: UseIntersectionOptions,
+    callback: Callback<web_sys::IntersectionObserverEntry>,
+    active: bool,
+) {
+    let callback = use_latest(callback);
+
+    use_effect_with((node, options, active), move |(node, options, active)| {
+        let element = node.cast::<web_sys::Element>().filter(|_| *active);
+
+        let closure = Closure::<dyn Fn(js_sys::Array)>::new(move |entries: js_sys::Array| {
+            let callback = callback.borrow().clone();
+            for entry in entries.iter() {
+                if let Ok(entry) = entry.dyn_into::<web_sys::IntersectionObserverEntry>() {
+                    callback.emit(entry);
+                }
+            }
+        });
+
+        let observer = element.and_then(|element| {
+            let observer = web_sys::IntersectionObserver::new_with_options(
+                closure.as_ref().unchecked_ref(),
+                &options.to_init(),
+            )
+            .ok()?;
+            observer.observe(&element);
+            Some(observer)
+        });
+
+        move || {
+            if let Some(observer) = observer {
+                observer.disconnect();
+            }
+            drop(closure);
+        }
+    });
+}
+
+/// Options for [`use_element_visibility_with_options`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UseElementVisibilityOptions {
+    /// Stop observing once the element became visible for the first time.
+    pub once: bool,
+    /// The options of the underlying intersection observer.
+    pub intersection: UseIntersectionOptions,
+}
+
+impl UseElementVisibilityOptions {
+    /// Stop observing once the element became visible for the first time
+    pub fn enable_once() -> Self {
+        Self {
+            once: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// Check if an element is visible in the viewport.
+///
+/// This is [`use_element_visibility_with_options`] using the default options.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let node = use_node_ref();
+///   let visible = use_element_visibility(node.clone());
+///
+///   html!(<div ref={node}>{ if visible { "Hello" } else { "" } }</div>)
+/// }
+/// ```
+#[hook]
+pub fn use_element_visibility(node: NodeRef) -> bool {
+    use_element_visibility_with_options(node, UseElementVisibilityOptions::default())
+}
+
+/// Check if an element is visible in the viewport, using options.
+///
+/// Returns `true` while the element referenced by `node` is intersecting with the viewport (or the
+/// configured root). When using the `once` option, the observer stops once the element became
+/// visible, and the hook keeps returning `true`. This is useful for tracking impressions or lazy
+/// loading content.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(LazyImage)]
+/// fn lazy_image() -> Html {
+///   let node = use_node_ref();
+///   let visible =
+///     use_element_visibility_with_options(node.clone(), UseElementVisibilityOptions::enable_once());
+///
+///   html!(<img ref={node} src={ visible.then_some("/image.png") } />)
+/// }
+/// ```
+#[hook]
+pub fn use_element_visibility_with_options(
+    node: NodeRef,
+    options: UseElementVisibilityOptions,
+) -> bool {
+    let visible = use_state_eq(|| false);
+    let active = !(options.once && *visible);
+
+    let callback = use_callback(
+        (visible.clone(), options.once),
+        |entry: web_sys::IntersectionObserverEntry, (visible, once)| {
+            // once visible, stay visible
+            visible.set(entry.is_intersecting() || (*once && **visible));
+        },
+    );
+    use_intersection_observer_active(node, options.intersection, callback, active);
+
+    *visible
+}
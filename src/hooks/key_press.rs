@@ -0,0 +1,95 @@
+//! Hooks for tracking pressed keys
+
+use crate::hooks::{use_event_listener, ListenerTarget};
+use std::collections::HashSet;
+use yew::prelude::*;
+
+/// Check if one of a set of keys is currently pressed.
+///
+/// Keys are compared case-insensitively with the value of
+/// [`KeyboardEvent::key`](web_sys::KeyboardEvent::key), like `Shift`, `a`, or `ArrowUp`. Returns
+/// `true` while at least one of the `keys` is held down.
+///
+/// Listening happens on the window. When the window loses the focus, all keys are considered
+/// released, as the browser will not report releasing them.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let extend = use_key_press(["Shift"]);
+///   let forward = use_key_press(["ArrowUp", "w"]);
+///
+///   html!(
+///     <>
+///       <div>{ if extend { "Extending selection" } else { "Selecting" } }</div>
+///       <div>{ if forward { "Moving" } else { "Standing" } }</div>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_key_press<I, K>(keys: I) -> bool
+where
+    I: IntoIterator<Item = K>,
+    K: AsRef<str>,
+{
+    let keys = keys
+        .into_iter()
+        .map(|key| key.as_ref().to_lowercase())
+        .collect::<Vec<_>>();
+
+    let pressed = use_state_eq(|| false);
+    let down = use_mut_ref(HashSet::<String>::new);
+
+    {
+        let down = down.clone();
+        let pressed = pressed.clone();
+        use_effect_with(keys.clone(), move |_| {
+            // keys held down before no longer count
+            down.borrow_mut().clear();
+            pressed.set(false);
+        });
+    }
+
+    let onkeydown = {
+        let down = down.clone();
+        use_callback(
+            (keys, pressed.clone()),
+            move |event: web_sys::KeyboardEvent, (keys, pressed)| {
+                let key = event.key().to_lowercase();
+                if keys.contains(&key) {
+                    down.borrow_mut().insert(key);
+                    pressed.set(true);
+                }
+            },
+        )
+    };
+
+    let onkeyup = {
+        let down = down.clone();
+        use_callback(
+            pressed.clone(),
+            move |event: web_sys::KeyboardEvent, pressed| {
+                let mut down = down.borrow_mut();
+                down.remove(&event.key().to_lowercase());
+                pressed.set(!down.is_empty());
+            },
+        )
+    };
+
+    let onblur = use_callback(pressed.clone(), move |_: web_sys::Event, pressed| {
+        down.borrow_mut().clear();
+        pressed.set(false);
+    });
+
+    use_event_listener(ListenerTarget::Window, "keydown", onkeydown);
+    use_event_listener(ListenerTarget::Window, "keyup", onkeyup);
+    use_event_listener(ListenerTarget::Window, "blur", onblur);
+
+    *pressed
+}
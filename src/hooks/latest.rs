@@ -0,0 +1,90 @@
+//! Hooks for keeping the latest, or previous, value
+
+use std::{cell::RefCell, rc::Rc};
+use yew::prelude::*;
+
+/// Keep a reference to the latest value.
+///
+/// This returns a shared, mutable reference, which will always hold the value provided to the
+/// most recent render. This is useful for closures which outlive the render they were created in
+/// (like callbacks of timers or event listeners), but should still see the latest value.
+///
+/// The returned reference is the same instance across renders.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   name: String,
+/// }
+///
+/// #[function_component(Example)]
+/// fn example(props: &Props) -> Html {
+///   let name = use_latest(props.name.clone());
+///   let onclick = use_callback((), move |_: MouseEvent, ()| {
+///     // will always see the latest name, without re-creating the callback
+///     let _greeting = format!("Hello {}", name.borrow());
+///   });
+///   html!(<button {onclick}>{"Greet"}</button>)
+/// }
+/// ```
+#[hook]
+pub fn use_latest<T>(value: T) -> Rc<RefCell<T>>
+where
+    T: 'static,
+{
+    let mut value = Some(value);
+    let latest = use_mut_ref(|| {
+        value
+            .take()
+            .expect("value must be present on initialization")
+    });
+
+    if let Some(value) = value {
+        *latest.borrow_mut() = value;
+    }
+
+    latest
+}
+
+/// Get the value of the previous render.
+///
+/// Returns the value provided to this hook during the previous render of the component, or
+/// `None` on the first render. This allows reacting to how a value changed, not only that it
+/// changed.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   price: u32,
+/// }
+///
+/// #[function_component(Price)]
+/// fn price(props: &Props) -> Html {
+///   let previous = use_previous(props.price);
+///
+///   let class = match previous {
+///     Some(previous) if previous < props.price => "up",
+///     Some(previous) if previous > props.price => "down",
+///     _ => "",
+///   };
+///
+///   html!(<span {class}>{ props.price }</span>)
+/// }
+/// ```
+#[hook]
+pub fn use_previous<T>(value: T) -> Option<T>
+where
+    T: 'static,
+{
+    use_mut_ref(|| None).replace(Some(value))
+}
@@ -0,0 +1,188 @@
+//! Hooks for detecting long presses
+
+use crate::hooks::{use_event_listener, use_latest};
+use gloo_timers::callback::Timeout;
+use std::{cell::Cell, rc::Rc};
+use yew::prelude::*;
+
+/// Options for [`use_long_press`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseLongPressOptions {
+    /// The time, in milliseconds, a pointer must be pressed down.
+    pub duration: u32,
+    /// The distance, in pixels, the pointer may move while being pressed.
+    pub tolerance: f64,
+    /// Prevent the context menu, which some browsers show on a long press.
+    pub prevent_context_menu: bool,
+    /// Called with the initial event, once the pointer was pressed down long enough.
+    pub onlongpress: Option<Callback<web_sys::PointerEvent>>,
+    /// Called with the final event, if the pointer was released before.
+    pub onclick: Option<Callback<web_sys::PointerEvent>>,
+}
+
+impl Default for UseLongPressOptions {
+    fn default() -> Self {
+        Self {
+            duration: 500,
+            tolerance: 10f64,
+            prevent_context_menu: true,
+            onlongpress: None,
+            onclick: None,
+        }
+    }
+}
+
+struct Press {
+    pointer_id: i32,
+    x: f64,
+    y: f64,
+    fired: Rc<Cell<bool>>,
+    _timeout: Timeout,
+}
+
+/// Distinguish a long press from a click.
+///
+/// Pressing the primary pointer button on the element referenced by `node`, and holding it for
+/// the configured duration, calls the `onlongpress` callback. Releasing it before calls the
+/// `onclick` callback instead. Moving the pointer further than the configured tolerance cancels
+/// both. The latest callbacks will always be used.
+///
+/// As the element would still receive a `click` event after a long press, the `onclick` callback
+/// of the options should be used instead.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let node = use_node_ref();
+///   let menu = use_state_eq(|| false);
+///
+///   let onlongpress = use_callback(menu.clone(), |_, menu| menu.set(true));
+///   let onclick = use_callback(menu.clone(), |_, menu| menu.set(false));
+///   use_long_press(
+///     node.clone(),
+///     UseLongPressOptions {
+///       onlongpress: Some(onlongpress),
+///       onclick: Some(onclick),
+///       ..Default::default()
+///     },
+///   );
+///
+///   html!(
+///     <div ref={node}>
+///       {"Press me"}
+///       if *menu {
+///         <ul class="context-menu"><li>{"Delete"}</li></ul>
+///       }
+///     </div>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_long_press(node: NodeRef, options: UseLongPressOptions) {
+    let options = use_latest(options);
+    let press = use_mut_ref(|| None::<Press>);
+
+    let onpointerdown = {
+        let press = press.clone();
+        let options = options.clone();
+        use_callback((), move |event: web_sys::PointerEvent, ()| {
+            if !event.is_primary() || event.button() != 0 {
+                return;
+            }
+
+            let fired = Rc::new(Cell::new(false));
+            let timeout = {
+                let duration = options.borrow().duration;
+                let fired = fired.clone();
+                let options = options.clone();
+                let event = event.clone();
+                Timeout::new(duration, move || {
+                    fired.set(true);
+                    let onlongpress = options.borrow().onlongpress.clone();
+                    if let Some(onlongpress) = onlongpress {
+                        onlongpress.emit(event);
+                    }
+                })
+            };
+
+            *press.borrow_mut() = Some(Press {
+                pointer_id: event.pointer_id(),
+                x: event.client_x().into(),
+                y: event.client_y().into(),
+                fired,
+                _timeout: timeout,
+            });
+        })
+    };
+
+    let onpointermove = {
+        let press = press.clone();
+        let options = options.clone();
+        use_callback((), move |event: web_sys::PointerEvent, ()| {
+            let tolerance = options.borrow().tolerance;
+            let mut press = press.borrow_mut();
+            if let Some(current) = &*press {
+                let dx = f64::from(event.client_x()) - current.x;
+                let dy = f64::from(event.client_y()) - current.y;
+                if current.pointer_id == event.pointer_id() && dx.hypot(dy) > tolerance {
+                    // dropping the timeout cancels it
+                    *press = None;
+                }
+            }
+        })
+    };
+
+    let onpointerup = {
+        let press = press.clone();
+        let options = options.clone();
+        use_callback((), move |event: web_sys::PointerEvent, ()| {
+            let released = {
+                let mut press = press.borrow_mut();
+                match &*press {
+                    Some(current) if current.pointer_id == event.pointer_id() => press.take(),
+                    _ => None,
+                }
+            };
+            if released.is_some_and(|press| !press.fired.get()) {
+                let onclick = options.borrow().onclick.clone();
+                if let Some(onclick) = onclick {
+                    onclick.emit(event);
+                }
+            }
+        })
+    };
+
+    let onpointercancel = {
+        let press = press.clone();
+        use_callback((), move |_: web_sys::PointerEvent, ()| {
+            press.borrow_mut().take();
+        })
+    };
+
+    let oncontextmenu = {
+        let press = press.clone();
+        use_callback((), move |event: web_sys::Event, ()| {
+            if options.borrow().prevent_context_menu && press.borrow().is_some() {
+                event.prevent_default();
+            }
+        })
+    };
+
+    use_event_listener(node.clone(), "pointerdown", onpointerdown);
+    use_event_listener(node.clone(), "pointermove", onpointermove);
+    use_event_listener(node.clone(), "pointerup", onpointerup);
+    use_event_listener(node.clone(), "pointercancel", onpointercancel.clone());
+    use_event_listener(node.clone(), "pointerleave", onpointercancel);
+    use_event_listener(node, "contextmenu", oncontextmenu);
+
+    use_effect_with((), move |()| {
+        move || {
+            press.borrow_mut().take();
+        }
+    });
+}
@@ -33,3 +33,114 @@ where
 {
     (*use_memo(value, |value| value.as_ref().map(f))).clone()
 }
+
+/// A hook for mapping a value, only when it changes.
+///
+/// This is like [`use_map`], but for any value, not just [`Option`]s. Compared to [`use_memo`],
+/// it returns a clone of the mapped value instead of an [`std::rc::Rc`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   name: String,
+/// }
+///
+/// #[function_component(Example)]
+/// fn example(props: &Props) -> Html {
+///   let greeting: String = use_map_value(props.name.clone(), |name| format!("Hello {name}"));
+///   html!(greeting)
+/// }
+/// ```
+#[hook]
+pub fn use_map_value<T, U, F>(value: T, f: F) -> U
+where
+    T: PartialEq + 'static,
+    U: Clone + 'static,
+    F: FnOnce(&T) -> U,
+{
+    (*use_memo(value, f)).clone()
+}
+
+/// A hook for mapping a value, only when it or its dependencies change.
+///
+/// This is like [`use_map_value`], but also re-evaluates the mapping function when the
+/// dependencies change, which are passed to the function as well.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   name: String,
+///   greeting: String,
+/// }
+///
+/// #[function_component(Example)]
+/// fn example(props: &Props) -> Html {
+///   let text: String = use_map_value_with_deps(
+///     props.name.clone(),
+///     props.greeting.clone(),
+///     |name, greeting| format!("{greeting} {name}"),
+///   );
+///   html!(text)
+/// }
+/// ```
+#[hook]
+pub fn use_map_value_with_deps<T, D, U, F>(value: T, deps: D, f: F) -> U
+where
+    T: PartialEq + 'static,
+    D: PartialEq + 'static,
+    U: Clone + 'static,
+    F: FnOnce(&T, &D) -> U,
+{
+    (*use_memo((value, deps), |(value, deps)| f(value, deps))).clone()
+}
+
+/// A hook for falling back to a default, if a value is missing.
+///
+/// Returns the value, or the default if it is `None`, along with a flag whether the default was
+/// used. The result is memoized, so the default is only created when the value changes to
+/// `None`. This is useful for optional properties, which have a default that is expensive to
+/// create, or must stay the same across renders.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   #[prop_or_default]
+///   id: Option<AttrValue>,
+/// }
+///
+/// #[function_component(Input)]
+/// fn input(props: &Props) -> Html {
+///   let (id, generated) = use_defaulted(props.id.clone(), || {
+///     AttrValue::from(format!("input-{}", js_sys::Math::random()))
+///   });
+///
+///   html!(<input {id} data-generated={generated.to_string()} />)
+/// }
+/// ```
+#[hook]
+pub fn use_defaulted<T, F>(value: Option<T>, default: F) -> (T, bool)
+where
+    T: Clone + PartialEq + 'static,
+    F: FnOnce() -> T,
+{
+    (*use_memo(value, |value| match value {
+        Some(value) => (value.clone(), false),
+        None => (default(), true),
+    }))
+    .clone()
+}
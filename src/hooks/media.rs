@@ -0,0 +1,392 @@
+//! Hooks for media devices
+
+use crate::hooks::{use_async_with_cloned_deps, use_async_with_deps, UseAsyncHandleDeps};
+use gloo_events::EventListener;
+use std::{
+    cell::Cell,
+    fmt::{Display, Formatter},
+    future::Future,
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use yew::prelude::*;
+
+/// An error accessing media devices.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MediaError {
+    /// The Media Devices API is not available, e.g. when not running in a secure context.
+    Unsupported,
+    /// The user, or the browser, denied access to the device.
+    PermissionDenied(String),
+    /// No device matches the requested constraints.
+    NotFound(String),
+    /// The device is in use, or can't be accessed due to a hardware error.
+    NotReadable(String),
+    /// Accessing the device failed for another reason.
+    Failed(String),
+}
+
+impl Display for MediaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str("Media Devices API is not supported"),
+            Self::PermissionDenied(msg) => write!(f, "Permission denied: {msg}"),
+            Self::NotFound(msg) => write!(f, "No matching device: {msg}"),
+            Self::NotReadable(msg) => write!(f, "Device not readable: {msg}"),
+            Self::Failed(msg) => write!(f, "Failed to access media device: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for MediaError {}
+
+impl From<JsValue> for MediaError {
+    fn from(err: JsValue) -> Self {
+        match err.dyn_ref::<web_sys::DomException>() {
+            Some(err) => match err.name().as_str() {
+                "NotAllowedError" | "SecurityError" => Self::PermissionDenied(err.message()),
+                "NotFoundError" | "OverconstrainedError" => Self::NotFound(err.message()),
+                "NotReadableError" | "AbortError" => Self::NotReadable(err.message()),
+                _ => Self::Failed(err.message()),
+            },
+            None => Self::Failed(format!("{err:?}")),
+        }
+    }
+}
+
+fn media_devices() -> Result<web_sys::MediaDevices, MediaError> {
+    let navigator = gloo_utils::window().navigator();
+    // not available in insecure contexts
+    match js_sys::Reflect::get(&navigator, &"mediaDevices".into()) {
+        Ok(devices) if !devices.is_undefined() => Ok(devices.unchecked_into()),
+        _ => Err(MediaError::Unsupported),
+    }
+}
+
+/// The kind of a media device.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MediaDeviceKind {
+    /// A microphone.
+    AudioInput,
+    /// A speaker, or headphones.
+    AudioOutput,
+    /// A camera.
+    VideoInput,
+}
+
+/// A media device.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MediaDevice {
+    /// The ID of the device, for requesting a specific device.
+    pub device_id: String,
+    /// Devices sharing a group, like a camera with a built-in microphone, have the same group ID.
+    pub group_id: String,
+    /// The kind of the device.
+    pub kind: MediaDeviceKind,
+    /// The label, which is empty as long as no permission to access media devices was granted.
+    pub label: String,
+}
+
+impl MediaDevice {
+    fn from_info(info: web_sys::MediaDeviceInfo) -> Option<Self> {
+        let kind = match info.kind() {
+            web_sys::MediaDeviceKind::Audioinput => MediaDeviceKind::AudioInput,
+            web_sys::MediaDeviceKind::Audiooutput => MediaDeviceKind::AudioOutput,
+            web_sys::MediaDeviceKind::Videoinput => MediaDeviceKind::VideoInput,
+            _ => return None,
+        };
+        Some(Self {
+            device_id: info.device_id(),
+            group_id: info.group_id(),
+            kind,
+            label: info.label(),
+        })
+    }
+}
+
+async fn enumerate_devices() -> Result<Vec<MediaDevice>, MediaError> {
+    let devices = JsFuture::from(media_devices()?.enumerate_devices()?).await?;
+    Ok(js_sys::Array::from(&devices)
+        .iter()
+        .filter_map(|info| MediaDevice::from_info(info.unchecked_into()))
+        .collect())
+}
+
+/// List the available media devices.
+///
+/// Enumerates cameras, microphones, and speakers using the
+/// [Media Devices API](https://developer.mozilla.org/en-US/docs/Web/API/MediaDevices), and
+/// enumerates them again when devices get connected or disconnected.
+///
+/// Browsers only report the labels of devices, and sometimes only a single device per kind, once
+/// the permission to access media devices was granted, e.g. by using [`use_user_media`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let devices = use_media_devices();
+///
+///   match &*devices {
+///     UseAsyncState::Ready(Ok(devices)) => html!(
+///       <select>
+///         { for devices
+///             .iter()
+///             .filter(|device| device.kind == MediaDeviceKind::VideoInput)
+///             .map(|device| html!(<option value={device.device_id.clone()}>{ &device.label }</option>))
+///         }
+///       </select>
+///     ),
+///     UseAsyncState::Ready(Err(err)) => html!(err.to_string()),
+///     _ => html!("Loading…"),
+///   }
+/// }
+/// ```
+#[hook]
+pub fn use_media_devices() -> UseAsyncHandleDeps<Vec<MediaDevice>, MediaError> {
+    let generation = use_state_eq(|| 0usize);
+
+    {
+        let generation = generation.clone();
+        use_effect_with((), move |()| {
+            let mut counter = 0;
+            let listener = media_devices().ok().map(|devices| {
+                EventListener::new(&devices, "devicechange", move |_| {
+                    counter += 1;
+                    generation.set(counter);
+                })
+            });
+            move || drop(listener)
+        });
+    }
+
+    use_async_with_deps(|_| enumerate_devices(), *generation)
+}
+
+/// Requesting a track of a media stream.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum TrackConstraint {
+    /// Don't request a track.
+    #[default]
+    Disabled,
+    /// Request a track from any device.
+    Enabled,
+    /// Request a track from a specific device, by its ID.
+    Device(String),
+}
+
+impl TrackConstraint {
+    fn to_js(&self) -> JsValue {
+        match self {
+            Self::Disabled => false.into(),
+            Self::Enabled => true.into(),
+            Self::Device(id) => {
+                let exact = js_sys::Object::new();
+                let _ = js_sys::Reflect::set(&exact, &"exact".into(), &id.into());
+                let constraints = js_sys::Object::new();
+                let _ = js_sys::Reflect::set(&constraints, &"deviceId".into(), &exact);
+                constraints.into()
+            }
+        }
+    }
+}
+
+/// Constraints for [`use_user_media`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UserMediaConstraints {
+    /// The requested audio track, from a microphone.
+    pub audio: TrackConstraint,
+    /// The requested video track, from a camera.
+    pub video: TrackConstraint,
+}
+
+impl UserMediaConstraints {
+    /// Request audio from any microphone.
+    pub fn audio() -> Self {
+        Self {
+            audio: TrackConstraint::Enabled,
+            video: TrackConstraint::Disabled,
+        }
+    }
+
+    /// Request video from any camera.
+    pub fn video() -> Self {
+        Self {
+            audio: TrackConstraint::Disabled,
+            video: TrackConstraint::Enabled,
+        }
+    }
+
+    /// Request audio and video from any microphone and camera.
+    pub fn audio_video() -> Self {
+        Self {
+            audio: TrackConstraint::Enabled,
+            video: TrackConstraint::Enabled,
+        }
+    }
+}
+
+fn stop_tracks(stream: &web_sys::MediaStream) {
+    for track in stream.get_tracks() {
+        track.unchecked_into::<web_sys::MediaStreamTrack>().stop();
+    }
+}
+
+async fn get_user_media(
+    constraints: UserMediaConstraints,
+) -> Result<web_sys::MediaStream, MediaError> {
+    let request = web_sys::MediaStreamConstraints::new();
+    request.set_audio(&constraints.audio.to_js());
+    request.set_video(&constraints.video.to_js());
+
+    let stream =
+        JsFuture::from(media_devices()?.get_user_media_with_constraints(&request)?).await?;
+    Ok(stream.unchecked_into())
+}
+
+/// Await a request, stopping its result if it arrived after the request was no longer active.
+async fn acquire<S, E>(
+    request: impl Future<Output = Result<S, E>>,
+    active: Rc<Cell<bool>>,
+    stop: impl FnOnce(&S),
+) -> Result<S, E> {
+    let result = request.await?;
+    if !active.get() {
+        // acquired too late, nobody will stop it otherwise
+        stop(&result);
+    }
+    Ok(result)
+}
+
+/// Acquire a media stream from a camera and/or microphone.
+///
+/// Requests a stream matching the constraints using
+/// [`getUserMedia`](https://developer.mozilla.org/en-US/docs/Web/API/MediaDevices/getUserMedia),
+/// which prompts the user for permission, and requests a new stream when the constraints change.
+/// All tracks of a stream are stopped once it gets replaced, or the component is unmounted.
+///
+/// Denying the permission is reported as [`MediaError::PermissionDenied`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let stream = use_user_media(UserMediaConstraints::video());
+///
+///   match &*stream {
+///     UseAsyncState::Ready(Ok(stream)) => html!(format!("Streaming {} track(s)", stream.get_tracks().length())),
+///     UseAsyncState::Ready(Err(MediaError::PermissionDenied(_))) => html!("Please allow access to the camera"),
+///     UseAsyncState::Ready(Err(err)) => html!(err.to_string()),
+///     _ => html!("Starting camera…"),
+///   }
+/// }
+/// ```
+#[hook]
+pub fn use_user_media(
+    constraints: UserMediaConstraints,
+) -> UseAsyncHandleDeps<web_sys::MediaStream, MediaError> {
+    // the flag of the current request, cleared when the constraints change, or the component is
+    // unmounted
+    let active = use_mut_ref(|| Rc::new(Cell::new(true)));
+    {
+        let active = active.clone();
+        use_effect_with(constraints.clone(), move |_| {
+            let current = Rc::new(Cell::new(true));
+            *active.borrow_mut() = current.clone();
+            move || current.set(false)
+        });
+    }
+
+    // runs after the effect above, using the flag of the new constraints
+    let stream = use_async_with_cloned_deps(
+        move |constraints| {
+            let active = active.borrow().clone();
+            acquire(get_user_media(constraints), active, stop_tracks)
+        },
+        constraints,
+    );
+
+    use_effect_with(stream.data().cloned(), |stream| {
+        let stream = stream.clone();
+        move || {
+            if let Some(stream) = stream {
+                stop_tracks(&stream);
+            }
+        }
+    });
+
+    stream
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{
+        cell::RefCell,
+        pin::pin,
+        sync::Arc,
+        task::{Context, Poll, Wake, Waker},
+    };
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    /// A request, which is pending until a result is provided.
+    #[derive(Clone, Default)]
+    struct Request(Rc<RefCell<Option<u32>>>);
+
+    impl Future for Request {
+        type Output = Result<u32, ()>;
+
+        fn poll(self: std::pin::Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Self::Output> {
+            match self.0.borrow_mut().take() {
+                Some(result) => Poll::Ready(Ok(result)),
+                None => Poll::Pending,
+            }
+        }
+    }
+
+    #[test]
+    fn test_change_while_pending() {
+        let stopped = Rc::new(RefCell::new(vec![]));
+        let stop = |stopped: &Rc<RefCell<Vec<u32>>>| {
+            let stopped = stopped.clone();
+            move |stream: &u32| stopped.borrow_mut().push(*stream)
+        };
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+
+        let first = Request::default();
+        let first_active = Rc::new(Cell::new(true));
+        let mut first_result = pin!(acquire(first.clone(), first_active.clone(), stop(&stopped)));
+        assert!(first_result.as_mut().poll(&mut cx).is_pending());
+
+        // the constraints change, while the first request is still pending
+        first_active.set(false);
+        let second = Request::default();
+        let mut second_result = pin!(acquire(
+            second.clone(),
+            Rc::new(Cell::new(true)),
+            stop(&stopped)
+        ));
+
+        *second.0.borrow_mut() = Some(2);
+        assert_eq!(second_result.as_mut().poll(&mut cx), Poll::Ready(Ok(2)));
+        *first.0.borrow_mut() = Some(1);
+        assert_eq!(first_result.as_mut().poll(&mut cx), Poll::Ready(Ok(1)));
+
+        assert_eq!(*stopped.borrow(), vec![1]);
+    }
+}
@@ -0,0 +1,117 @@
+//! Hooks for merging callbacks
+
+use yew::prelude::*;
+
+/// A value which might provide a callback.
+///
+/// This is implemented for [`Callback`] and `Option<Callback>`, allowing to mix required and
+/// optional callbacks (like the ones coming from properties) when merging.
+pub trait MaybeCallback<IN> {
+    /// Convert into the callback, if there is one.
+    fn into_callback(self) -> Option<Callback<IN>>;
+}
+
+impl<IN> MaybeCallback<IN> for Callback<IN> {
+    fn into_callback(self) -> Option<Callback<IN>> {
+        Some(self)
+    }
+}
+
+impl<IN> MaybeCallback<IN> for Option<Callback<IN>> {
+    fn into_callback(self) -> Option<Callback<IN>> {
+        self
+    }
+}
+
+/// A set of callbacks which can be merged by [`use_merge_callbacks`].
+///
+/// This is implemented for [`Vec`]s, arrays, and tuples (up to 6 elements) of [`MaybeCallback`].
+pub trait IntoCallbacks<IN> {
+    /// Convert into the callbacks, dropping missing ones.
+    fn into_callbacks(self) -> Vec<Callback<IN>>;
+}
+
+impl<IN, C> IntoCallbacks<IN> for Vec<C>
+where
+    C: MaybeCallback<IN>,
+{
+    fn into_callbacks(self) -> Vec<Callback<IN>> {
+        self.into_iter().filter_map(C::into_callback).collect()
+    }
+}
+
+impl<IN, C, const N: usize> IntoCallbacks<IN> for [C; N]
+where
+    C: MaybeCallback<IN>,
+{
+    fn into_callbacks(self) -> Vec<Callback<IN>> {
+        self.into_iter().filter_map(C::into_callback).collect()
+    }
+}
+
+macro_rules! tuple_callbacks {
+    ($($n:ident),+) => {
+        impl<IN, $($n),+> IntoCallbacks<IN> for ($($n,)+)
+        where
+            $($n: MaybeCallback<IN>),+
+        {
+            #[allow(non_snake_case)]
+            fn into_callbacks(self) -> Vec<Callback<IN>> {
+                let ($($n,)+) = self;
+                [$($n.into_callback()),+].into_iter().flatten().collect()
+            }
+        }
+    };
+}
+
+tuple_callbacks!(A);
+tuple_callbacks!(A, B);
+tuple_callbacks!(A, B, C);
+tuple_callbacks!(A, B, C, D);
+tuple_callbacks!(A, B, C, D, E);
+tuple_callbacks!(A, B, C, D, E, F);
+
+/// Merge a set of callbacks into a single one.
+///
+/// The returned callback will emit the event to all provided callbacks, in the order they were
+/// provided. Missing (`None`) callbacks will be skipped. A new instance is only returned when one
+/// of the original callbacks changes.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   onchange: Option<Callback<String>>,
+/// }
+///
+/// #[function_component(Example)]
+/// fn example(props: &Props) -> Html {
+///   let value = use_state(String::new);
+///   let internal = use_callback(value.clone(), |new_value, value| value.set(new_value));
+///
+///   // notify the parent, as well as our own state
+///   let onchange = use_merge_callbacks((props.onchange.clone(), internal));
+///
+///   html!()
+/// }
+/// ```
+#[hook]
+pub fn use_merge_callbacks<IN, C>(callbacks: C) -> Callback<IN>
+where
+    IN: Clone + 'static,
+    C: IntoCallbacks<IN>,
+{
+    (*use_memo(callbacks.into_callbacks(), |callbacks| {
+        let callbacks = callbacks.clone();
+        Callback::from(move |input: IN| {
+            for callback in &callbacks {
+                callback.emit(input.clone());
+            }
+        })
+    }))
+    .clone()
+}
@@ -0,0 +1,148 @@
+//! Hooks for managing `<meta>` tags of the page
+
+use yew::prelude::*;
+
+/// The attribute identifying a `<meta>` tag.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MetaKey {
+    /// Identified by `name`, like `description` or `theme-color`.
+    Name(String),
+    /// Identified by `property`, like `og:title`.
+    Property(String),
+    /// Identified by `http-equiv`, like `content-security-policy`.
+    HttpEquiv(String),
+}
+
+impl MetaKey {
+    fn attribute(&self) -> (&'static str, &str) {
+        match self {
+            Self::Name(value) => ("name", value),
+            Self::Property(value) => ("property", value),
+            Self::HttpEquiv(value) => ("http-equiv", value),
+        }
+    }
+}
+
+/// A `<meta>` tag.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MetaTag {
+    /// The attribute identifying the tag.
+    pub key: MetaKey,
+    /// The value of the `content` attribute.
+    pub content: String,
+}
+
+impl MetaTag {
+    /// A tag identified by `name`.
+    pub fn name(name: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            key: MetaKey::Name(name.into()),
+            content: content.into(),
+        }
+    }
+
+    /// A tag identified by `property`, used by Open Graph.
+    pub fn property(property: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            key: MetaKey::Property(property.into()),
+            content: content.into(),
+        }
+    }
+
+    /// A tag identified by `http-equiv`.
+    pub fn http_equiv(http_equiv: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            key: MetaKey::HttpEquiv(http_equiv.into()),
+            content: content.into(),
+        }
+    }
+}
+
+fn find(key: &MetaKey) -> Option<web_sys::Element> {
+    let (name, value) = key.attribute();
+    let tags = gloo_utils::document().get_elements_by_tag_name("meta");
+    (0..tags.length())
+        .filter_map(|i| tags.item(i))
+        .find(|tag| tag.get_attribute(name).as_deref() == Some(value))
+}
+
+/// How to undo applying a tag.
+enum Undo {
+    /// Restore the content of an existing tag.
+    Restore(web_sys::Element, Option<String>),
+    /// Remove an added tag.
+    Remove(web_sys::Element),
+}
+
+fn apply(tag: &MetaTag) -> Option<Undo> {
+    if let Some(existing) = find(&tag.key) {
+        let original = existing.get_attribute("content");
+        let _ = existing.set_attribute("content", &tag.content);
+        return Some(Undo::Restore(existing, original));
+    }
+
+    let document = gloo_utils::document();
+    let element = document.create_element("meta").ok()?;
+    let (name, value) = tag.key.attribute();
+    let _ = element.set_attribute(name, value);
+    let _ = element.set_attribute("content", &tag.content);
+    document.head()?.append_child(&element).ok()?;
+    Some(Undo::Remove(element))
+}
+
+/// Manage `<meta>` tags of the page.
+///
+/// Applies the tags to the head of the document: existing tags with the same key (like the same
+/// `name`) get their content replaced, missing tags are added. When the tags change, or the
+/// component is unmounted, the changes are reverted: replaced content is restored, and added
+/// tags are removed.
+///
+/// As changes are reverted in reverse order, a nested component can override the tags of its
+/// parents while being mounted.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   title: String,
+///   summary: String,
+/// }
+///
+/// #[function_component(Article)]
+/// fn article(props: &Props) -> Html {
+///   use_meta([
+///     MetaTag::name("description", &props.summary),
+///     MetaTag::property("og:title", &props.title),
+///     MetaTag::name("theme-color", "#3366cc"),
+///   ]);
+///
+///   html!(<h1>{ &props.title }</h1>)
+/// }
+/// ```
+#[hook]
+pub fn use_meta<I>(tags: I)
+where
+    I: IntoIterator<Item = MetaTag>,
+{
+    let tags = tags.into_iter().collect::<Vec<_>>();
+    use_effect_with(tags, |tags| {
+        let undo = tags.iter().filter_map(apply).collect::<Vec<_>>();
+        move || {
+            for undo in undo.into_iter().rev() {
+                match undo {
+                    Undo::Restore(element, Some(content)) => {
+                        let _ = element.set_attribute("content", &content);
+                    }
+                    Undo::Restore(element, None) => {
+                        let _ = element.remove_attribute("content");
+                    }
+                    Undo::Remove(element) => element.remove(),
+                }
+            }
+        }
+    });
+}
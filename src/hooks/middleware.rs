@@ -0,0 +1,298 @@
+//! Hooks for reducers with middleware
+
+use crate::hooks::use_latest;
+use std::{cell::RefCell, ops::Deref, rc::Rc};
+use yew::prelude::*;
+
+/// A middleware of a reducer, wrapping each dispatch.
+///
+/// The middleware receives the state before the action, and the action. It passes them on to
+/// `next`, which runs the following middleware and the reducer, and returns the resulting state.
+/// This allows acting on the action before, and on the new state after reducing, like for logging
+/// or persisting. A middleware may also change or drop actions.
+///
+/// Closures with the same signature as [`Middleware::reduce`] are middleware too.
+pub trait Middleware<R>
+where
+    R: Reducible,
+{
+    /// Handle an action, passing it on to `next`, and returning the resulting state.
+    fn reduce(
+        &self,
+        state: Rc<R>,
+        action: R::Action,
+        next: &dyn Fn(Rc<R>, R::Action) -> Rc<R>,
+    ) -> Rc<R>;
+}
+
+impl<R, F> Middleware<R> for F
+where
+    R: Reducible,
+    F: Fn(Rc<R>, R::Action, &dyn Fn(Rc<R>, R::Action) -> Rc<R>) -> Rc<R>,
+{
+    fn reduce(
+        &self,
+        state: Rc<R>,
+        action: R::Action,
+        next: &dyn Fn(Rc<R>, R::Action) -> Rc<R>,
+    ) -> Rc<R> {
+        self(state, action, next)
+    }
+}
+
+/// A middleware, logging each action along with the state before and after it.
+#[cfg(feature = "log")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LogMiddleware;
+
+#[cfg(feature = "log")]
+impl<R> Middleware<R> for LogMiddleware
+where
+    R: Reducible + std::fmt::Debug,
+    R::Action: std::fmt::Debug,
+{
+    fn reduce(
+        &self,
+        state: Rc<R>,
+        action: R::Action,
+        next: &dyn Fn(Rc<R>, R::Action) -> Rc<R>,
+    ) -> Rc<R> {
+        log::info!("Action: {action:?}");
+        log::debug!("Before: {state:?}");
+        let state = next(state, action);
+        log::debug!("After: {state:?}");
+        state
+    }
+}
+
+/// Run the middleware, one after the other, and finally the reducer.
+fn run<R>(middleware: &[Rc<dyn Middleware<R>>], state: Rc<R>, action: R::Action) -> Rc<R>
+where
+    R: Reducible,
+{
+    match middleware.split_first() {
+        Some((first, rest)) => {
+            first.reduce(state, action, &|state, action| run(rest, state, action))
+        }
+        None => state.reduce(action),
+    }
+}
+
+/// The middleware of a reducer, in order.
+type MiddlewareChain<R> = Rc<[Rc<dyn Middleware<R>>]>;
+
+struct WithMiddleware<R> {
+    state: Rc<R>,
+}
+
+struct MiddlewareAction<R>
+where
+    R: Reducible,
+{
+    action: R::Action,
+    /// The middleware at the time of dispatching.
+    middleware: MiddlewareChain<R>,
+}
+
+impl<R> Reducible for WithMiddleware<R>
+where
+    R: Reducible + 'static,
+{
+    type Action = MiddlewareAction<R>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let state = run(&action.middleware, self.state.clone(), action.action);
+        match Rc::ptr_eq(&state, &self.state) {
+            true => self,
+            false => Rc::new(Self { state }),
+        }
+    }
+}
+
+impl<R> PartialEq for WithMiddleware<R> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+/// State handle for the [`use_reducer_with_middleware`] hook.
+pub struct UseReducerWithMiddlewareHandle<R>
+where
+    R: Reducible + 'static,
+{
+    state: UseReducerHandle<WithMiddleware<R>>,
+    middleware: Rc<RefCell<MiddlewareChain<R>>>,
+}
+
+impl<R> UseReducerWithMiddlewareHandle<R>
+where
+    R: Reducible + 'static,
+{
+    /// Dispatch an action, running it through the latest middleware.
+    pub fn dispatch(&self, action: R::Action) {
+        let middleware = self.middleware.borrow().clone();
+        self.state.dispatch(MiddlewareAction { action, middleware });
+    }
+
+    /// Create a callback, dispatching actions.
+    pub fn dispatcher(&self) -> Callback<R::Action> {
+        let handle = self.clone();
+        Callback::from(move |action| handle.dispatch(action))
+    }
+}
+
+impl<R> Clone for UseReducerWithMiddlewareHandle<R>
+where
+    R: Reducible + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            middleware: self.middleware.clone(),
+        }
+    }
+}
+
+impl<R> PartialEq for UseReducerWithMiddlewareHandle<R>
+where
+    R: Reducible + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && Rc::ptr_eq(&self.middleware, &other.middleware)
+    }
+}
+
+impl<R> Deref for UseReducerWithMiddlewareHandle<R>
+where
+    R: Reducible + 'static,
+{
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state.state
+    }
+}
+
+/// A reducer, running each dispatch through middleware.
+///
+/// This works like [`use_reducer`], but each action passes through the `middleware` first, in
+/// order, before reaching the reducer. This keeps cross-cutting concerns, like logging,
+/// persistence, or analytics, out of the reducers. The latest middleware is used for each
+/// dispatch.
+///
+/// With the `log` feature, `LogMiddleware` logs each action.
+///
+/// ## Example
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(Debug, Default, PartialEq)]
+/// struct Cart {
+///   items: Vec<String>,
+/// }
+///
+/// #[derive(Debug)]
+/// enum CartAction {
+///   Add(String),
+///   Clear,
+/// }
+///
+/// impl Reducible for Cart {
+///   type Action = CartAction;
+///
+///   fn reduce(self: Rc<Self>, action: CartAction) -> Rc<Self> {
+///     match action {
+///       CartAction::Add(item) => {
+///         let mut items = self.items.clone();
+///         items.push(item);
+///         Rc::new(Self { items })
+///       }
+///       CartAction::Clear => Rc::new(Self::default()),
+///     }
+///   }
+/// }
+///
+/// #[function_component(Shop)]
+/// fn shop() -> Html {
+///   let cart = use_reducer_with_middleware(
+///     Cart::default,
+///     vec![Rc::new(
+///       |state: Rc<Cart>, action, next: &dyn Fn(Rc<Cart>, CartAction) -> Rc<Cart>| {
+///         let state = next(state, action);
+///         // report the size of the cart
+///         let _size = state.items.len();
+///         state
+///       },
+///     )],
+///   );
+///
+///   let onadd = use_callback(cart.clone(), |_, cart| cart.dispatch(CartAction::Add("Apple".into())));
+///
+///   html!(<button onclick={onadd}>{ format!("Add ({})", cart.items.len()) }</button>)
+/// }
+/// ```
+#[hook]
+pub fn use_reducer_with_middleware<R, F>(
+    init: F,
+    middleware: Vec<Rc<dyn Middleware<R>>>,
+) -> UseReducerWithMiddlewareHandle<R>
+where
+    R: Reducible + 'static,
+    F: FnOnce() -> R,
+{
+    let state = use_reducer(move || WithMiddleware {
+        state: Rc::new(init()),
+    });
+    let middleware = use_latest(Rc::from(middleware));
+
+    UseReducerWithMiddlewareHandle { state, middleware }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Count(u32);
+
+    impl Reducible for Count {
+        type Action = u32;
+
+        fn reduce(self: Rc<Self>, action: u32) -> Rc<Self> {
+            Rc::new(Self(self.0 + action))
+        }
+    }
+
+    #[test]
+    fn test_order() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let logger = |name: &'static str| -> Rc<dyn Middleware<Count>> {
+            let log = log.clone();
+            Rc::new(
+                move |state: Rc<Count>, action, next: &dyn Fn(Rc<Count>, u32) -> Rc<Count>| {
+                    log.borrow_mut().push(format!("{name} before {}", state.0));
+                    let state = next(state, action);
+                    log.borrow_mut().push(format!("{name} after {}", state.0));
+                    state
+                },
+            )
+        };
+        // doubles each action
+        let double: Rc<dyn Middleware<Count>> = Rc::new(
+            |state, action: u32, next: &dyn Fn(Rc<Count>, u32) -> Rc<Count>| {
+                next(state, action * 2)
+            },
+        );
+
+        let middleware: Rc<[_]> = Rc::from(vec![logger("a"), double, logger("b")]);
+        let state = run(&middleware, Rc::new(Count(0)), 1);
+
+        assert_eq!(state.0, 2);
+        assert_eq!(
+            *log.borrow(),
+            vec!["a before 0", "b before 0", "b after 2", "a after 2"]
+        );
+    }
+}
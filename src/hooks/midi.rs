@@ -0,0 +1,317 @@
+//! Hooks for the Web MIDI API
+
+use crate::hooks::{use_latest, UseAsyncState};
+use gloo_events::EventListener;
+use std::{
+    cell::RefCell,
+    fmt::{Display, Formatter},
+    ops::Deref,
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use yew::prelude::*;
+
+/// An error accessing MIDI devices.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MidiError {
+    /// The Web MIDI API is not supported by the browser.
+    Unsupported,
+    /// The user, or the browser, denied access to MIDI devices.
+    PermissionDenied(String),
+    /// There is no output port with the requested ID.
+    UnknownPort(String),
+    /// Accessing MIDI devices failed for another reason.
+    Failed(String),
+}
+
+impl Display for MidiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str("Web MIDI API is not supported"),
+            Self::PermissionDenied(msg) => write!(f, "Permission denied: {msg}"),
+            Self::UnknownPort(id) => write!(f, "Unknown MIDI port: {id}"),
+            Self::Failed(msg) => write!(f, "Failed to access MIDI devices: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for MidiError {}
+
+impl From<JsValue> for MidiError {
+    fn from(err: JsValue) -> Self {
+        match err.dyn_ref::<web_sys::DomException>() {
+            Some(err) if matches!(err.name().as_str(), "NotAllowedError" | "SecurityError") => {
+                Self::PermissionDenied(err.message())
+            }
+            Some(err) => Self::Failed(err.message()),
+            None => Self::Failed(format!("{err:?}")),
+        }
+    }
+}
+
+/// A MIDI port.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MidiPortInfo {
+    /// The unique ID of the port, for sending messages to it.
+    pub id: String,
+    /// The name of the port, if provided by the system.
+    pub name: Option<String>,
+    /// The manufacturer of the device, if provided by the system.
+    pub manufacturer: Option<String>,
+    /// The device of the port is connected.
+    pub connected: bool,
+}
+
+impl From<&web_sys::MidiPort> for MidiPortInfo {
+    fn from(port: &web_sys::MidiPort) -> Self {
+        Self {
+            id: port.id(),
+            name: port.name(),
+            manufacturer: port.manufacturer(),
+            connected: port.state() == web_sys::MidiPortDeviceState::Connected,
+        }
+    }
+}
+
+/// The available MIDI ports.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MidiPorts {
+    /// The ports receiving messages from devices.
+    pub inputs: Vec<MidiPortInfo>,
+    /// The ports sending messages to devices.
+    pub outputs: Vec<MidiPortInfo>,
+}
+
+/// A message received from a MIDI input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MidiMessage {
+    /// The ID of the input port.
+    pub input: String,
+    /// The bytes of the message, starting with the status byte.
+    pub data: Vec<u8>,
+    /// The time the message was received, in milliseconds.
+    pub timestamp: f64,
+}
+
+/// Options for [`use_web_midi`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UseWebMidiOptions {
+    /// Request access to system exclusive messages.
+    pub sysex: bool,
+    /// Called with messages received from any input.
+    pub onmessage: Option<Callback<MidiMessage>>,
+}
+
+/// The ports of a MIDI port map.
+fn ports<T: JsCast>(map: &JsValue) -> Vec<T> {
+    let values = js_sys::Reflect::get(map, &"values".into())
+        .ok()
+        .and_then(|values| values.dyn_into::<js_sys::Function>().ok())
+        .and_then(|values| values.call0(map).ok());
+    match values {
+        Some(values) => js_sys::Array::from(&values)
+            .iter()
+            .map(JsCast::unchecked_into)
+            .collect(),
+        None => vec![],
+    }
+}
+
+fn inputs(access: &web_sys::MidiAccess) -> Vec<web_sys::MidiInput> {
+    ports(&access.inputs())
+}
+
+fn outputs(access: &web_sys::MidiAccess) -> Vec<web_sys::MidiOutput> {
+    ports(&access.outputs())
+}
+
+/// State handle for the [`use_web_midi`] hook.
+#[derive(Clone)]
+pub struct UseWebMidiHandle {
+    state: UseStateHandle<UseAsyncState<MidiPorts, MidiError>>,
+    access: Rc<RefCell<Option<web_sys::MidiAccess>>>,
+}
+
+impl UseWebMidiHandle {
+    /// Send a message to an output port.
+    pub fn send(&self, output: &str, data: &[u8]) -> Result<(), MidiError> {
+        let access = self.access.borrow();
+        let access = access.as_ref().ok_or(MidiError::Unsupported)?;
+        let port = outputs(access)
+            .into_iter()
+            .find(|port| port.id() == output)
+            .ok_or_else(|| MidiError::UnknownPort(output.to_string()))?;
+        port.send(&js_sys::Uint8Array::from(data))?;
+        Ok(())
+    }
+}
+
+impl PartialEq for UseWebMidiHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && Rc::ptr_eq(&self.access, &other.access)
+    }
+}
+
+impl Deref for UseWebMidiHandle {
+    type Target = UseAsyncState<MidiPorts, MidiError>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// Listen to messages of all inputs, and report the current ports.
+fn connect(
+    access: &web_sys::MidiAccess,
+    state: &UseStateHandle<UseAsyncState<MidiPorts, MidiError>>,
+    onmessage: &Rc<RefCell<Option<Callback<MidiMessage>>>>,
+) -> Vec<EventListener> {
+    let inputs = inputs(access);
+
+    state.set(UseAsyncState::Ready(Ok(MidiPorts {
+        inputs: inputs
+            .iter()
+            .map(|port| MidiPortInfo::from(&**port))
+            .collect(),
+        outputs: outputs(access)
+            .iter()
+            .map(|port| MidiPortInfo::from(&**port))
+            .collect(),
+    })));
+
+    inputs
+        .into_iter()
+        .map(|input| {
+            let onmessage = onmessage.clone();
+            let id = input.id();
+            EventListener::new(&input, "midimessage", move |event| {
+                let event = event.unchecked_ref::<web_sys::MidiMessageEvent>();
+                let onmessage = onmessage.borrow().clone();
+                if let Some(onmessage) = onmessage {
+                    onmessage.emit(MidiMessage {
+                        input: id.clone(),
+                        data: event.data().unwrap_or_default(),
+                        timestamp: event.time_stamp(),
+                    });
+                }
+            })
+        })
+        .collect()
+}
+
+/// Access MIDI devices.
+///
+/// Requests access to MIDI devices using the
+/// [Web MIDI API](https://developer.mozilla.org/en-US/docs/Web/API/Web_MIDI_API) when mounted,
+/// which might prompt the user for permission. Once granted, the state contains the available
+/// input and output ports, which is updated when devices get connected or disconnected.
+///
+/// Messages received from any input are passed to the latest `onmessage` callback. Messages can be
+/// sent to outputs using the returned handle.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Keyboard)]
+/// fn keyboard() -> Html {
+///   let last_note = use_state_eq(|| None::<u8>);
+///   let onmessage = use_callback(last_note.clone(), |message: MidiMessage, last_note| {
+///     // note on
+///     if let [0x90..=0x9F, note, velocity] = message.data[..] {
+///       if velocity > 0 {
+///         last_note.set(Some(note));
+///       }
+///     }
+///   });
+///   let midi = use_web_midi(UseWebMidiOptions {
+///     onmessage: Some(onmessage),
+///     ..Default::default()
+///   });
+///
+///   match &*midi {
+///     UseAsyncState::Ready(Ok(ports)) => html!(
+///       <>
+///         <ul>{ for ports.inputs.iter().map(|input| html!(<li>{ input.name.clone() }</li>)) }</ul>
+///         <p>{ format!("Last note: {:?}", *last_note) }</p>
+///       </>
+///     ),
+///     UseAsyncState::Ready(Err(err)) => html!(err.to_string()),
+///     _ => html!("Requesting MIDI access…"),
+///   }
+/// }
+/// ```
+#[hook]
+pub fn use_web_midi(options: UseWebMidiOptions) -> UseWebMidiHandle {
+    let state = use_state_eq(UseAsyncState::<MidiPorts, MidiError>::default);
+    let access = use_mut_ref(|| None::<web_sys::MidiAccess>);
+    let onmessage = use_latest(options.onmessage);
+
+    {
+        let state = state.clone();
+        let access = access.clone();
+        use_effect_with(options.sysex, move |sysex| {
+            // dropped when the effect is cleaned up, which stops the async task from connecting
+            let listeners = Rc::new(RefCell::new(Some(Vec::<EventListener>::new())));
+
+            let request = match js_sys::Reflect::has(
+                &gloo_utils::window().navigator(),
+                &"requestMIDIAccess".into(),
+            ) {
+                Ok(true) => {
+                    let request = web_sys::MidiOptions::new();
+                    request.set_sysex(*sysex);
+                    gloo_utils::window()
+                        .navigator()
+                        .request_midi_access_with_options(&request)
+                        .map_err(MidiError::from)
+                }
+                _ => Err(MidiError::Unsupported),
+            };
+
+            match request {
+                Ok(promise) => {
+                    state.set(UseAsyncState::Processing);
+                    let listeners = listeners.clone();
+                    let access = access.clone();
+                    spawn_local(async move {
+                        let result = JsFuture::from(promise).await;
+                        if listeners.borrow().is_none() {
+                            return;
+                        }
+                        let granted = match result {
+                            Ok(granted) => granted.unchecked_into::<web_sys::MidiAccess>(),
+                            Err(err) => {
+                                state.set(UseAsyncState::Ready(Err(err.into())));
+                                return;
+                            }
+                        };
+
+                        let messages = Rc::new(RefCell::new(connect(&granted, &state, &onmessage)));
+                        let onstatechange = {
+                            let granted = granted.clone();
+                            EventListener::new(&granted.clone(), "statechange", move |_| {
+                                *messages.borrow_mut() = connect(&granted, &state, &onmessage);
+                            })
+                        };
+                        if let Some(listeners) = &mut *listeners.borrow_mut() {
+                            listeners.push(onstatechange);
+                        }
+                        *access.borrow_mut() = Some(granted);
+                    });
+                }
+                Err(err) => state.set(UseAsyncState::Ready(Err(err))),
+            }
+
+            move || {
+                listeners.borrow_mut().take();
+                access.borrow_mut().take();
+            }
+        });
+    }
+
+    UseWebMidiHandle { state, access }
+}
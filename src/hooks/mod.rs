@@ -1,20 +1,241 @@
 //! Additional hooks for the Yew hook system
 
+pub mod animation;
 #[cfg(feature = "async")]
 pub mod r#async;
+pub mod atom;
+#[cfg(all(feature = "async", feature = "timers"))]
+pub mod barcode;
+#[cfg(feature = "async")]
+pub mod battery;
 #[cfg(feature = "breakpoint")]
 pub mod breakpoint;
+pub mod caret;
+pub mod click_outside;
+#[cfg(all(feature = "async", feature = "timers"))]
+pub mod clipboard;
+pub mod collection;
+pub mod content_editable;
+pub mod controllable;
+pub mod counter;
+#[cfg(feature = "timers")]
+pub mod debounce;
+pub mod derived;
+#[cfg(feature = "async")]
+pub mod device;
+pub mod disclosure;
+#[cfg(feature = "timers")]
+pub mod double_click;
+pub mod drag;
+pub mod drop_zone;
+pub mod element_scroll;
+pub mod event;
+pub mod event_bus;
+pub mod event_listener;
+#[cfg(feature = "async")]
+pub mod eye_dropper;
+pub mod favicon;
+#[cfg(feature = "async")]
+pub mod field;
+#[cfg(feature = "async")]
+pub mod file_picker;
+#[cfg(feature = "async")]
+pub mod file_reader;
+#[cfg(feature = "timers")]
+pub mod filter;
+pub mod focus;
+#[cfg(feature = "async")]
+pub mod form;
+pub mod geolocation;
+pub mod hotkeys;
+#[cfg(feature = "timers")]
+pub mod hover;
+#[cfg(all(feature = "async", feature = "timers"))]
+pub mod idle;
+#[cfg(feature = "async")]
+pub mod infinite_scroll;
+pub mod input_value;
+pub mod intersection;
+pub mod key_press;
+pub mod latest;
+#[cfg(feature = "timers")]
+pub mod long_press;
 pub mod map;
+#[cfg(feature = "async")]
+pub mod media;
+pub mod merge;
+pub mod meta;
+pub mod middleware;
+#[cfg(feature = "async")]
+pub mod midi;
+#[cfg(feature = "async")]
+pub mod notification;
 pub mod open;
 #[cfg(feature = "page_state")]
 pub mod page_state;
+pub mod pagination;
+pub mod paste;
+#[cfg(feature = "async")]
+pub mod payment;
+pub mod performance;
+#[cfg(feature = "async")]
+pub mod permission;
+#[cfg(feature = "storage")]
+pub mod persistent;
+pub mod raf;
 pub mod reform;
+pub mod resize_observer;
+pub mod resource;
+#[cfg(feature = "async")]
+pub mod screen_orientation;
+pub mod scroll_direction;
+pub mod scroll_lock;
+pub mod selection;
+#[cfg(feature = "async")]
+pub mod service_worker;
+pub mod state_history;
+pub mod state_machine;
+pub mod sticky;
+#[cfg(feature = "timers")]
+pub mod stopwatch;
+pub mod swipe;
+pub mod table_sort;
+#[cfg(feature = "timers")]
+pub mod throttle;
+#[cfg(feature = "timers")]
+pub mod timers;
+#[cfg(feature = "timers")]
+pub mod toast;
+pub mod toggle;
+pub mod tree;
+pub mod undo_redo;
+pub mod vibrate;
+pub mod virtual_list;
+#[cfg(feature = "async")]
+pub mod visible_once;
+pub mod web_animation;
+#[cfg(feature = "async")]
+pub mod webauthn;
+pub mod wizard;
+#[cfg(feature = "worker")]
+pub mod worker;
 
+pub use animation::*;
+pub use atom::*;
+#[cfg(all(feature = "async", feature = "timers"))]
+pub use barcode::*;
+#[cfg(feature = "async")]
+pub use battery::*;
+#[cfg(feature = "breakpoint")]
 pub use breakpoint::*;
+pub use caret::*;
+pub use click_outside::*;
+#[cfg(all(feature = "async", feature = "timers"))]
+pub use clipboard::*;
+pub use collection::*;
+pub use content_editable::*;
+pub use controllable::*;
+pub use counter::*;
+#[cfg(feature = "timers")]
+pub use debounce::*;
+pub use derived::*;
+#[cfg(feature = "async")]
+pub use device::*;
+pub use disclosure::*;
+#[cfg(feature = "timers")]
+pub use double_click::*;
+pub use drag::*;
+pub use drop_zone::*;
+pub use element_scroll::*;
+pub use event::*;
+pub use event_bus::*;
+pub use event_listener::*;
+#[cfg(feature = "async")]
+pub use eye_dropper::*;
+pub use favicon::*;
+#[cfg(feature = "async")]
+pub use field::*;
+#[cfg(feature = "async")]
+pub use file_picker::*;
+#[cfg(feature = "async")]
+pub use file_reader::*;
+#[cfg(feature = "timers")]
+pub use filter::*;
+pub use focus::*;
+#[cfg(feature = "async")]
+pub use form::*;
+pub use geolocation::*;
+pub use hotkeys::*;
+#[cfg(feature = "timers")]
+pub use hover::*;
+#[cfg(all(feature = "async", feature = "timers"))]
+pub use idle::*;
+#[cfg(feature = "async")]
+pub use infinite_scroll::*;
+pub use input_value::*;
+pub use intersection::*;
+pub use key_press::*;
+pub use latest::*;
+#[cfg(feature = "timers")]
+pub use long_press::*;
 pub use map::*;
+#[cfg(feature = "async")]
+pub use media::*;
+pub use merge::*;
+pub use meta::*;
+pub use middleware::*;
+#[cfg(feature = "async")]
+pub use midi::*;
+#[cfg(feature = "async")]
+pub use notification::*;
 pub use open::*;
 #[cfg(feature = "page_state")]
 pub use page_state::*;
+pub use pagination::*;
+pub use paste::*;
+#[cfg(feature = "async")]
+pub use payment::*;
+pub use performance::*;
+#[cfg(feature = "async")]
+pub use permission::*;
+#[cfg(feature = "storage")]
+pub use persistent::*;
 #[cfg(feature = "async")]
 pub use r#async::*;
+pub use raf::*;
 pub use reform::*;
+pub use resize_observer::*;
+pub use resource::*;
+#[cfg(feature = "async")]
+pub use screen_orientation::*;
+pub use scroll_direction::*;
+pub use scroll_lock::*;
+pub use selection::*;
+#[cfg(feature = "async")]
+pub use service_worker::*;
+pub use state_history::*;
+pub use state_machine::*;
+pub use sticky::*;
+#[cfg(feature = "timers")]
+pub use stopwatch::*;
+pub use swipe::*;
+pub use table_sort::*;
+#[cfg(feature = "timers")]
+pub use throttle::*;
+#[cfg(feature = "timers")]
+pub use timers::*;
+#[cfg(feature = "timers")]
+pub use toast::*;
+pub use toggle::*;
+pub use tree::*;
+pub use undo_redo::*;
+pub use vibrate::*;
+pub use virtual_list::*;
+#[cfg(feature = "async")]
+pub use visible_once::*;
+pub use web_animation::*;
+#[cfg(feature = "async")]
+pub use webauthn::*;
+pub use wizard::*;
+#[cfg(feature = "worker")]
+pub use worker::*;
@@ -0,0 +1,189 @@
+//! Hooks for showing notifications
+
+use crate::hooks::{use_latest, UsePermissionState};
+use wasm_bindgen::{closure::Closure, JsCast};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use yew::prelude::*;
+
+/// The content of a notification.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NotificationContent {
+    /// The title.
+    pub title: String,
+    /// The body text, shown below the title.
+    pub body: Option<String>,
+    /// URL of an icon.
+    pub icon: Option<String>,
+    /// A notification replaces an existing one with the same tag.
+    pub tag: Option<String>,
+}
+
+impl NotificationContent {
+    /// Create a notification with a title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the body text.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Set the URL of an icon.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the tag, replacing an existing notification with the same tag.
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    fn to_options(&self) -> web_sys::NotificationOptions {
+        let options = web_sys::NotificationOptions::new();
+        if let Some(body) = &self.body {
+            options.set_body(body);
+        }
+        if let Some(icon) = &self.icon {
+            options.set_icon(icon);
+        }
+        if let Some(tag) = &self.tag {
+            options.set_tag(tag);
+        }
+        options
+    }
+}
+
+fn is_supported() -> bool {
+    js_sys::Reflect::has(&gloo_utils::window(), &"Notification".into()).unwrap_or_default()
+}
+
+fn permission() -> UsePermissionState {
+    if !is_supported() {
+        return UsePermissionState::Unsupported;
+    }
+    match web_sys::Notification::permission() {
+        web_sys::NotificationPermission::Granted => UsePermissionState::Granted,
+        web_sys::NotificationPermission::Denied => UsePermissionState::Denied,
+        _ => UsePermissionState::Prompt,
+    }
+}
+
+/// A notification, which is being shown.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShownNotification {
+    notification: web_sys::Notification,
+}
+
+impl ShownNotification {
+    /// Close the notification.
+    pub fn close(&self) {
+        self.notification.close();
+    }
+}
+
+/// State handle for the [`use_notification`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseNotificationHandle {
+    permission: UseStateHandle<UsePermissionState>,
+    onclick: Callback<NotificationContent>,
+}
+
+impl UseNotificationHandle {
+    /// The permission to show notifications.
+    pub fn permission(&self) -> UsePermissionState {
+        *self.permission
+    }
+
+    /// Ask the user for permission to show notifications.
+    ///
+    /// Browsers only allow this in response to a user interaction, like a click.
+    pub fn request_permission(&self) {
+        let Ok(request) = web_sys::Notification::request_permission() else {
+            return;
+        };
+        let permission = self.permission.clone();
+        spawn_local(async move {
+            let _ = JsFuture::from(request).await;
+            permission.set(self::permission());
+        });
+    }
+
+    /// Show a notification.
+    ///
+    /// Returns `None` if notifications are not supported, or the permission is not granted.
+    pub fn show(&self, content: NotificationContent) -> Option<ShownNotification> {
+        if !permission().is_granted() {
+            return None;
+        }
+
+        let notification =
+            web_sys::Notification::new_with_options(&content.title, &content.to_options()).ok()?;
+
+        // handed over to JavaScript, living as long as the notification
+        let onclick = self.onclick.clone();
+        let onclick = Closure::<dyn Fn()>::new(move || onclick.emit(content.clone()));
+        notification.set_onclick(Some(onclick.into_js_value().unchecked_ref()));
+
+        Some(ShownNotification { notification })
+    }
+}
+
+/// Show notifications.
+///
+/// Returns a handle for requesting the permission, and showing notifications using the
+/// [Notifications API](https://developer.mozilla.org/en-US/docs/Web/API/Notifications_API). When
+/// a notification is clicked, the latest `onclick` callback is called with its content.
+///
+/// Availability of the API is reported through the permission, as
+/// [`UsePermissionState::Unsupported`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let clicked = use_state_eq(|| false);
+///   let onclick = use_callback(clicked.clone(), |_, clicked| clicked.set(true));
+///   let notification = use_notification(Some(onclick));
+///
+///   let onrequest = use_callback(notification.clone(), |_, notification| {
+///     notification.request_permission()
+///   });
+///   let onshow = use_callback(notification.clone(), |_, notification| {
+///     notification.show(NotificationContent::new("Hello").body("From Yew").tag("greeting"));
+///   });
+///
+///   match notification.permission() {
+///     UsePermissionState::Granted => html!(<button onclick={onshow}>{"Notify"}</button>),
+///     UsePermissionState::Prompt => html!(<button onclick={onrequest}>{"Enable notifications"}</button>),
+///     _ => html!("Notifications are not available"),
+///   }
+/// }
+/// ```
+#[hook]
+pub fn use_notification(onclick: Option<Callback<NotificationContent>>) -> UseNotificationHandle {
+    let permission = use_state_eq(permission);
+    let latest = use_latest(onclick);
+
+    let onclick = use_callback((), move |content, ()| {
+        let onclick = latest.borrow().clone();
+        if let Some(onclick) = onclick {
+            onclick.emit(content);
+        }
+    });
+
+    UseNotificationHandle {
+        permission,
+        onclick,
+    }
+}
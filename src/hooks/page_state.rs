@@ -42,7 +42,7 @@ where
     type Target = S;
 
     fn deref(&self) -> &Self::Target {
-        &*self.state
+        &self.state
     }
 }
 
@@ -0,0 +1,291 @@
+//! Hooks for paginating lists and tables
+
+use std::ops::{Deref, Range};
+use yew::prelude::*;
+
+/// A page of a number of items.
+///
+/// Pages are counted from zero. There is always at least one page, even if there are no items.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pagination {
+    page: usize,
+    per_page: usize,
+    total: usize,
+}
+
+impl Pagination {
+    /// Create a new pagination, limiting the page to the available pages.
+    pub fn new(page: usize, per_page: usize, total: usize) -> Self {
+        let per_page = per_page.max(1);
+        let page_count = total.div_ceil(per_page).max(1);
+        Self {
+            page: page.min(page_count - 1),
+            per_page,
+            total,
+        }
+    }
+
+    /// The current page.
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// The number of items per page.
+    pub fn per_page(&self) -> usize {
+        self.per_page
+    }
+
+    /// The total number of items.
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// The number of pages.
+    pub fn page_count(&self) -> usize {
+        self.total.div_ceil(self.per_page).max(1)
+    }
+
+    /// The index of the first item of the current page.
+    pub fn offset(&self) -> usize {
+        self.page * self.per_page
+    }
+
+    /// The range of the items on the current page.
+    pub fn items(&self) -> Range<usize> {
+        self.offset().min(self.total)..(self.offset() + self.per_page).min(self.total)
+    }
+
+    /// Check if the current page is the first page.
+    pub fn is_first(&self) -> bool {
+        self.page == 0
+    }
+
+    /// Check if the current page is the last page.
+    pub fn is_last(&self) -> bool {
+        self.page + 1 >= self.page_count()
+    }
+
+    /// Up to `size` page numbers around the current page, for rendering page links.
+    pub fn window(&self, size: usize) -> Range<usize> {
+        let count = self.page_count();
+        let start = self.page.saturating_sub(size / 2);
+        let end = (start + size).min(count);
+        end.saturating_sub(size)..end
+    }
+
+    /// The page showing the same first item, with a different number of items per page.
+    fn with_per_page(&self, per_page: usize) -> Self {
+        Self::new(self.offset() / per_page.max(1), per_page, self.total)
+    }
+}
+
+/// Options for [`use_pagination_with_options`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UsePaginationOptions {
+    /// The initial number of items per page.
+    pub per_page: usize,
+    /// The number of page numbers in the [`UsePaginationHandle::window`].
+    pub window: usize,
+}
+
+impl Default for UsePaginationOptions {
+    fn default() -> Self {
+        Self {
+            per_page: 10,
+            window: 5,
+        }
+    }
+}
+
+/// State handle for the [`use_pagination`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UsePaginationHandle {
+    pagination: Pagination,
+    window: usize,
+    /// The stored page and number of items per page, which might be out of range.
+    stored: (usize, usize),
+    /// Stores a new page and number of items per page.
+    store: Callback<(usize, usize)>,
+}
+
+impl UsePaginationHandle {
+    fn go(&self, pagination: Pagination) {
+        let value = (pagination.page, pagination.per_page);
+        if value != self.stored {
+            self.store.emit(value);
+        }
+    }
+
+    /// Go to a page, limited to the available pages.
+    pub fn set_page(&self, page: usize) {
+        self.go(Pagination::new(page, self.per_page, self.total));
+    }
+
+    /// Change the number of items per page, staying on the page of the current first item.
+    pub fn set_per_page(&self, per_page: usize) {
+        self.go(self.pagination.with_per_page(per_page));
+    }
+
+    /// Go to the first page.
+    pub fn first(&self) {
+        self.set_page(0);
+    }
+
+    /// Go to the previous page, if there is one.
+    pub fn previous(&self) {
+        self.set_page(self.page.saturating_sub(1));
+    }
+
+    /// Go to the next page, if there is one.
+    pub fn next(&self) {
+        self.set_page(self.page + 1);
+    }
+
+    /// Go to the last page.
+    pub fn last(&self) {
+        self.set_page(self.page_count() - 1);
+    }
+
+    /// The page numbers around the current page, for rendering page links.
+    pub fn window(&self) -> Range<usize> {
+        self.pagination.window(self.window)
+    }
+
+    /// Create a click handler, going to a page.
+    pub fn onclick(&self, page: usize) -> Callback<MouseEvent> {
+        let handle = self.clone();
+        Callback::from(move |_| handle.set_page(page))
+    }
+}
+
+impl Deref for UsePaginationHandle {
+    type Target = Pagination;
+
+    fn deref(&self) -> &Self::Target {
+        &self.pagination
+    }
+}
+
+/// Paginate a number of items, using default options.
+///
+/// See [`use_pagination_with_options`].
+#[hook]
+pub fn use_pagination(total: usize) -> UsePaginationHandle {
+    use_pagination_with_options(total, UsePaginationOptions::default())
+}
+
+/// Paginate a number of items.
+///
+/// The handle dereferences to the current [`Pagination`], providing the offset and range of the
+/// items to show, and the number of pages. It also provides methods for navigating, and a window
+/// of page numbers around the current page, for rendering page links.
+///
+/// When the total number of items shrinks, the current page is limited to the available pages.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   items: Vec<String>,
+/// }
+///
+/// #[function_component(Items)]
+/// fn items(props: &Props) -> Html {
+///   let pagination = use_pagination(props.items.len());
+///   let onprevious = use_callback(pagination.clone(), |_, pagination| pagination.previous());
+///   let onnext = use_callback(pagination.clone(), |_, pagination| pagination.next());
+///
+///   html!(
+///     <>
+///       <ul>
+///         { for props.items[pagination.items()].iter().map(|item| html!(<li>{ item }</li>)) }
+///       </ul>
+///       <nav>
+///         <button onclick={onprevious} disabled={pagination.is_first()}>{"<"}</button>
+///         { for pagination.window().map(|page| html!(
+///           <button
+///             class={classes!((page == pagination.page()).then_some("current"))}
+///             onclick={pagination.onclick(page)}
+///           >
+///             { page + 1 }
+///           </button>
+///         )) }
+///         <button onclick={onnext} disabled={pagination.is_last()}>{">"}</button>
+///       </nav>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_pagination_with_options(
+    total: usize,
+    options: UsePaginationOptions,
+) -> UsePaginationHandle {
+    let state = use_state_eq(|| (0, options.per_page));
+    let store = use_callback(state.setter(), |value, setter| setter.set(value));
+    let (page, per_page) = *state;
+
+    UsePaginationHandle {
+        pagination: Pagination::new(page, per_page, total),
+        window: options.window,
+        stored: (page, per_page),
+        store,
+    }
+}
+
+/// Paginate a number of items, keeping the page in the state of the current page.
+///
+/// This works like [`use_pagination_with_options`], but stores the page and the number of
+/// items per page using [`use_page_state`](crate::hooks::use_page_state), so that they survive
+/// navigating back to the page.
+#[cfg(feature = "page_state")]
+#[hook]
+pub fn use_pagination_with_page_state(
+    total: usize,
+    options: UsePaginationOptions,
+) -> UsePaginationHandle {
+    let state = crate::hooks::use_page_state(|| (0usize, options.per_page));
+    let store = use_callback(state.clone(), |value, state| state.set(value));
+    let (page, per_page) = *state;
+
+    UsePaginationHandle {
+        pagination: Pagination::new(page, per_page, total),
+        window: options.window,
+        stored: (page, per_page),
+        store,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_pagination() {
+        let pagination = Pagination::new(7, 10, 42);
+        assert_eq!(pagination.page(), 4);
+        assert_eq!(pagination.page_count(), 5);
+        assert_eq!(pagination.items(), 40..42);
+        assert!(pagination.is_last());
+
+        let empty = Pagination::new(3, 10, 0);
+        assert_eq!(empty.page(), 0);
+        assert_eq!(empty.page_count(), 1);
+        assert_eq!(empty.items(), 0..0);
+
+        // stay with the first item on the page
+        assert_eq!(Pagination::new(3, 10, 100).with_per_page(25).page(), 1);
+    }
+
+    #[test]
+    fn test_window() {
+        assert_eq!(Pagination::new(0, 10, 100).window(5), 0..5);
+        assert_eq!(Pagination::new(5, 10, 100).window(5), 3..8);
+        assert_eq!(Pagination::new(9, 10, 100).window(5), 5..10);
+        assert_eq!(Pagination::new(1, 10, 30).window(5), 0..3);
+    }
+}
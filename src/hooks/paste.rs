@@ -0,0 +1,129 @@
+//! Hooks for handling pasted content
+
+use crate::hooks::{use_event_listener, ListenerTarget};
+use yew::prelude::*;
+
+/// Content pasted from the clipboard.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PasteData {
+    /// The plain text content, if any.
+    pub text: Option<String>,
+    /// The HTML content, if any.
+    pub html: Option<String>,
+    /// Pasted files, like images.
+    pub files: Vec<web_sys::File>,
+}
+
+impl From<&web_sys::DataTransfer> for PasteData {
+    fn from(transfer: &web_sys::DataTransfer) -> Self {
+        let data = |format: &str| {
+            transfer
+                .get_data(format)
+                .ok()
+                .filter(|data| !data.is_empty())
+        };
+        let files = transfer
+            .files()
+            .map(|files| (0..files.length()).filter_map(|i| files.item(i)).collect())
+            .unwrap_or_default();
+
+        Self {
+            text: data("text/plain"),
+            html: data("text/html"),
+            files,
+        }
+    }
+}
+
+/// Options for [`use_paste_with_options`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UsePasteOptions {
+    /// Prevent the browser from inserting the content.
+    pub prevent_default: bool,
+}
+
+/// Handle content pasted into a target.
+///
+/// This is [`use_paste_with_options`] using the default options, not preventing the browser from
+/// inserting the content.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let last = use_state_eq(String::new);
+///
+///   let onpaste = use_callback(last.clone(), |data: PasteData, last| {
+///     last.set(data.text.unwrap_or_default());
+///   });
+///   use_paste(ListenerTarget::Window, onpaste);
+///
+///   html!(format!("Last pasted: {}", *last))
+/// }
+/// ```
+#[hook]
+pub fn use_paste<T>(target: T, callback: Callback<PasteData>)
+where
+    T: Into<ListenerTarget>,
+{
+    use_paste_with_options(target, UsePasteOptions::default(), callback)
+}
+
+/// Handle content pasted into a target, using options.
+///
+/// Listens for `paste` events of the `target`, like a node or the window, and passes the text,
+/// HTML, and files of the clipboard to the callback.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Upload)]
+/// fn upload() -> Html {
+///   let node = use_node_ref();
+///   let images = use_state_eq(|| 0);
+///
+///   let onpaste = use_callback(images.clone(), |data: PasteData, images| {
+///     let count = data
+///       .files
+///       .iter()
+///       .filter(|file| file.type_().starts_with("image/"))
+///       .count();
+///     images.set(**images + count);
+///   });
+///   use_paste_with_options(
+///     node.clone(),
+///     UsePasteOptions {
+///       prevent_default: true,
+///     },
+///     onpaste,
+///   );
+///
+///   html!(<div ref={node} tabindex="0">{ format!("{} images, paste more", *images) }</div>)
+/// }
+/// ```
+#[hook]
+pub fn use_paste_with_options<T>(target: T, options: UsePasteOptions, callback: Callback<PasteData>)
+where
+    T: Into<ListenerTarget>,
+{
+    let onpaste = use_callback(
+        (options, callback),
+        |event: web_sys::ClipboardEvent, (options, callback)| {
+            if options.prevent_default {
+                event.prevent_default();
+            }
+            if let Some(transfer) = event.clipboard_data() {
+                callback.emit(PasteData::from(&transfer));
+            }
+        },
+    );
+
+    use_event_listener(target, "paste", onpaste);
+}
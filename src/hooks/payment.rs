@@ -0,0 +1,450 @@
+//! Hooks for the Payment Request API
+
+use crate::hooks::UseAsyncState;
+use std::{
+    cell::RefCell,
+    fmt::{Display, Formatter},
+    ops::Deref,
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::PaymentComplete;
+use yew::prelude::*;
+
+/// An error requesting a payment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PaymentError {
+    /// The Payment Request API is not supported by the browser.
+    Unsupported,
+    /// None of the payment methods is supported.
+    MethodNotSupported,
+    /// The user cancelled the payment, or it was aborted.
+    Aborted,
+    /// Another payment request is already being shown.
+    InvalidState,
+    /// Requesting the payment failed for another reason.
+    Failed(String),
+}
+
+impl Display for PaymentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str("Payment Request API is not supported"),
+            Self::MethodNotSupported => f.write_str("No supported payment method"),
+            Self::Aborted => f.write_str("Payment was aborted"),
+            Self::InvalidState => f.write_str("Another payment request is already shown"),
+            Self::Failed(msg) => write!(f, "Payment failed: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for PaymentError {}
+
+impl From<JsValue> for PaymentError {
+    fn from(err: JsValue) -> Self {
+        match err.dyn_ref::<web_sys::DomException>() {
+            Some(err) => match err.name().as_str() {
+                "NotSupportedError" => Self::MethodNotSupported,
+                "AbortError" => Self::Aborted,
+                "InvalidStateError" => Self::InvalidState,
+                _ => Self::Failed(err.message()),
+            },
+            None => Self::Failed(format!("{err:?}")),
+        }
+    }
+}
+
+/// A monetary amount.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentAmount {
+    /// The ISO 4217 currency code, like `EUR`.
+    pub currency: String,
+    /// The decimal value, like `12.50`.
+    pub value: String,
+}
+
+impl PaymentAmount {
+    /// Create an amount of a currency.
+    pub fn new(currency: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            currency: currency.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// A line item of a payment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentItem {
+    /// The label shown to the user.
+    pub label: String,
+    /// The amount of the item.
+    pub amount: PaymentAmount,
+    /// The amount is not final yet.
+    pub pending: bool,
+}
+
+impl PaymentItem {
+    /// Create a final item.
+    pub fn new(label: impl Into<String>, amount: PaymentAmount) -> Self {
+        Self {
+            label: label.into(),
+            amount,
+            pending: false,
+        }
+    }
+}
+
+/// A supported payment method.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaymentMethod {
+    /// The identifier of the method, like `https://google.com/pay`.
+    pub supported_methods: String,
+    /// Data specific to the payment method.
+    pub data: Option<JsValue>,
+}
+
+impl PaymentMethod {
+    /// Create a method, without specific data.
+    pub fn new(supported_methods: impl Into<String>) -> Self {
+        Self {
+            supported_methods: supported_methods.into(),
+            data: None,
+        }
+    }
+
+    /// Set the data specific to the payment method.
+    pub fn data(mut self, data: impl Into<JsValue>) -> Self {
+        self.data = Some(data.into());
+        self
+    }
+}
+
+/// The details of a payment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentDetails {
+    /// An identifier of the payment, generated by the browser if missing.
+    pub id: Option<String>,
+    /// The total amount of the payment.
+    pub total: PaymentItem,
+    /// Line items shown to the user, which are not required to add up to the total.
+    pub display_items: Vec<PaymentItem>,
+}
+
+impl PaymentDetails {
+    /// Create details with a total, and no line items.
+    pub fn new(total: PaymentItem) -> Self {
+        Self {
+            id: None,
+            total,
+            display_items: vec![],
+        }
+    }
+}
+
+/// Information requested from the payer.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PaymentOptions {
+    /// Request the name of the payer.
+    pub request_payer_name: bool,
+    /// Request the email address of the payer.
+    pub request_payer_email: bool,
+    /// Request the phone number of the payer.
+    pub request_payer_phone: bool,
+    /// Request a shipping address.
+    pub request_shipping: bool,
+}
+
+fn object(entries: &[(&str, JsValue)]) -> js_sys::Object {
+    let object = js_sys::Object::new();
+    for (key, value) in entries {
+        let _ = js_sys::Reflect::set(&object, &(*key).into(), value);
+    }
+    object
+}
+
+impl From<&PaymentAmount> for JsValue {
+    fn from(amount: &PaymentAmount) -> Self {
+        object(&[
+            ("currency", amount.currency.as_str().into()),
+            ("value", amount.value.as_str().into()),
+        ])
+        .into()
+    }
+}
+
+impl From<&PaymentItem> for JsValue {
+    fn from(item: &PaymentItem) -> Self {
+        object(&[
+            ("label", item.label.as_str().into()),
+            ("amount", (&item.amount).into()),
+            ("pending", item.pending.into()),
+        ])
+        .into()
+    }
+}
+
+impl From<&PaymentMethod> for JsValue {
+    fn from(method: &PaymentMethod) -> Self {
+        let mut entries = vec![("supportedMethods", method.supported_methods.as_str().into())];
+        if let Some(data) = &method.data {
+            entries.push(("data", data.clone()));
+        }
+        object(&entries).into()
+    }
+}
+
+impl From<&PaymentDetails> for JsValue {
+    fn from(details: &PaymentDetails) -> Self {
+        let mut entries = vec![
+            ("total", (&details.total).into()),
+            (
+                "displayItems",
+                details
+                    .display_items
+                    .iter()
+                    .map(JsValue::from)
+                    .collect::<js_sys::Array>()
+                    .into(),
+            ),
+        ];
+        if let Some(id) = &details.id {
+            entries.push(("id", id.as_str().into()));
+        }
+        object(&entries).into()
+    }
+}
+
+impl From<&PaymentOptions> for JsValue {
+    fn from(options: &PaymentOptions) -> Self {
+        object(&[
+            ("requestPayerName", options.request_payer_name.into()),
+            ("requestPayerEmail", options.request_payer_email.into()),
+            ("requestPayerPhone", options.request_payer_phone.into()),
+            ("requestShipping", options.request_shipping.into()),
+        ])
+        .into()
+    }
+}
+
+fn payment_request_class() -> Option<js_sys::Function> {
+    js_sys::Reflect::get(&gloo_utils::window(), &"PaymentRequest".into())
+        .ok()?
+        .dyn_into()
+        .ok()
+}
+
+fn call(target: &JsValue, name: &str) -> Result<JsValue, PaymentError> {
+    let function: js_sys::Function = js_sys::Reflect::get(target, &name.into())?.dyn_into()?;
+    Ok(function.call0(target)?)
+}
+
+/// The input of a payment request.
+#[derive(Clone, PartialEq)]
+struct Request {
+    methods: Vec<PaymentMethod>,
+    details: PaymentDetails,
+    options: PaymentOptions,
+}
+
+impl Request {
+    fn create(&self) -> Result<JsValue, PaymentError> {
+        let class = payment_request_class().ok_or(PaymentError::Unsupported)?;
+        let methods = self
+            .methods
+            .iter()
+            .map(JsValue::from)
+            .collect::<js_sys::Array>();
+        let args = js_sys::Array::of3(&methods, &(&self.details).into(), &(&self.options).into());
+        Ok(js_sys::Reflect::construct(&class, &args)?)
+    }
+}
+
+/// State handle for the [`use_payment_request`] hook.
+#[derive(Clone)]
+pub struct UsePaymentRequestHandle {
+    state: UseStateHandle<UseAsyncState<web_sys::PaymentResponse, PaymentError>>,
+    request: Rc<Request>,
+    active: Rc<RefCell<Option<JsValue>>>,
+    supported: bool,
+}
+
+impl UsePaymentRequestHandle {
+    /// Check if the browser supports the Payment Request API.
+    pub fn is_supported(&self) -> bool {
+        self.supported
+    }
+
+    /// Show the payment sheet to the user.
+    ///
+    /// Browsers only allow this in response to a user interaction, like a click. Once the user
+    /// accepted, the state contains the response, which must be completed using
+    /// [`Self::complete`].
+    ///
+    /// Does nothing while a payment sheet is already shown.
+    pub fn show(&self) {
+        if self.active.borrow().is_some() {
+            return;
+        }
+
+        let request = self.request.create().and_then(|request| {
+            let promise = call(&request, "show")?.dyn_into::<js_sys::Promise>()?;
+            *self.active.borrow_mut() = Some(request.clone());
+            Ok((request, promise))
+        });
+        let (request, promise) = match request {
+            Ok(request) => request,
+            Err(err) => {
+                self.state.set(UseAsyncState::Ready(Err(err)));
+                return;
+            }
+        };
+
+        self.state.set(UseAsyncState::Processing);
+        let state = self.state.clone();
+        let active = self.active.clone();
+        spawn_local(async move {
+            let result = JsFuture::from(promise).await;
+            {
+                // it might have been aborted, and another request shown in the meantime
+                let mut active = active.borrow_mut();
+                if active.as_ref() == Some(&request) {
+                    active.take();
+                }
+            }
+            state.set(UseAsyncState::Ready(
+                result
+                    .map(JsCast::unchecked_into)
+                    .map_err(PaymentError::from),
+            ));
+        });
+    }
+
+    /// Abort the payment sheet currently shown.
+    pub fn abort(&self) {
+        if let Some(request) = self.active.borrow_mut().take() {
+            // the outcome is reported through the promise of showing it
+            let _ = call(&request, "abort");
+        }
+    }
+
+    /// Complete the payment of the current response, closing the payment sheet.
+    pub async fn complete(&self, result: PaymentComplete) -> Result<(), PaymentError> {
+        let UseAsyncState::Ready(Ok(response)) = &*self.state else {
+            return Err(PaymentError::InvalidState);
+        };
+        JsFuture::from(response.complete_with_result(result)).await?;
+        Ok(())
+    }
+}
+
+impl PartialEq for UsePaymentRequestHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+            && Rc::ptr_eq(&self.request, &other.request)
+            && Rc::ptr_eq(&self.active, &other.active)
+    }
+}
+
+impl Deref for UsePaymentRequestHandle {
+    type Target = UseAsyncState<web_sys::PaymentResponse, PaymentError>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// Request a payment.
+///
+/// See [`use_payment_request_with_options`] for requesting information about the payer.
+#[hook]
+pub fn use_payment_request(
+    methods: Vec<PaymentMethod>,
+    details: PaymentDetails,
+) -> UsePaymentRequestHandle {
+    use_payment_request_with_options(methods, details, PaymentOptions::default())
+}
+
+/// Request a payment, with options.
+///
+/// Returns a handle for showing the payment sheet of the
+/// [Payment Request API](https://developer.mozilla.org/en-US/docs/Web/API/Payment_Request_API),
+/// which dereferences to the state of the payment. Once the user accepted the payment, it must be
+/// processed and completed using [`UsePaymentRequestHandle::complete`]. A payment sheet still
+/// shown is aborted when the component is unmounted.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+/// use web_sys::PaymentComplete;
+///
+/// #[function_component(Checkout)]
+/// fn checkout() -> Html {
+///   let payment = use_payment_request_with_options(
+///     vec![PaymentMethod::new("https://example.com/pay")],
+///     PaymentDetails::new(PaymentItem::new("Total", PaymentAmount::new("EUR", "12.50"))),
+///     PaymentOptions {
+///       request_payer_email: true,
+///       ..Default::default()
+///     },
+///   );
+///   let onclick = use_callback(payment.clone(), |_, payment| payment.show());
+///
+///   {
+///     let payment = payment.clone();
+///     use_effect_with(payment.data().cloned(), move |response| {
+///       if response.is_some() {
+///         wasm_bindgen_futures::spawn_local(async move {
+///           // process the payment with your backend, then
+///           let _ = payment.complete(PaymentComplete::Success).await;
+///         });
+///       }
+///     });
+///   }
+///
+///   html!(
+///     <button {onclick} disabled={!payment.is_supported() || payment.is_processing()}>
+///       {"Pay"}
+///     </button>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_payment_request_with_options(
+    methods: Vec<PaymentMethod>,
+    details: PaymentDetails,
+    options: PaymentOptions,
+) -> UsePaymentRequestHandle {
+    let state = use_state_eq(UseAsyncState::<web_sys::PaymentResponse, PaymentError>::default);
+    let active = use_mut_ref(|| None::<JsValue>);
+    let supported = *use_memo((), |()| payment_request_class().is_some());
+    let request = use_memo(
+        Request {
+            methods,
+            details,
+            options,
+        },
+        Request::clone,
+    );
+
+    {
+        let active = active.clone();
+        use_effect_with((), move |()| {
+            move || {
+                if let Some(request) = active.borrow_mut().take() {
+                    let _ = call(&request, "abort");
+                }
+            }
+        });
+    }
+
+    UsePaymentRequestHandle {
+        state,
+        request,
+        active,
+        supported,
+    }
+}
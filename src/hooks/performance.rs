@@ -0,0 +1,183 @@
+//! Hooks for observing the performance of the page
+
+use std::{ops::Deref, rc::Rc};
+use wasm_bindgen::{closure::Closure, JsCast};
+use yew::prelude::*;
+
+/// A performance entry, reported by a [`web_sys::PerformanceObserver`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PerformanceTask {
+    /// The type of the entry, like `longtask` or `event`.
+    pub entry_type: String,
+    /// The name of the entry, like `self` or the type of the event.
+    pub name: String,
+    /// Start time, in milliseconds since the time origin.
+    pub start_time: f64,
+    /// Duration, in milliseconds.
+    pub duration: f64,
+}
+
+impl From<web_sys::PerformanceEntry> for PerformanceTask {
+    fn from(entry: web_sys::PerformanceEntry) -> Self {
+        Self {
+            entry_type: entry.entry_type(),
+            name: entry.name(),
+            start_time: entry.start_time(),
+            duration: entry.duration(),
+        }
+    }
+}
+
+/// Options for [`use_long_tasks_with_options`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UseLongTasksOptions {
+    /// Also observe slow event handling (`event` entries).
+    pub event_timing: bool,
+    /// Include entries which happened before the observer was created.
+    pub buffered: bool,
+    /// The maximum number of entries to keep, dropping the oldest ones.
+    pub limit: usize,
+}
+
+impl Default for UseLongTasksOptions {
+    fn default() -> Self {
+        Self {
+            event_timing: false,
+            buffered: true,
+            limit: 100,
+        }
+    }
+}
+
+/// The entries recorded by [`use_long_tasks`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LongTasks {
+    entries: Vec<PerformanceTask>,
+}
+
+/// Actions of the [`LongTasks`] reducer.
+pub enum LongTasksAction {
+    /// Record entries, keeping at most the given number of the latest entries.
+    Add(Vec<PerformanceTask>, usize),
+    /// Drop all recorded entries.
+    Clear,
+}
+
+impl Reducible for LongTasks {
+    type Action = LongTasksAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        match action {
+            LongTasksAction::Add(entries, limit) => {
+                let mut next = (*self).clone();
+                next.entries.extend(entries);
+                let overflow = next.entries.len().saturating_sub(limit);
+                next.entries.drain(..overflow);
+                Rc::new(next)
+            }
+            LongTasksAction::Clear => Default::default(),
+        }
+    }
+}
+
+/// State handle for the [`use_long_tasks`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseLongTasksHandle {
+    state: UseReducerHandle<LongTasks>,
+}
+
+impl UseLongTasksHandle {
+    /// Remove all recorded entries.
+    pub fn clear(&self) {
+        self.state.dispatch(LongTasksAction::Clear);
+    }
+}
+
+impl Deref for UseLongTasksHandle {
+    type Target = [PerformanceTask];
+
+    fn deref(&self) -> &Self::Target {
+        &self.state.entries
+    }
+}
+
+/// Observe long tasks, blocking the main thread.
+///
+/// This is [`use_long_tasks_with_options`] using the default options.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(JankMonitor)]
+/// fn jank_monitor() -> Html {
+///   let tasks = use_long_tasks();
+///   let total: f64 = tasks.iter().map(|task| task.duration).sum();
+///
+///   html!(format!("{} long tasks, blocking for {total:.0}ms", tasks.len()))
+/// }
+/// ```
+#[hook]
+pub fn use_long_tasks() -> UseLongTasksHandle {
+    use_long_tasks_with_options(UseLongTasksOptions::default())
+}
+
+/// Observe long tasks, blocking the main thread, using options.
+///
+/// Records entries of the [Long Tasks API](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceLongTaskTiming),
+/// and optionally the [Event Timing API](https://developer.mozilla.org/en-US/docs/Web/API/PerformanceEventTiming),
+/// re-rendering the component when new entries get reported. Browsers not supporting those entry
+/// types will simply not report any entries.
+///
+/// The observer gets disconnected when the component gets unmounted.
+#[hook]
+pub fn use_long_tasks_with_options(options: UseLongTasksOptions) -> UseLongTasksHandle {
+    let state = use_reducer(LongTasks::default);
+
+    {
+        let state = state.clone();
+        use_effect_with(options, move |options| {
+            let limit = options.limit;
+            let callback = Closure::<dyn Fn(web_sys::PerformanceObserverEntryList)>::new(
+                move |list: web_sys::PerformanceObserverEntryList| {
+                    let entries = list
+                        .get_entries()
+                        .iter()
+                        .filter_map(|entry| entry.dyn_into::<web_sys::PerformanceEntry>().ok())
+                        .map(PerformanceTask::from)
+                        .collect();
+                    state.dispatch(LongTasksAction::Add(entries, limit));
+                },
+            );
+
+            let observer =
+                web_sys::PerformanceObserver::new(callback.as_ref().unchecked_ref()).ok();
+
+            if let Some(observer) = &observer {
+                let mut entry_types = vec!["longtask"];
+                if options.event_timing {
+                    entry_types.push("event");
+                }
+                // `buffered` only works when observing a single `type`, not `entryTypes`
+                for entry_type in entry_types {
+                    let init = js_sys::Object::new();
+                    let _ = js_sys::Reflect::set(&init, &"type".into(), &entry_type.into());
+                    let _ =
+                        js_sys::Reflect::set(&init, &"buffered".into(), &options.buffered.into());
+                    observer.observe(init.unchecked_ref());
+                }
+            }
+
+            move || {
+                if let Some(observer) = observer {
+                    observer.disconnect();
+                }
+                drop(callback);
+            }
+        });
+    }
+
+    UseLongTasksHandle { state }
+}
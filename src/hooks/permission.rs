@@ -0,0 +1,141 @@
+//! Hooks for the Permissions API
+
+use std::{
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use wasm_bindgen::{closure::Closure, JsCast};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use yew::prelude::*;
+
+/// The state of a permission.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UsePermissionState {
+    /// The state is being queried.
+    #[default]
+    Pending,
+    /// The Permissions API, or the permission, is not supported by the browser.
+    Unsupported,
+    /// The permission was granted.
+    Granted,
+    /// The permission was denied.
+    Denied,
+    /// Using the API will prompt the user for permission.
+    Prompt,
+}
+
+impl UsePermissionState {
+    /// Check if the permission is granted.
+    pub fn is_granted(&self) -> bool {
+        matches!(self, Self::Granted)
+    }
+}
+
+impl From<web_sys::PermissionState> for UsePermissionState {
+    fn from(state: web_sys::PermissionState) -> Self {
+        match state {
+            web_sys::PermissionState::Granted => Self::Granted,
+            web_sys::PermissionState::Denied => Self::Denied,
+            web_sys::PermissionState::Prompt => Self::Prompt,
+            _ => Self::Unsupported,
+        }
+    }
+}
+
+type Subscription = (web_sys::PermissionStatus, Closure<dyn Fn()>);
+
+/// Track the state of a permission.
+///
+/// Queries the state of the permission `name` (like `geolocation`, `notifications`, or
+/// `clipboard-read`) using the
+/// [Permissions API](https://developer.mozilla.org/en-US/docs/Web/API/Permissions_API), and keeps
+/// tracking changes of it. This allows adapting the UI, before calling an API which requires the
+/// permission.
+///
+/// Names unknown to the browser result in [`UsePermissionState::Unsupported`].
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let permission = use_permission("geolocation");
+///
+///   match permission {
+///     UsePermissionState::Pending => html!(),
+///     UsePermissionState::Denied => html!("Location access was denied"),
+///     UsePermissionState::Unsupported => html!("Location is not supported"),
+///     UsePermissionState::Granted | UsePermissionState::Prompt => html!(<button>{"Show my location"}</button>),
+///   }
+/// }
+/// ```
+#[hook]
+pub fn use_permission<S>(name: S) -> UsePermissionState
+where
+    S: Into<Cow<'static, str>>,
+{
+    let state = use_state_eq(UsePermissionState::default);
+
+    {
+        let state = state.clone();
+        use_effect_with(name.into(), move |name| {
+            state.set(UsePermissionState::Pending);
+            let subscription = Rc::new(RefCell::new(None::<Subscription>));
+            let cancelled = Rc::new(Cell::new(false));
+
+            let query = gloo_utils::window()
+                .navigator()
+                .permissions()
+                .and_then(|permissions| {
+                    let descriptor = js_sys::Object::new();
+                    js_sys::Reflect::set(&descriptor, &"name".into(), &name.as_ref().into())?;
+                    permissions.query(&descriptor)
+                });
+
+            match query {
+                Ok(query) => {
+                    let subscription = subscription.clone();
+                    let cancelled = cancelled.clone();
+                    spawn_local(async move {
+                        let result = JsFuture::from(query).await;
+                        if cancelled.get() {
+                            return;
+                        }
+                        // unknown names get rejected
+                        let status = match result {
+                            Ok(status) => status.unchecked_into::<web_sys::PermissionStatus>(),
+                            Err(_) => {
+                                state.set(UsePermissionState::Unsupported);
+                                return;
+                            }
+                        };
+
+                        state.set(status.state().into());
+
+                        let onchange = {
+                            let status = status.clone();
+                            Closure::<dyn Fn()>::new(move || state.set(status.state().into()))
+                        };
+                        status.set_onchange(Some(onchange.as_ref().unchecked_ref()));
+                        *subscription.borrow_mut() = Some((status, onchange));
+                    });
+                }
+                Err(_) => state.set(UsePermissionState::Unsupported),
+            }
+
+            move || {
+                cancelled.set(true);
+                if let Some((status, onchange)) = subscription.borrow_mut().take() {
+                    status.set_onchange(None);
+                    drop(onchange);
+                }
+            }
+        });
+    }
+
+    *state
+}
@@ -0,0 +1,319 @@
+//! Hooks for state persisted in the web storage
+
+use crate::hooks::use_latest;
+use gloo_utils::format::JsValueSerdeExt;
+use std::{ops::Deref, rc::Rc};
+use wasm_bindgen::JsValue;
+use yew::prelude::*;
+
+/// The storage to persist state in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StorageKind {
+    /// Stored across browser sessions.
+    #[default]
+    Local,
+    /// Stored for the current tab only, until it gets closed.
+    Session,
+}
+
+impl StorageKind {
+    fn get(&self) -> Option<web_sys::Storage> {
+        let window = gloo_utils::window();
+        match self {
+            Self::Local => window.local_storage(),
+            Self::Session => window.session_storage(),
+        }
+        .ok()
+        .flatten()
+    }
+}
+
+/// A migration of stored state, receiving the version and the serialized state.
+pub type Migration<R> = Rc<dyn Fn(u32, JsValue) -> Option<R>>;
+
+/// Options for [`use_persistent_reducer_with_options`].
+pub struct UsePersistentReducerOptions<R> {
+    /// The storage to persist the state in.
+    pub storage: StorageKind,
+    /// The version of the state, which is stored along with it.
+    pub version: u32,
+    /// Migrate state stored with a different version. If there is no migration, or it returns
+    /// `None`, the stored state is dropped.
+    pub migrate: Option<Migration<R>>,
+}
+
+impl<R> Default for UsePersistentReducerOptions<R> {
+    fn default() -> Self {
+        Self {
+            storage: StorageKind::default(),
+            version: 0,
+            migrate: None,
+        }
+    }
+}
+
+/// Load the state from the storage, migrating it if necessary.
+fn load<R>(key: &str, options: &UsePersistentReducerOptions<R>) -> Option<R>
+where
+    for<'de> R: serde::Deserialize<'de>,
+{
+    let stored = options.storage.get()?.get_item(key).ok()??;
+    // stored as `[version, state]`
+    let stored = js_sys::JSON::parse(&stored).ok()?;
+    if !js_sys::Array::is_array(&stored) {
+        return None;
+    }
+    let version = js_sys::Reflect::get_u32(&stored, 0).ok()?.as_f64()? as u32;
+    let state = js_sys::Reflect::get_u32(&stored, 1).ok()?;
+
+    match version == options.version {
+        true => {
+            let result = state.into_serde();
+            #[cfg(feature = "log")]
+            let result = result.map_err(|err| {
+                log::debug!("Failed to deserialize stored state: {err}");
+            });
+            result.ok()
+        }
+        false => options
+            .migrate
+            .as_ref()
+            .and_then(|migrate| migrate(version, state)),
+    }
+}
+
+/// Store the state, along with its version.
+fn store<R>(key: &str, state: &R, storage: StorageKind, version: u32)
+where
+    R: serde::Serialize,
+{
+    let Some(storage) = storage.get() else {
+        return;
+    };
+    let result = JsValue::from_serde(&(version, state))
+        .map_err(|err| err.to_string())
+        .and_then(|value| js_sys::JSON::stringify(&value).map_err(|err| format!("{err:?}")))
+        .and_then(|value| {
+            storage
+                .set_item(key, &String::from(value))
+                .map_err(|err| format!("{err:?}"))
+        });
+
+    if let Err(_err) = result {
+        #[cfg(feature = "log")]
+        log::warn!("Failed to store state: {_err}");
+    }
+}
+
+/// The state of a reducer, along with the key it is stored under.
+struct Persisted<R> {
+    key: String,
+    state: Rc<R>,
+}
+
+enum PersistedAction<R>
+where
+    R: Reducible,
+{
+    Dispatch(R::Action),
+    /// Replace the state with the one of a different key.
+    Load(String, R),
+}
+
+impl<R> Reducible for Persisted<R>
+where
+    R: Reducible,
+{
+    type Action = PersistedAction<R>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        match action {
+            PersistedAction::Dispatch(action) => {
+                let state = self.state.clone().reduce(action);
+                match Rc::ptr_eq(&state, &self.state) {
+                    true => self,
+                    false => Rc::new(Self {
+                        key: self.key.clone(),
+                        state,
+                    }),
+                }
+            }
+            PersistedAction::Load(key, state) => Rc::new(Self {
+                key,
+                state: Rc::new(state),
+            }),
+        }
+    }
+}
+
+impl<R> PartialEq for Persisted<R>
+where
+    R: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.state == other.state
+    }
+}
+
+/// State handle for the [`use_persistent_reducer`] hook.
+pub struct UsePersistentReducerHandle<R>
+where
+    R: Reducible + 'static,
+{
+    state: UseReducerHandle<Persisted<R>>,
+}
+
+impl<R> UsePersistentReducerHandle<R>
+where
+    R: Reducible + 'static,
+{
+    /// Dispatch an action to the reducer.
+    pub fn dispatch(&self, action: R::Action) {
+        self.state.dispatch(PersistedAction::Dispatch(action));
+    }
+
+    /// Create a callback, dispatching actions.
+    pub fn dispatcher(&self) -> Callback<R::Action> {
+        let handle = self.clone();
+        Callback::from(move |action| handle.dispatch(action))
+    }
+}
+
+impl<R> Clone for UsePersistentReducerHandle<R>
+where
+    R: Reducible + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<R> PartialEq for UsePersistentReducerHandle<R>
+where
+    R: Reducible + PartialEq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<R> Deref for UsePersistentReducerHandle<R>
+where
+    R: Reducible + 'static,
+{
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state.state
+    }
+}
+
+/// A reducer, persisted in the storage, using default options.
+///
+/// See [`use_persistent_reducer_with_options`].
+#[hook]
+pub fn use_persistent_reducer<R, F>(
+    key: impl Into<String>,
+    init: F,
+) -> UsePersistentReducerHandle<R>
+where
+    for<'de> R: Reducible + PartialEq + serde::Serialize + serde::Deserialize<'de> + 'static,
+    F: Fn() -> R + 'static,
+{
+    use_persistent_reducer_with_options(key, init, UsePersistentReducerOptions::default())
+}
+
+/// A reducer, persisted in the local or session storage.
+///
+/// The state is initialized from the storage when the component is mounted, falling back to
+/// `init` if there is no stored state, or it can't be deserialized. Whenever the state changes,
+/// it gets serialized and stored under `key`. When the key changes, the state is loaded from the
+/// new key in the same way.
+///
+/// The state is stored along with a version. If the stored version differs from the current
+/// version, the stored state is passed to the `migrate` function of the options, which can convert
+/// it to the current state.
+///
+/// ## Example
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use gloo_utils::format::JsValueSerdeExt;
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(Default, PartialEq, serde::Serialize, serde::Deserialize)]
+/// struct Cart {
+///   items: Vec<(String, u32)>,
+/// }
+///
+/// impl Reducible for Cart {
+///   type Action = String;
+///
+///   fn reduce(self: Rc<Self>, item: String) -> Rc<Self> {
+///     let mut items = self.items.clone();
+///     items.push((item, 1));
+///     Rc::new(Self { items })
+///   }
+/// }
+///
+/// #[function_component(Shop)]
+/// fn shop() -> Html {
+///   let cart = use_persistent_reducer_with_options(
+///     "cart",
+///     Cart::default,
+///     UsePersistentReducerOptions {
+///       version: 1,
+///       // version 0 only stored the names of the items
+///       migrate: Some(Rc::new(|_version, state| {
+///         let items: Vec<String> = state.into_serde().ok()?;
+///         Some(Cart { items: items.into_iter().map(|item| (item, 1)).collect() })
+///       })),
+///       ..Default::default()
+///     },
+///   );
+///   let onadd = use_callback(cart.clone(), |_, cart| cart.dispatch("Apple".into()));
+///
+///   html!(<button onclick={onadd}>{ format!("Add ({})", cart.items.len()) }</button>)
+/// }
+/// ```
+#[hook]
+pub fn use_persistent_reducer_with_options<R, F>(
+    key: impl Into<String>,
+    init: F,
+    options: UsePersistentReducerOptions<R>,
+) -> UsePersistentReducerHandle<R>
+where
+    for<'de> R: Reducible + PartialEq + serde::Serialize + serde::Deserialize<'de> + 'static,
+    F: Fn() -> R + 'static,
+{
+    let key = key.into();
+    let state = use_reducer(|| Persisted {
+        state: Rc::new(load(&key, &options).unwrap_or_else(&init)),
+        key: key.clone(),
+    });
+    let init = use_latest(init);
+    let options = use_latest(options);
+
+    {
+        let state = state.clone();
+        let options = options.clone();
+        use_effect_with(key, move |key| {
+            if state.key != *key {
+                let options = options.borrow();
+                let loaded = load(key, &options).unwrap_or_else(|| (init.borrow())());
+                state.dispatch(PersistedAction::Load(key.clone(), loaded));
+            }
+        });
+    }
+
+    use_effect_with(state.clone(), move |state| {
+        let options = options.borrow();
+        // stored under its own key, also while a different key is being loaded
+        store(&state.key, &*state.state, options.storage, options.version);
+    });
+
+    UsePersistentReducerHandle { state }
+}
@@ -0,0 +1,257 @@
+//! Hooks for working with animation frames
+
+use crate::hooks::use_latest;
+use std::{
+    cell::{Cell, RefCell},
+    ops::Deref,
+    rc::Rc,
+};
+use wasm_bindgen::{closure::Closure, JsCast};
+use yew::prelude::*;
+
+type FrameCallback = Closure<dyn FnMut(f64)>;
+type FrameClosure = Rc<RefCell<Option<FrameCallback>>>;
+
+fn request_frame(closure: &FrameCallback) -> Option<i32> {
+    gloo_utils::window()
+        .request_animation_frame(closure.as_ref().unchecked_ref())
+        .ok()
+}
+
+/// State handle for the [`use_raf`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseRafHandle {
+    running: UseStateHandle<bool>,
+}
+
+impl UseRafHandle {
+    /// Pause the loop.
+    pub fn pause(&self) {
+        self.running.set(false);
+    }
+
+    /// Resume a paused loop.
+    pub fn resume(&self) {
+        self.running.set(true);
+    }
+
+    /// Check if the loop is running.
+    pub fn is_running(&self) -> bool {
+        *self.running
+    }
+}
+
+/// Run a function on every animation frame.
+///
+/// The function will be called on every frame, using `requestAnimationFrame`, with the time in
+/// milliseconds since the previous frame. The first frame after starting or resuming the loop
+/// reports a delta of `0`. The latest function will always be called.
+///
+/// The loop starts when the component is mounted, and can be paused and resumed using the
+/// returned handle. It is stopped when the component gets unmounted.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let angle = use_mut_ref(|| 0f64);
+///   let node = use_node_ref();
+///
+///   let raf = {
+///     let node = node.clone();
+///     use_raf(move |delta| {
+///       let mut angle = angle.borrow_mut();
+///       // one rotation per second
+///       *angle = (*angle + delta * 0.36) % 360f64;
+///       if let Some(element) = node.cast::<web_sys::Element>() {
+///         let _ = element.set_attribute("style", &format!("rotate: {angle}deg"));
+///       }
+///     })
+///   };
+///
+///   let onclick = use_callback(raf.clone(), |_, raf| match raf.is_running() {
+///     true => raf.pause(),
+///     false => raf.resume(),
+///   });
+///
+///   html!(<div ref={node} {onclick}>{"Spinning"}</div>)
+/// }
+/// ```
+#[hook]
+pub fn use_raf<F>(f: F) -> UseRafHandle
+where
+    F: Fn(f64) + 'static,
+{
+    let running = use_state_eq(|| true);
+    use_raf_loop(f, *running);
+
+    UseRafHandle { running }
+}
+
+/// Run a function on every animation frame, while `running` is `true`.
+#[hook]
+pub(crate) fn use_raf_loop<F>(f: F, running: bool)
+where
+    F: Fn(f64) + 'static,
+{
+    let f = use_latest(f);
+
+    use_effect_with(running, move |running| {
+        let closure: FrameClosure = Default::default();
+        let id = Rc::new(Cell::new(None));
+
+        if *running {
+            let mut last = None;
+            let next = {
+                let closure = closure.clone();
+                let id = id.clone();
+                FrameCallback::new(move |timestamp: f64| {
+                    let delta = last.map(|last| timestamp - last).unwrap_or_default();
+                    last = Some(timestamp);
+
+                    (f.borrow())(delta);
+
+                    if let Some(closure) = &*closure.borrow() {
+                        id.set(request_frame(closure));
+                    }
+                })
+            };
+            id.set(request_frame(&next));
+            *closure.borrow_mut() = Some(next);
+        }
+
+        move || {
+            if let Some(id) = id.take() {
+                let _ = gloo_utils::window().cancel_animation_frame(id);
+            }
+            closure.borrow_mut().take();
+        }
+    });
+}
+
+struct RafState<T> {
+    pending: RefCell<Option<T>>,
+    frame: Cell<Option<i32>>,
+    closure: RefCell<Option<FrameCallback>>,
+}
+
+/// State handle for the [`use_raf_state`] hook.
+pub struct UseRafStateHandle<T> {
+    state: UseStateHandle<T>,
+    inner: Rc<RafState<T>>,
+}
+
+impl<T> UseRafStateHandle<T> {
+    /// Set a new value, which will be applied with the next animation frame.
+    ///
+    /// Setting multiple values before the next frame only applies the latest one.
+    pub fn set(&self, value: T) {
+        *self.inner.pending.borrow_mut() = Some(value);
+        if self.inner.frame.get().is_none() {
+            if let Some(closure) = &*self.inner.closure.borrow() {
+                self.inner.frame.set(request_frame(closure));
+            }
+        }
+    }
+}
+
+impl<T> Clone for UseRafStateHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseRafStateHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<T> Deref for UseRafStateHandle<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// State, which gets updated at most once per animation frame.
+///
+/// This works like [`use_state`], but calls to [`set`](UseRafStateHandle::set) are coalesced, so
+/// that the state is updated (and the component re-rendered) at most once per animation frame.
+/// This prevents render storms from high-frequency events, like `pointermove` or `scroll`.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let position = use_raf_state(|| (0, 0));
+///   let onpointermove = use_callback(position.clone(), |e: PointerEvent, position| {
+///     position.set((e.client_x(), e.client_y()));
+///   });
+///
+///   let (x, y) = *position;
+///   html!(<div {onpointermove}>{ format!("{x} / {y}") }</div>)
+/// }
+/// ```
+#[hook]
+pub fn use_raf_state<T, F>(init: F) -> UseRafStateHandle<T>
+where
+    T: 'static,
+    F: FnOnce() -> T,
+{
+    let state = use_state(init);
+
+    let inner = {
+        let setter = state.setter();
+        (*use_memo((), move |()| {
+            let inner = Rc::new(RafState {
+                pending: RefCell::new(None),
+                frame: Cell::new(None),
+                closure: RefCell::new(None),
+            });
+            let closure = {
+                let inner = inner.clone();
+                FrameCallback::new(move |_: f64| {
+                    inner.frame.set(None);
+                    let pending = inner.pending.borrow_mut().take();
+                    if let Some(value) = pending {
+                        setter.set(value);
+                    }
+                })
+            };
+            *inner.closure.borrow_mut() = Some(closure);
+            inner
+        }))
+        .clone()
+    };
+
+    {
+        let inner = inner.clone();
+        use_effect_with((), move |()| {
+            move || {
+                if let Some(id) = inner.frame.take() {
+                    let _ = gloo_utils::window().cancel_animation_frame(id);
+                }
+                // break the cycle between the state and the closure
+                inner.closure.borrow_mut().take();
+            }
+        });
+    }
+
+    UseRafStateHandle { state, inner }
+}
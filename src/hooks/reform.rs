@@ -1,5 +1,6 @@
 //! Hooks for reforming callback
 
+use crate::hooks::use_latest;
 use yew::prelude::*;
 
 /// Reform a callback.
@@ -33,3 +34,59 @@ where
 {
     (*use_memo(callback, |callback| callback.reform(f))).clone()
 }
+
+/// Reform a callback, only emitting when a predicate passes.
+///
+/// Just like [`use_reform`], but the incoming event is first checked by the `predicate`. Only
+/// when it returns `true`, the event will be reformed and forwarded to the original callback.
+///
+/// The predicate and reform function are always evaluated in their latest version, so that
+/// they may capture state. Still, a changed instance is only returned when the original callback
+/// changes.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let processing = use_state_eq(|| false);
+///   // a callback accepting `()`
+///   let onclick = use_callback(processing.clone(), |(), processing| processing.set(true));
+///   // reform to accept `MouseEvent`, ignoring clicks while processing
+///   let onclick = use_reform_if(onclick, {
+///     let processing = *processing;
+///     move |_| !processing
+///   }, |_| ());
+///   // use
+///   html!(<button {onclick}>{"Click me"}</button>)
+/// }
+/// ```
+#[hook]
+pub fn use_reform_if<IN1, IN2, P, F>(callback: Callback<IN1>, predicate: P, f: F) -> Callback<IN2>
+where
+    IN1: 'static,
+    IN2: 'static,
+    P: Fn(&IN2) -> bool + 'static,
+    F: Fn(IN2) -> IN1 + 'static,
+{
+    let latest = use_latest((predicate, f));
+
+    (*use_memo(callback, |callback| {
+        let callback = callback.clone();
+        Callback::from(move |input: IN2| {
+            let input = {
+                let latest = latest.borrow();
+                let (predicate, f) = &*latest;
+                if !predicate(&input) {
+                    return;
+                }
+                f(input)
+            };
+            callback.emit(input);
+        })
+    }))
+    .clone()
+}
@@ -0,0 +1,232 @@
+//! Hooks for observing the size of elements
+//!
+//! All hook instances share a single [`web_sys::ResizeObserver`], which is created on first use.
+//! Each element is observed once, no matter how many hooks are interested in it.
+
+use crate::hooks::use_latest;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+use yew::prelude::*;
+
+/// The size of a box, in the writing mode of the element.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BoxSize {
+    /// The size in the inline direction, the width for horizontal text.
+    pub inline_size: f64,
+    /// The size in the block direction, the height for horizontal text.
+    pub block_size: f64,
+}
+
+/// The content rectangle of an element.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ContentRect {
+    /// The left offset of the content, which is the left padding.
+    pub x: f64,
+    /// The top offset of the content, which is the top padding.
+    pub y: f64,
+    /// The width of the content.
+    pub width: f64,
+    /// The height of the content.
+    pub height: f64,
+}
+
+/// The data of a [`web_sys::ResizeObserverEntry`].
+///
+/// The box sizes contain one entry per fragment of the element. They might be empty, when the
+/// browser doesn't support them (e.g. the device pixel content box).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResizeEntry {
+    /// The content rectangle, in CSS pixels.
+    pub content_rect: ContentRect,
+    /// The size of the content box, in CSS pixels.
+    pub content_box: Vec<BoxSize>,
+    /// The size of the border box, in CSS pixels.
+    pub border_box: Vec<BoxSize>,
+    /// The size of the content box, in device pixels.
+    pub device_pixel_content_box: Vec<BoxSize>,
+}
+
+fn sizes(entry: &web_sys::ResizeObserverEntry, key: &str) -> Vec<BoxSize> {
+    js_sys::Reflect::get(entry, &JsValue::from_str(key))
+        .ok()
+        .and_then(|sizes| sizes.dyn_into::<js_sys::Array>().ok())
+        .map(|sizes| {
+            sizes
+                .iter()
+                .map(|size| size.unchecked_into::<web_sys::ResizeObserverSize>())
+                .map(|size| BoxSize {
+                    inline_size: size.inline_size(),
+                    block_size: size.block_size(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl From<&web_sys::ResizeObserverEntry> for ResizeEntry {
+    fn from(entry: &web_sys::ResizeObserverEntry) -> Self {
+        let rect = entry.content_rect();
+        Self {
+            content_rect: ContentRect {
+                x: rect.x(),
+                y: rect.y(),
+                width: rect.width(),
+                height: rect.height(),
+            },
+            content_box: sizes(entry, "contentBoxSize"),
+            border_box: sizes(entry, "borderBoxSize"),
+            device_pixel_content_box: sizes(entry, "devicePixelContentBoxSize"),
+        }
+    }
+}
+
+struct Registration {
+    id: usize,
+    element: web_sys::Element,
+    callback: Rc<RefCell<Callback<ResizeEntry>>>,
+}
+
+struct SharedObserver {
+    observer: web_sys::ResizeObserver,
+    registrations: Rc<RefCell<Vec<Registration>>>,
+    _closure: Closure<dyn Fn(js_sys::Array)>,
+}
+
+impl SharedObserver {
+    fn new() -> Option<Self> {
+        let registrations = Rc::new(RefCell::new(Vec::<Registration>::new()));
+
+        let closure = {
+            let registrations = registrations.clone();
+            Closure::<dyn Fn(js_sys::Array)>::new(move |entries: js_sys::Array| {
+                for entry in entries.iter() {
+                    let entry = entry.unchecked_into::<web_sys::ResizeObserverEntry>();
+                    let target = entry.target();
+                    let data = ResizeEntry::from(&entry);
+
+                    // collect first, so that callbacks may (un)register
+                    let callbacks = registrations
+                        .borrow()
+                        .iter()
+                        .filter(|registration| registration.element == target)
+                        .map(|registration| registration.callback.borrow().clone())
+                        .collect::<Vec<_>>();
+
+                    for callback in callbacks {
+                        callback.emit(data.clone());
+                    }
+                }
+            })
+        };
+
+        let observer = web_sys::ResizeObserver::new(closure.as_ref().unchecked_ref()).ok()?;
+
+        Some(Self {
+            observer,
+            registrations,
+            _closure: closure,
+        })
+    }
+
+    fn register(&self, registration: Registration) {
+        let mut registrations = self.registrations.borrow_mut();
+        if !registrations
+            .iter()
+            .any(|existing| existing.element == registration.element)
+        {
+            self.observer.observe(&registration.element);
+        }
+        registrations.push(registration);
+    }
+
+    fn unregister(&self, id: usize) {
+        let mut registrations = self.registrations.borrow_mut();
+        let Some(index) = registrations.iter().position(|r| r.id == id) else {
+            return;
+        };
+        let removed = registrations.remove(index);
+        if !registrations
+            .iter()
+            .any(|existing| existing.element == removed.element)
+        {
+            self.observer.unobserve(&removed.element);
+        }
+    }
+}
+
+thread_local! {
+    static OBSERVER: RefCell<Option<Rc<SharedObserver>>> = const { RefCell::new(None) };
+    static NEXT_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+fn shared_observer() -> Option<Rc<SharedObserver>> {
+    OBSERVER.with(|observer| {
+        let mut observer = observer.borrow_mut();
+        if observer.is_none() {
+            *observer = SharedObserver::new().map(Rc::new);
+        }
+        observer.clone()
+    })
+}
+
+/// Observe the size of an element.
+///
+/// Every change of the size of the element referenced by `node` will be reported to the callback,
+/// including all box sizes reported by the browser. The latest callback will always be used.
+///
+/// The element is observed once the component got rendered, and re-observed when the node
+/// reference changes. It stops observing when the component gets unmounted.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let node = use_node_ref();
+///   let pixels = use_state_eq(BoxSize::default);
+///
+///   let onresize = use_callback(pixels.clone(), |entry: ResizeEntry, pixels| {
+///     if let Some(size) = entry.device_pixel_content_box.first() {
+///       pixels.set(*size);
+///     }
+///   });
+///   use_resize_observer(node.clone(), onresize);
+///
+///   html!(
+///     <canvas
+///       ref={node}
+///       width={pixels.inline_size.to_string()}
+///       height={pixels.block_size.to_string()}
+///     />
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_resize_observer(node: NodeRef, callback: Callback<ResizeEntry>) {
+    let callback = use_latest(callback);
+
+    use_effect_with(node, move |node| {
+        let element = node.cast::<web_sys::Element>();
+        let registered = element.zip(shared_observer()).map(|(element, observer)| {
+            let id = NEXT_ID.with(|id| id.replace(id.get() + 1));
+            observer.register(Registration {
+                id,
+                element,
+                callback,
+            });
+            (observer, id)
+        });
+
+        move || {
+            if let Some((observer, id)) = registered {
+                observer.unregister(id);
+            }
+        }
+    });
+}
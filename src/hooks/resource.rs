@@ -0,0 +1,256 @@
+//! Hooks for loading external resources, like scripts and stylesheets
+
+use gloo_events::EventListener;
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::{Rc, Weak},
+    thread::LocalKey,
+};
+use yew::prelude::*;
+
+/// The state of loading a resource.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum UseResourceState {
+    /// The resource is being loaded.
+    #[default]
+    Loading,
+    /// The resource was loaded.
+    Ready,
+    /// The resource failed to load.
+    Error,
+}
+
+impl UseResourceState {
+    /// Check if the resource is ready to be used.
+    pub fn is_ready(&self) -> bool {
+        matches!(self, Self::Ready)
+    }
+}
+
+/// An element loading a resource, shared by all hooks using it.
+struct Resource {
+    element: web_sys::Element,
+    state: Cell<UseResourceState>,
+    users: Cell<usize>,
+    _listeners: Vec<EventListener>,
+}
+
+impl Resource {
+    fn new(element: web_sys::Element, state: UseResourceState) -> Rc<Self> {
+        Rc::new_cyclic(|weak: &Weak<Self>| {
+            let mut listeners = vec![];
+            if state == UseResourceState::Loading {
+                for (event, outcome) in [
+                    ("load", UseResourceState::Ready),
+                    ("error", UseResourceState::Error),
+                ] {
+                    let weak = weak.clone();
+                    listeners.push(EventListener::new(&element, event, move |_| {
+                        if let Some(resource) = weak.upgrade() {
+                            resource.state.set(outcome);
+                        }
+                    }));
+                }
+            }
+            Self {
+                element,
+                state: Cell::new(state),
+                users: Cell::new(0),
+                _listeners: listeners,
+            }
+        })
+    }
+}
+
+type Registry = RefCell<HashMap<String, Rc<Resource>>>;
+
+thread_local! {
+    static SCRIPTS: Registry = Default::default();
+    static STYLESHEETS: Registry = Default::default();
+    static STYLES: Registry = Default::default();
+}
+
+/// Create an element for a resource, along with its initial state.
+type Create = fn(&str) -> Option<(web_sys::Element, UseResourceState)>;
+
+/// Use a shared resource, tracking its state.
+///
+/// If `release` is set, the element of the resource is removed once it is no longer used.
+#[hook]
+fn use_resource(
+    registry: &'static LocalKey<Registry>,
+    key: String,
+    create: Create,
+    release: bool,
+) -> UseResourceState {
+    let state = use_state_eq(UseResourceState::default);
+
+    {
+        let state = state.clone();
+        // the registry is part of the dependencies, as keys are only unique per registry
+        let id = registry as *const _ as usize;
+        use_effect_with((id, key), move |(_, key)| {
+            let resource = registry.with(|registry| {
+                let mut registry = registry.borrow_mut();
+                if let Some(resource) = registry.get(key) {
+                    return Some(resource.clone());
+                }
+                let (element, initial) = create(key)?;
+                let resource = Resource::new(element, initial);
+                registry.insert(key.clone(), resource.clone());
+                Some(resource)
+            });
+
+            // registered after the listeners of the resource, so its state is already updated
+            let mut listeners = vec![];
+            match &resource {
+                Some(resource) => {
+                    resource.users.set(resource.users.get() + 1);
+                    state.set(resource.state.get());
+                    for event in ["load", "error"] {
+                        let state = state.clone();
+                        let resource = resource.clone();
+                        listeners.push(EventListener::new(
+                            &resource.element.clone(),
+                            event,
+                            move |_| state.set(resource.state.get()),
+                        ));
+                    }
+                }
+                None => state.set(UseResourceState::Error),
+            }
+
+            let key = key.clone();
+            move || {
+                drop(listeners);
+                let Some(resource) = resource else {
+                    return;
+                };
+                resource.users.set(resource.users.get() - 1);
+                if release && resource.users.get() == 0 {
+                    registry.with(|registry| registry.borrow_mut().remove(&key));
+                    resource.element.remove();
+                }
+            }
+        });
+    }
+
+    *state
+}
+
+fn create_script(src: &str) -> Option<(web_sys::Element, UseResourceState)> {
+    let document = gloo_utils::document();
+
+    // loaded by someone else, we can't know when it finished
+    let scripts = document.scripts();
+    let existing = (0..scripts.length())
+        .filter_map(|i| scripts.item(i))
+        .find(|script| script.get_attribute("src").as_deref() == Some(src));
+    if let Some(existing) = existing {
+        return Some((existing, UseResourceState::Ready));
+    }
+
+    let script = document.create_element("script").ok()?;
+    script.set_attribute("src", src).ok()?;
+    script.set_attribute("async", "").ok()?;
+    document.head()?.append_child(&script).ok()?;
+    Some((script, UseResourceState::Loading))
+}
+
+/// Load an external script.
+///
+/// Adds a `<script>` element for the URL to the head of the document, and tracks the state of
+/// loading it. The script is only loaded once, no matter how many components use it, and stays
+/// loaded when the components are unmounted, as the effects of running it can't be reverted.
+///
+/// Scripts which are already part of the document, but not added by this hook, are considered
+/// ready.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Map)]
+/// fn map() -> Html {
+///   let sdk = use_script("https://example.com/maps-sdk.js");
+///
+///   match sdk {
+///     UseResourceState::Loading => html!("Loading map…"),
+///     UseResourceState::Ready => html!(<div id="map" />),
+///     UseResourceState::Error => html!("Failed to load the map"),
+///   }
+/// }
+/// ```
+#[hook]
+pub fn use_script(src: impl Into<String>) -> UseResourceState {
+    use_resource(&SCRIPTS, src.into(), create_script, false)
+}
+
+fn create_stylesheet(href: &str) -> Option<(web_sys::Element, UseResourceState)> {
+    let document = gloo_utils::document();
+    let link = document.create_element("link").ok()?;
+    link.set_attribute("rel", "stylesheet").ok()?;
+    link.set_attribute("href", href).ok()?;
+    document.head()?.append_child(&link).ok()?;
+    Some((link, UseResourceState::Loading))
+}
+
+fn create_style(css: &str) -> Option<(web_sys::Element, UseResourceState)> {
+    let document = gloo_utils::document();
+    let style = document.create_element("style").ok()?;
+    style.set_text_content(Some(css));
+    document.head()?.append_child(&style).ok()?;
+    // applied right away
+    Some((style, UseResourceState::Ready))
+}
+
+/// A stylesheet to add to the document.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Stylesheet {
+    /// A stylesheet loaded from a URL.
+    Url(String),
+    /// Raw CSS.
+    Css(String),
+}
+
+/// Add a stylesheet to the document.
+///
+/// Adds a `<link>` element for a URL, or a `<style>` element for raw CSS, to the head of the
+/// document, and tracks the state of loading it. Components using the same stylesheet share the
+/// element, which is removed once the last of them is unmounted.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   theme: String,
+/// }
+///
+/// #[function_component(Widget)]
+/// fn widget(props: &Props) -> Html {
+///   let theme = use_stylesheet(Stylesheet::Url(format!("/themes/{}.css", props.theme)));
+///   use_stylesheet(Stylesheet::Css(".widget { display: grid; }".into()));
+///
+///   html!(
+///     // avoid a flash of unstyled content
+///     if theme != UseResourceState::Loading {
+///       <div class="widget" />
+///     }
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_stylesheet(stylesheet: Stylesheet) -> UseResourceState {
+    let (registry, key, create): (_, _, Create) = match stylesheet {
+        Stylesheet::Url(href) => (&STYLESHEETS, href, create_stylesheet),
+        Stylesheet::Css(css) => (&STYLES, css, create_style),
+    };
+    use_resource(registry, key, create, true)
+}
@@ -0,0 +1,243 @@
+//! Hooks for the orientation of the screen
+
+use gloo_events::EventListener;
+use std::{
+    cell::Cell,
+    fmt::{Display, Formatter},
+    ops::Deref,
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use yew::prelude::*;
+
+/// The type of orientation of the screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrientationKind {
+    /// The primary portrait mode, usually upright.
+    PortraitPrimary,
+    /// The secondary portrait mode, usually upside down.
+    PortraitSecondary,
+    /// The primary landscape mode.
+    LandscapePrimary,
+    /// The secondary landscape mode, rotated by 180 degrees.
+    LandscapeSecondary,
+}
+
+impl OrientationKind {
+    /// Check if this is either of the portrait orientations.
+    pub fn is_portrait(&self) -> bool {
+        matches!(self, Self::PortraitPrimary | Self::PortraitSecondary)
+    }
+
+    /// Check if this is either of the landscape orientations.
+    pub fn is_landscape(&self) -> bool {
+        matches!(self, Self::LandscapePrimary | Self::LandscapeSecondary)
+    }
+}
+
+/// The current orientation of the screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScreenOrientationState {
+    /// The type of orientation.
+    pub kind: OrientationKind,
+    /// The angle, in degrees, relative to the natural orientation of the device.
+    pub angle: u16,
+}
+
+/// An orientation to lock the screen to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrientationLock {
+    /// Any of the orientations.
+    Any,
+    /// The natural orientation of the device.
+    Natural,
+    /// Either of the landscape orientations.
+    Landscape,
+    /// Either of the portrait orientations.
+    Portrait,
+    /// The primary portrait orientation.
+    PortraitPrimary,
+    /// The secondary portrait orientation.
+    PortraitSecondary,
+    /// The primary landscape orientation.
+    LandscapePrimary,
+    /// The secondary landscape orientation.
+    LandscapeSecondary,
+}
+
+impl From<OrientationLock> for web_sys::OrientationLockType {
+    fn from(lock: OrientationLock) -> Self {
+        match lock {
+            OrientationLock::Any => Self::Any,
+            OrientationLock::Natural => Self::Natural,
+            OrientationLock::Landscape => Self::Landscape,
+            OrientationLock::Portrait => Self::Portrait,
+            OrientationLock::PortraitPrimary => Self::PortraitPrimary,
+            OrientationLock::PortraitSecondary => Self::PortraitSecondary,
+            OrientationLock::LandscapePrimary => Self::LandscapePrimary,
+            OrientationLock::LandscapeSecondary => Self::LandscapeSecondary,
+        }
+    }
+}
+
+/// An error locking the orientation of the screen.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ScreenOrientationError {
+    /// Locking is not supported, e.g. on desktop browsers, or when not in fullscreen mode.
+    Unsupported,
+    /// Locking was aborted, e.g. by another call to lock.
+    Aborted,
+    /// Locking failed for another reason.
+    Failed(String),
+}
+
+impl Display for ScreenOrientationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str("Locking the screen orientation is not supported"),
+            Self::Aborted => f.write_str("Locking the screen orientation was aborted"),
+            Self::Failed(msg) => write!(f, "Failed to lock the screen orientation: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ScreenOrientationError {}
+
+impl From<JsValue> for ScreenOrientationError {
+    fn from(err: JsValue) -> Self {
+        match err.dyn_ref::<web_sys::DomException>() {
+            Some(err) if err.name() == "NotSupportedError" => Self::Unsupported,
+            Some(err) if err.name() == "AbortError" => Self::Aborted,
+            Some(err) => Self::Failed(err.message()),
+            None => Self::Failed(format!("{err:?}")),
+        }
+    }
+}
+
+fn screen_orientation() -> Option<web_sys::ScreenOrientation> {
+    let screen = gloo_utils::window().screen().ok()?;
+    // not available in older browsers
+    match js_sys::Reflect::get(&screen, &"orientation".into()) {
+        Ok(orientation) if !orientation.is_undefined() => Some(orientation.unchecked_into()),
+        _ => None,
+    }
+}
+
+fn current() -> Option<ScreenOrientationState> {
+    let orientation = screen_orientation()?;
+    let kind = match orientation.type_().ok()? {
+        web_sys::OrientationType::PortraitPrimary => OrientationKind::PortraitPrimary,
+        web_sys::OrientationType::PortraitSecondary => OrientationKind::PortraitSecondary,
+        web_sys::OrientationType::LandscapePrimary => OrientationKind::LandscapePrimary,
+        web_sys::OrientationType::LandscapeSecondary => OrientationKind::LandscapeSecondary,
+        _ => return None,
+    };
+    Some(ScreenOrientationState {
+        kind,
+        angle: orientation.angle().ok()?,
+    })
+}
+
+/// State handle for the [`use_screen_orientation_lock`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseScreenOrientationLockHandle {
+    state: UseStateHandle<Option<ScreenOrientationState>>,
+    locked: Rc<Cell<bool>>,
+}
+
+impl UseScreenOrientationLockHandle {
+    /// Lock the orientation of the screen.
+    ///
+    /// Most browsers only allow this in fullscreen mode.
+    pub async fn lock(&self, lock: OrientationLock) -> Result<(), ScreenOrientationError> {
+        let orientation = screen_orientation().ok_or(ScreenOrientationError::Unsupported)?;
+        JsFuture::from(orientation.lock(lock.into())?).await?;
+        self.locked.set(true);
+        Ok(())
+    }
+
+    /// Unlock the orientation of the screen.
+    pub fn unlock(&self) {
+        if let Some(orientation) = screen_orientation() {
+            let _ = orientation.unlock();
+        }
+        self.locked.set(false);
+    }
+}
+
+impl Deref for UseScreenOrientationLockHandle {
+    type Target = Option<ScreenOrientationState>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// Track, and lock, the orientation of the screen.
+///
+/// Returns the current orientation, using the
+/// [Screen Orientation API](https://developer.mozilla.org/en-US/docs/Web/API/Screen_Orientation_API),
+/// or `None` if the API is not available. The handle allows locking the orientation, and unlocking
+/// it again. A lock acquired through the handle is released when the component is unmounted.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let orientation = use_screen_orientation_lock();
+///   let error = use_state_eq(|| None::<ScreenOrientationError>);
+///
+///   let onclick = use_callback(
+///     (orientation.clone(), error.clone()),
+///     |_, (orientation, error)| {
+///       let orientation = orientation.clone();
+///       let error = error.clone();
+///       wasm_bindgen_futures::spawn_local(async move {
+///         error.set(orientation.lock(OrientationLock::Landscape).await.err());
+///       });
+///     },
+///   );
+///
+///   html!(
+///     <>
+///       if orientation.is_some_and(|orientation| orientation.kind.is_portrait()) {
+///         <p>{"Please rotate your device"}</p>
+///       }
+///       <button {onclick}>{"Lock to landscape"}</button>
+///       if let Some(err) = &*error {
+///         <p class="error">{ err.to_string() }</p>
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_screen_orientation_lock() -> UseScreenOrientationLockHandle {
+    let state = use_state_eq(current);
+    let locked = use_memo((), |()| Cell::new(false));
+
+    {
+        let state = state.clone();
+        let locked = locked.clone();
+        use_effect_with((), move |()| {
+            let listener = screen_orientation().map(|orientation| {
+                EventListener::new(&orientation, "change", move |_| state.set(current()))
+            });
+            move || {
+                drop(listener);
+                if locked.get() {
+                    if let Some(orientation) = screen_orientation() {
+                        let _ = orientation.unlock();
+                    }
+                }
+            }
+        });
+    }
+
+    UseScreenOrientationLockHandle { state, locked }
+}
@@ -0,0 +1,123 @@
+//! Hooks for tracking the scroll direction
+
+use crate::hooks::{use_event_listener_with_options, ListenerTarget, UseEventListenerOptions};
+use yew::prelude::*;
+
+/// The vertical direction of scrolling.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScrollDirection {
+    /// Scrolling towards the top.
+    Up,
+    /// Scrolling towards the bottom.
+    Down,
+}
+
+/// Tracks the scroll position, reporting a direction once it moved far enough.
+#[derive(Clone, Copy, Debug, Default)]
+struct Tracker {
+    anchor: f64,
+}
+
+impl Tracker {
+    fn update(&mut self, position: f64, threshold: f64) -> Option<ScrollDirection> {
+        let delta = position - self.anchor;
+        if delta.abs() < threshold {
+            return None;
+        }
+        self.anchor = position;
+        Some(match delta < 0f64 {
+            true => ScrollDirection::Up,
+            false => ScrollDirection::Down,
+        })
+    }
+}
+
+fn scroll_position(target: &ListenerTarget) -> Option<f64> {
+    match target {
+        ListenerTarget::Window => gloo_utils::window().scroll_y().ok(),
+        ListenerTarget::Document => gloo_utils::document()
+            .scrolling_element()
+            .map(|element| element.scroll_top().into()),
+        ListenerTarget::Node(node) => node
+            .cast::<web_sys::Element>()
+            .map(|element| element.scroll_top().into()),
+    }
+}
+
+/// Track the direction of vertical scrolling.
+///
+/// Returns the direction the `target` (like the window, or a scrollable element) was last
+/// scrolled in, or `None` if it wasn't scrolled yet. The direction only changes after scrolling
+/// at least `threshold` pixels, which filters out small movements.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let direction = use_scroll_direction(ListenerTarget::Window, 10f64);
+///   let hidden = direction == Some(ScrollDirection::Down);
+///
+///   html!(
+///     <header class={classes!("header", hidden.then_some("hidden"))}>{"Title"}</header>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_scroll_direction<T>(target: T, threshold: f64) -> Option<ScrollDirection>
+where
+    T: Into<ListenerTarget>,
+{
+    let target = target.into();
+    let direction = use_state_eq(|| None::<ScrollDirection>);
+    let tracker = use_mut_ref(Tracker::default);
+
+    {
+        let tracker = tracker.clone();
+        use_effect_with(target.clone(), move |target| {
+            // start from the current position
+            if let Some(position) = scroll_position(target) {
+                tracker.borrow_mut().anchor = position;
+            }
+        });
+    }
+
+    let onscroll = use_callback(
+        (target.clone(), direction.clone(), threshold),
+        move |_: web_sys::Event, (target, direction, threshold)| {
+            let Some(position) = scroll_position(target) else {
+                return;
+            };
+            if let Some(next) = tracker.borrow_mut().update(position, *threshold) {
+                direction.set(Some(next));
+            }
+        },
+    );
+
+    use_event_listener_with_options(
+        target,
+        "scroll",
+        onscroll,
+        UseEventListenerOptions::enable_passive(),
+    );
+
+    *direction
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_threshold() {
+        let mut tracker = Tracker::default();
+        assert_eq!(tracker.update(5f64, 10f64), None);
+        assert_eq!(tracker.update(12f64, 10f64), Some(ScrollDirection::Down));
+        assert_eq!(tracker.update(8f64, 10f64), None);
+        assert_eq!(tracker.update(30f64, 10f64), Some(ScrollDirection::Down));
+        assert_eq!(tracker.update(15f64, 10f64), Some(ScrollDirection::Up));
+    }
+}
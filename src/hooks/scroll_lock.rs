@@ -0,0 +1,119 @@
+//! Hooks for locking the scrolling of the page
+
+use std::cell::RefCell;
+use yew::prelude::*;
+
+/// The state of the body, before it got locked.
+struct Lock {
+    count: usize,
+    overflow: String,
+    padding_right: String,
+}
+
+thread_local! {
+    static LOCK: RefCell<Option<Lock>> = const { RefCell::new(None) };
+}
+
+fn lock() {
+    LOCK.with(|lock| {
+        let mut lock = lock.borrow_mut();
+        if let Some(lock) = lock.as_mut() {
+            lock.count += 1;
+            return;
+        }
+
+        let body = gloo_utils::body();
+        let style = body.style();
+
+        // compensate for the scrollbar disappearing, preventing the content from shifting
+        let window = gloo_utils::window();
+        let scrollbar = window
+            .inner_width()
+            .ok()
+            .and_then(|width| width.as_f64())
+            .zip(gloo_utils::document().document_element())
+            .map(|(width, root)| width - f64::from(root.client_width()))
+            .unwrap_or_default();
+
+        *lock = Some(Lock {
+            count: 1,
+            overflow: style.get_property_value("overflow").unwrap_or_default(),
+            padding_right: style
+                .get_property_value("padding-right")
+                .unwrap_or_default(),
+        });
+
+        let _ = style.set_property("overflow", "hidden");
+        if scrollbar > 0f64 {
+            let _ = style.set_property("padding-right", &format!("{scrollbar}px"));
+        }
+    });
+}
+
+fn unlock() {
+    LOCK.with(|lock| {
+        let mut lock = lock.borrow_mut();
+        let Some(current) = lock.as_mut() else {
+            return;
+        };
+
+        current.count -= 1;
+        if current.count > 0 {
+            return;
+        }
+
+        if let Some(original) = lock.take() {
+            let style = gloo_utils::body().style();
+            let _ = style.set_property("overflow", &original.overflow);
+            let _ = style.set_property("padding-right", &original.padding_right);
+        }
+    });
+}
+
+/// Prevent scrolling the page, while `active` is `true`.
+///
+/// This locks scrolling of the body, e.g. while a modal dialog is open. The width of the
+/// scrollbar, which disappears when locking, is added as padding to the body to prevent the
+/// content from shifting. The original style of the body is restored when deactivating, or when
+/// the component gets unmounted.
+///
+/// Locks can be nested: the page stays locked until all active hooks are released.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let open = use_state_eq(|| false);
+///   use_scroll_lock(*open);
+///
+///   let onclick = use_callback(open.clone(), |_, open| open.set(!**open));
+///
+///   html!(
+///     <>
+///       <button {onclick}>{"Toggle"}</button>
+///       if *open {
+///         <div class="modal">{"No scrolling"}</div>
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_scroll_lock(active: bool) {
+    use_effect_with(active, |active| {
+        let locked = *active;
+        if locked {
+            lock();
+        }
+
+        move || {
+            if locked {
+                unlock();
+            }
+        }
+    });
+}
@@ -0,0 +1,367 @@
+//! Hooks for selecting items of lists and tables
+
+use std::{collections::HashSet, hash::Hash, ops::Deref, rc::Rc};
+use yew::prelude::*;
+
+/// How many items can be selected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// At most one item.
+    #[default]
+    Single,
+    /// Any number of items, allowing toggling items and selecting ranges.
+    Multiple,
+}
+
+/// The selected items, identified by their keys.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Selection<K>
+where
+    K: Eq + Hash,
+{
+    selected: HashSet<K>,
+    /// The item ranges start from.
+    anchor: Option<K>,
+}
+
+impl<K> Default for Selection<K>
+where
+    K: Eq + Hash,
+{
+    fn default() -> Self {
+        Self {
+            selected: HashSet::new(),
+            anchor: None,
+        }
+    }
+}
+
+impl<K> Selection<K>
+where
+    K: Eq + Hash,
+{
+    /// The keys of the selected items.
+    pub fn selected(&self) -> &HashSet<K> {
+        &self.selected
+    }
+
+    /// The item last selected or toggled, which is the start of ranges.
+    pub fn anchor(&self) -> Option<&K> {
+        self.anchor.as_ref()
+    }
+}
+
+/// Operations of the [`Selection`] reducer.
+pub enum SelectionOp<K> {
+    /// Select only this item.
+    Select(K),
+    /// Add or remove an item.
+    Toggle(K),
+    /// Select the items from the anchor to this item.
+    Range(K),
+    /// Select all items, if multiple items can be selected.
+    SelectAll,
+    /// Deselect all items.
+    Clear,
+}
+
+/// Actions of the [`Selection`] reducer.
+pub struct SelectionAction<K> {
+    /// The operation to apply.
+    pub op: SelectionOp<K>,
+    /// The current selection mode.
+    pub mode: SelectionMode,
+    /// All items, in order.
+    pub items: Rc<Vec<K>>,
+}
+
+impl<K> Reducible for Selection<K>
+where
+    K: Clone + Eq + Hash,
+{
+    type Action = SelectionAction<K>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let SelectionAction { op, mode, items } = action;
+        let single = |key: K| Self {
+            selected: HashSet::from([key.clone()]),
+            anchor: Some(key),
+        };
+
+        let next = match (op, mode) {
+            (SelectionOp::Select(key), _) => single(key),
+            (SelectionOp::Toggle(key), SelectionMode::Single) => match self.selected.contains(&key)
+            {
+                true => Self {
+                    selected: HashSet::new(),
+                    anchor: Some(key),
+                },
+                false => single(key),
+            },
+            (SelectionOp::Toggle(key), SelectionMode::Multiple) => {
+                let mut selected = self.selected.clone();
+                if !selected.remove(&key) {
+                    selected.insert(key.clone());
+                }
+                Self {
+                    selected,
+                    anchor: Some(key),
+                }
+            }
+            (SelectionOp::Range(key), SelectionMode::Single) => single(key),
+            (SelectionOp::Range(key), SelectionMode::Multiple) => {
+                let end = items.iter().position(|item| *item == key);
+                let start = self
+                    .anchor
+                    .as_ref()
+                    .and_then(|anchor| items.iter().position(|item| item == anchor));
+                match (start, end) {
+                    (Some(start), Some(end)) => Self {
+                        selected: items[start.min(end)..=start.max(end)]
+                            .iter()
+                            .cloned()
+                            .collect(),
+                        anchor: self.anchor.clone(),
+                    },
+                    // without a known anchor, there is no range
+                    _ => single(key),
+                }
+            }
+            (SelectionOp::SelectAll, SelectionMode::Single) => return self,
+            (SelectionOp::SelectAll, SelectionMode::Multiple) => Self {
+                selected: items.iter().cloned().collect(),
+                anchor: self.anchor.clone(),
+            },
+            (SelectionOp::Clear, _) => Self::default(),
+        };
+
+        match next == *self {
+            true => self,
+            false => Rc::new(next),
+        }
+    }
+}
+
+/// State handle for the [`use_selection`] hook.
+pub struct UseSelectionHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    state: UseReducerHandle<Selection<K>>,
+    mode: SelectionMode,
+    items: Rc<Vec<K>>,
+}
+
+impl<K> UseSelectionHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    fn dispatch(&self, op: SelectionOp<K>) {
+        self.state.dispatch(SelectionAction {
+            op,
+            mode: self.mode,
+            items: self.items.clone(),
+        });
+    }
+
+    /// Select only this item.
+    pub fn select(&self, key: K) {
+        self.dispatch(SelectionOp::Select(key));
+    }
+
+    /// Add or remove an item, or select and deselect it, if only a single item can be selected.
+    pub fn toggle(&self, key: K) {
+        self.dispatch(SelectionOp::Toggle(key));
+    }
+
+    /// Select the items from the item last selected or toggled to this one.
+    pub fn select_range(&self, key: K) {
+        self.dispatch(SelectionOp::Range(key));
+    }
+
+    /// Select all items, if multiple items can be selected.
+    pub fn select_all(&self) {
+        self.dispatch(SelectionOp::SelectAll);
+    }
+
+    /// Deselect all items.
+    pub fn clear(&self) {
+        self.dispatch(SelectionOp::Clear);
+    }
+
+    /// Check if an item is selected.
+    pub fn is_selected(&self, key: &K) -> bool {
+        self.state.selected.contains(key)
+    }
+
+    /// Check if all items are selected.
+    pub fn is_all_selected(&self) -> bool {
+        !self.items.is_empty() && self.items.iter().all(|key| self.is_selected(key))
+    }
+
+    /// Create a click handler for the row of an item.
+    ///
+    /// A click selects only this item, holding Ctrl (or ⌘) toggles it, and holding Shift selects
+    /// the range from the item last clicked.
+    pub fn onclick(&self, key: K) -> Callback<MouseEvent> {
+        let handle = self.clone();
+        Callback::from(move |e: MouseEvent| {
+            let key = key.clone();
+            if e.shift_key() {
+                handle.select_range(key);
+            } else if e.ctrl_key() || e.meta_key() {
+                handle.toggle(key);
+            } else {
+                handle.select(key);
+            }
+        })
+    }
+}
+
+impl<K> Clone for UseSelectionHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            mode: self.mode,
+            items: self.items.clone(),
+        }
+    }
+}
+
+impl<K> PartialEq for UseSelectionHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && self.mode == other.mode && self.items == other.items
+    }
+}
+
+impl<K> Deref for UseSelectionHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    type Target = HashSet<K>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state.selected
+    }
+}
+
+/// Selection of items, like rows of a table.
+///
+/// The items are identified by their keys, which are provided in the order they are shown, for
+/// selecting ranges and all items. The handle dereferences to the keys of the selected items, and
+/// provides methods for changing the selection, as well as click handlers for rows.
+///
+/// Selected keys which are no longer part of the items stay selected.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   files: Vec<String>,
+/// }
+///
+/// #[function_component(Files)]
+/// fn files(props: &Props) -> Html {
+///   let selection = use_selection(props.files.clone(), SelectionMode::Multiple);
+///   let onselectall = use_callback(selection.clone(), |_, selection| {
+///     match selection.is_all_selected() {
+///       true => selection.clear(),
+///       false => selection.select_all(),
+///     }
+///   });
+///
+///   html!(
+///     <table>
+///       <tr><th onclick={onselectall}>{ format!("{} selected", selection.len()) }</th></tr>
+///       { for props.files.iter().map(|file| html!(
+///         <tr
+///           key={file.clone()}
+///           class={classes!(selection.is_selected(file).then_some("selected"))}
+///           onclick={selection.onclick(file.clone())}
+///         >
+///           <td>{ file }</td>
+///         </tr>
+///       )) }
+///     </table>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_selection<K>(items: Vec<K>, mode: SelectionMode) -> UseSelectionHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    let state = use_reducer(Selection::default);
+    let items = use_memo(items, |items| items.clone());
+
+    UseSelectionHandle { state, mode, items }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn reduce(
+        state: Rc<Selection<u32>>,
+        op: SelectionOp<u32>,
+        mode: SelectionMode,
+    ) -> Rc<Selection<u32>> {
+        state.reduce(SelectionAction {
+            op,
+            mode,
+            items: Rc::new(vec![1, 2, 3, 4, 5]),
+        })
+    }
+
+    fn selected(state: &Selection<u32>) -> Vec<u32> {
+        let mut selected = state.selected().iter().copied().collect::<Vec<_>>();
+        selected.sort();
+        selected
+    }
+
+    #[test]
+    fn test_multiple() {
+        let mode = SelectionMode::Multiple;
+        let state = Rc::new(Selection::default());
+        let state = reduce(state, SelectionOp::Select(2), mode);
+        let state = reduce(state, SelectionOp::Range(4), mode);
+        assert_eq!(selected(&state), vec![2, 3, 4]);
+
+        // ranges start from the anchor, in any direction
+        let state = reduce(state, SelectionOp::Range(1), mode);
+        assert_eq!(selected(&state), vec![1, 2]);
+
+        let state = reduce(state, SelectionOp::Toggle(5), mode);
+        let state = reduce(state, SelectionOp::Toggle(1), mode);
+        assert_eq!(selected(&state), vec![2, 5]);
+        assert_eq!(state.anchor(), Some(&1));
+
+        let state = reduce(state, SelectionOp::SelectAll, mode);
+        assert_eq!(selected(&state), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_single() {
+        let mode = SelectionMode::Single;
+        let state = Rc::new(Selection::default());
+        let state = reduce(state, SelectionOp::Select(2), mode);
+        let state = reduce(state, SelectionOp::Range(4), mode);
+        assert_eq!(selected(&state), vec![4]);
+
+        let state = reduce(state, SelectionOp::Toggle(4), mode);
+        assert!(state.selected().is_empty());
+
+        let unchanged = reduce(state.clone(), SelectionOp::SelectAll, mode);
+        assert!(Rc::ptr_eq(&state, &unchanged));
+    }
+}
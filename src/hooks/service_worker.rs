@@ -0,0 +1,341 @@
+//! Hooks for registering a service worker
+
+use gloo_events::EventListener;
+use std::{
+    cell::{Cell, RefCell},
+    ops::Deref,
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use yew::prelude::*;
+
+/// The message posted to a waiting worker by
+/// [`UseServiceWorkerHandle::skip_waiting_and_reload`].
+pub const SKIP_WAITING_MESSAGE_TYPE: &str = "SKIP_WAITING";
+
+/// The state of the service worker registration.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ServiceWorkerState {
+    /// The browser supports service workers.
+    pub supported: bool,
+    /// The worker was registered.
+    pub registered: bool,
+    /// A worker is being installed.
+    pub installing: bool,
+    /// An installed worker is waiting to be activated.
+    pub waiting: bool,
+    /// A worker is active.
+    pub active: bool,
+    /// The page is controlled by an active worker.
+    pub controlled: bool,
+    /// The error of the registration, if it failed.
+    pub error: Option<String>,
+}
+
+impl ServiceWorkerState {
+    /// Check if a new version is waiting, replacing the one controlling the page.
+    pub fn update_available(&self) -> bool {
+        self.waiting && self.controlled
+    }
+}
+
+/// Options for [`use_service_worker_with_options`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UseServiceWorkerOptions {
+    /// The scope of the worker, defaults to the location of the script.
+    pub scope: Option<String>,
+    /// Load the script as an ES module, rather than a classic script.
+    pub module: bool,
+}
+
+fn container() -> Option<web_sys::ServiceWorkerContainer> {
+    let navigator = gloo_utils::window().navigator();
+    // not available in insecure contexts
+    match js_sys::Reflect::get(&navigator, &"serviceWorker".into()) {
+        Ok(container) if !container.is_undefined() => Some(container.unchecked_into()),
+        _ => None,
+    }
+}
+
+fn js_error(err: &JsValue) -> String {
+    match err.dyn_ref::<js_sys::Error>() {
+        Some(err) => err.message().into(),
+        None => err.as_string().unwrap_or_else(|| format!("{err:?}")),
+    }
+}
+
+/// A registration, tracking state changes of its workers.
+struct Registration {
+    registration: web_sys::ServiceWorkerRegistration,
+    state: UseStateHandle<ServiceWorkerState>,
+    onupdatefound: RefCell<Option<EventListener>>,
+    /// Listeners of the currently tracked workers.
+    onstatechange: RefCell<Vec<EventListener>>,
+}
+
+impl Registration {
+    fn new(
+        registration: web_sys::ServiceWorkerRegistration,
+        state: UseStateHandle<ServiceWorkerState>,
+    ) -> Rc<Self> {
+        let result = Rc::new(Self {
+            registration,
+            state,
+            onupdatefound: Default::default(),
+            onstatechange: Default::default(),
+        });
+
+        let onupdatefound = {
+            let weak = Rc::downgrade(&result);
+            EventListener::new(&result.registration, "updatefound", move |_| {
+                if let Some(registration) = weak.upgrade() {
+                    registration.track();
+                }
+            })
+        };
+        *result.onupdatefound.borrow_mut() = Some(onupdatefound);
+        result.track();
+
+        result
+    }
+
+    /// Listen to state changes of the installing or waiting worker, and update the state.
+    ///
+    /// This replaces the listeners of previously tracked workers.
+    fn track(self: &Rc<Self>) {
+        let workers = [self.registration.installing(), self.registration.waiting()];
+        let listeners = workers
+            .into_iter()
+            .flatten()
+            .map(|worker| {
+                let weak = Rc::downgrade(self);
+                EventListener::new(&worker, "statechange", move |_| {
+                    if let Some(registration) = weak.upgrade() {
+                        registration.update_state();
+                    }
+                })
+            })
+            .collect();
+        *self.onstatechange.borrow_mut() = listeners;
+        self.update_state();
+    }
+
+    fn update_state(&self) {
+        self.state.set(ServiceWorkerState {
+            supported: true,
+            registered: true,
+            installing: self.registration.installing().is_some(),
+            waiting: self.registration.waiting().is_some(),
+            active: self.registration.active().is_some(),
+            controlled: container().and_then(|c| c.controller()).is_some(),
+            error: None,
+        });
+    }
+}
+
+/// State handle for the [`use_service_worker`] hook.
+#[derive(Clone)]
+pub struct UseServiceWorkerHandle {
+    state: UseStateHandle<ServiceWorkerState>,
+    registration: Rc<RefCell<Option<Rc<Registration>>>>,
+    reload: Rc<Cell<bool>>,
+}
+
+impl UseServiceWorkerHandle {
+    /// Check the server for a new version of the worker.
+    pub fn update(&self) {
+        let Some(registration) = &*self.registration.borrow() else {
+            return;
+        };
+        if let Ok(promise) = registration.registration.update() {
+            spawn_local(async move {
+                let _ = JsFuture::from(promise).await;
+            });
+        }
+    }
+
+    /// Activate the waiting worker, and reload the page once it took control.
+    ///
+    /// This posts a message of type [`SKIP_WAITING_MESSAGE_TYPE`] to the waiting worker, which is
+    /// expected to call `self.skipWaiting()` in response:
+    ///
+    /// ```javascript
+    /// self.addEventListener("message", (event) => {
+    ///   if (event.data?.type === "SKIP_WAITING") {
+    ///     self.skipWaiting();
+    ///   }
+    /// });
+    /// ```
+    pub fn skip_waiting_and_reload(&self) {
+        let Some(waiting) = self
+            .registration
+            .borrow()
+            .as_ref()
+            .and_then(|registration| registration.registration.waiting())
+        else {
+            return;
+        };
+
+        let message = js_sys::Object::new();
+        let _ = js_sys::Reflect::set(&message, &"type".into(), &SKIP_WAITING_MESSAGE_TYPE.into());
+        if waiting.post_message(&message).is_ok() {
+            self.reload.set(true);
+        }
+    }
+}
+
+impl PartialEq for UseServiceWorkerHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && Rc::ptr_eq(&self.registration, &other.registration)
+    }
+}
+
+impl Deref for UseServiceWorkerHandle {
+    type Target = ServiceWorkerState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// Register a service worker.
+///
+/// This is [`use_service_worker_with_options`] using the default options.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let worker = use_service_worker("/sw.js");
+///   let onclick = use_callback(worker.clone(), |_, worker| worker.skip_waiting_and_reload());
+///
+///   html!(
+///     if worker.update_available() {
+///       <div class="banner">
+///         {"A new version is available. "}
+///         <button {onclick}>{"Reload"}</button>
+///       </div>
+///     }
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_service_worker(url: impl Into<AttrValue>) -> UseServiceWorkerHandle {
+    use_service_worker_with_options(url, UseServiceWorkerOptions::default())
+}
+
+/// Register a service worker, using options.
+///
+/// Registers the script at `url` as a
+/// [service worker](https://developer.mozilla.org/en-US/docs/Web/API/Service_Worker_API), and
+/// tracks the state of the registration: the worker being installed, waiting, and active. Once a
+/// new version of an already controlling worker is waiting, an update is available, which can
+/// be activated using `skip_waiting_and_reload` of the returned handle.
+///
+/// The registration stays in place when the component is unmounted, as it outlives the page
+/// anyway.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let worker = use_service_worker_with_options(
+///     "/app/sw.js",
+///     UseServiceWorkerOptions {
+///       scope: Some("/app/".into()),
+///       ..Default::default()
+///     },
+///   );
+///
+///   html!(
+///     if let Some(err) = &worker.error {
+///       <p class="error">{ format!("Offline support unavailable: {err}") }</p>
+///     }
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_service_worker_with_options(
+    url: impl Into<AttrValue>,
+    options: UseServiceWorkerOptions,
+) -> UseServiceWorkerHandle {
+    let state = use_state_eq(|| ServiceWorkerState {
+        supported: container().is_some(),
+        ..Default::default()
+    });
+    let registration = use_mut_ref(|| None::<Rc<Registration>>);
+    let reload = use_memo((), |()| Cell::new(false));
+
+    {
+        let state = state.clone();
+        let registration = registration.clone();
+        let reload = reload.clone();
+        use_effect_with((url.into(), options), move |(url, options)| {
+            let active = Rc::new(Cell::new(true));
+            let mut listener = None;
+
+            if let Some(container) = container() {
+                let register = web_sys::RegistrationOptions::new();
+                if let Some(scope) = &options.scope {
+                    register.set_scope(scope);
+                }
+                if options.module {
+                    register.set_type("module");
+                }
+                let promise = container.register_with_options(url, &register);
+
+                listener = Some({
+                    let registration = registration.clone();
+                    EventListener::new(&container, "controllerchange", move |_| {
+                        if reload.get() {
+                            let _ = gloo_utils::window().location().reload();
+                        } else if let Some(registration) = &*registration.borrow() {
+                            registration.update_state();
+                        }
+                    })
+                });
+
+                let active = active.clone();
+                let registration = registration.clone();
+                spawn_local(async move {
+                    let result = JsFuture::from(promise).await;
+                    if !active.get() {
+                        return;
+                    }
+                    match result {
+                        Ok(result) => {
+                            *registration.borrow_mut() =
+                                Some(Registration::new(result.unchecked_into(), state));
+                        }
+                        Err(err) => state.set(ServiceWorkerState {
+                            supported: true,
+                            error: Some(js_error(&err)),
+                            ..Default::default()
+                        }),
+                    }
+                });
+            }
+
+            move || {
+                active.set(false);
+                drop(listener);
+                registration.borrow_mut().take();
+            }
+        });
+    }
+
+    UseServiceWorkerHandle {
+        state,
+        registration,
+        reload,
+    }
+}
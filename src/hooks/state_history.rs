@@ -0,0 +1,225 @@
+//! Hooks for recording the changes of state
+
+use std::{ops::Deref, rc::Rc};
+use yew::prelude::*;
+
+/// A recorded change of a value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateChange<T> {
+    /// The value after the change.
+    pub value: T,
+    /// The time of the change, in milliseconds since the epoch.
+    pub timestamp: f64,
+}
+
+/// A bounded log of changes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateLog<T> {
+    changes: Vec<StateChange<T>>,
+    limit: usize,
+}
+
+impl<T> StateLog<T> {
+    /// Create a new log, keeping at most `limit` changes.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            changes: vec![],
+            limit,
+        }
+    }
+
+    /// The recorded changes, oldest first.
+    pub fn changes(&self) -> &[StateChange<T>] {
+        &self.changes
+    }
+}
+
+/// Actions of the [`StateLog`] reducer.
+pub enum StateLogAction<T> {
+    /// Record a change, dropping the oldest change if the log is full.
+    Record(StateChange<T>),
+    /// Drop all recorded changes.
+    Clear,
+}
+
+impl<T> Reducible for StateLog<T>
+where
+    T: Clone + PartialEq,
+{
+    type Action = StateLogAction<T>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut next = (*self).clone();
+        match action {
+            StateLogAction::Record(change) => {
+                if self.changes.last().map(|last| &last.value) == Some(&change.value) {
+                    return self;
+                }
+                next.changes.push(change);
+                let overflow = next.changes.len().saturating_sub(next.limit);
+                next.changes.drain(..overflow);
+            }
+            StateLogAction::Clear => {
+                if self.changes.is_empty() {
+                    return self;
+                }
+                next.changes.clear();
+            }
+        }
+        Rc::new(next)
+    }
+}
+
+/// State handle for the [`use_state_history`] hook.
+pub struct UseStateHistoryHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    state: UseStateHandle<T>,
+    log: UseReducerHandle<StateLog<T>>,
+}
+
+impl<T> UseStateHistoryHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// Set a new value of the wrapped state.
+    pub fn set(&self, value: T) {
+        self.state.set(value);
+    }
+
+    /// The recorded changes, oldest first, including the value when the hook was mounted.
+    pub fn history(&self) -> &[StateChange<T>] {
+        self.log.changes()
+    }
+
+    /// Set the value of a recorded change again.
+    ///
+    /// This is recorded as a new change, keeping the changes after it in the history.
+    pub fn revert_to(&self, index: usize) {
+        if let Some(change) = self.log.changes().get(index) {
+            self.state.set(change.value.clone());
+        }
+    }
+
+    /// Drop the recorded changes.
+    pub fn clear(&self) {
+        self.log.dispatch(StateLogAction::Clear);
+    }
+}
+
+impl<T> Clone for UseStateHistoryHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            log: self.log.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseStateHistoryHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && self.log == other.log
+    }
+}
+
+impl<T> Deref for UseStateHistoryHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// Record the changes of a state.
+///
+/// This wraps a state handle, and records each value it takes, along with the time of the change,
+/// keeping the latest `limit` changes. The handle dereferences to the current value, and provides
+/// the recorded changes, which is useful for debug or audit views, as well as reverting to a
+/// previous value. The limit is only read when the component is mounted.
+///
+/// Unlike [`use_undo_redo`](crate::hooks::use_undo_redo), reverting doesn't rewind the history,
+/// but records the value as a new change. Changes are recorded after they are rendered, so
+/// changes between two renders are combined.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Settings)]
+/// fn settings() -> Html {
+///   let volume = use_state_eq(|| 50u8);
+///   let volume = use_state_history(volume, 20);
+///   let onlouder = use_callback(volume.clone(), |_, volume| volume.set(volume.saturating_add(10)));
+///
+///   html!(
+///     <>
+///       <button onclick={onlouder}>{ format!("Volume: {}", *volume) }</button>
+///       <ol>
+///         { for volume.history().iter().enumerate().map(|(index, change)| {
+///           let volume = volume.clone();
+///           let onclick = Callback::from(move |_| volume.revert_to(index));
+///           html!(<li {onclick}>{ format!("{} at {}", change.value, change.timestamp) }</li>)
+///         }) }
+///       </ol>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_state_history<T>(state: UseStateHandle<T>, limit: usize) -> UseStateHistoryHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    let log = use_reducer(|| StateLog::new(limit));
+
+    {
+        let log = log.clone();
+        use_effect_with((*state).clone(), move |value| {
+            log.dispatch(StateLogAction::Record(StateChange {
+                value: value.clone(),
+                timestamp: js_sys::Date::now(),
+            }));
+        });
+    }
+
+    UseStateHistoryHandle { state, log }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn record(log: Rc<StateLog<u32>>, value: u32) -> Rc<StateLog<u32>> {
+        log.reduce(StateLogAction::Record(StateChange {
+            value,
+            timestamp: value as f64,
+        }))
+    }
+
+    #[test]
+    fn test_limit() {
+        let log = Rc::new(StateLog::new(3));
+        let log = record(log, 1);
+        let log = record(log, 2);
+
+        let unchanged = record(log.clone(), 2);
+        assert!(Rc::ptr_eq(&log, &unchanged));
+
+        let log = record(log, 3);
+        let log = record(log, 1);
+        let values = log.changes().iter().map(|c| c.value).collect::<Vec<_>>();
+        assert_eq!(values, vec![2, 3, 1]);
+    }
+}
@@ -0,0 +1,240 @@
+//! Hooks for finite state machines
+
+use std::{ops::Deref, rc::Rc};
+use yew::prelude::*;
+
+/// A finite state machine.
+///
+/// Implemented by the type of the states, usually an enum.
+pub trait StateMachine: Clone + PartialEq + 'static {
+    /// The events the machine reacts to.
+    type Event;
+
+    /// Get the state following this one for an event.
+    ///
+    /// Returns `None` if the event is not valid in this state, which rejects the event. Guards of
+    /// transitions can be implemented by returning `None` as well.
+    fn transition(&self, event: &Self::Event) -> Option<Self>;
+
+    /// Called after a transition was made to this state.
+    ///
+    /// This runs synchronously when the event is processed, before the component is re-rendered.
+    fn on_transition(&self, _previous: &Self, _event: &Self::Event) {}
+}
+
+/// A [`StateMachine`] as reducer.
+#[derive(PartialEq)]
+struct Machine<S>(S);
+
+impl<S> Reducible for Machine<S>
+where
+    S: StateMachine,
+{
+    type Action = S::Event;
+
+    fn reduce(self: Rc<Self>, event: Self::Action) -> Rc<Self> {
+        match self.0.transition(&event) {
+            Some(next) => {
+                next.on_transition(&self.0, &event);
+                Rc::new(Self(next))
+            }
+            None => self,
+        }
+    }
+}
+
+/// State handle for the [`use_state_machine`] hook.
+pub struct UseStateMachineHandle<S>
+where
+    S: StateMachine,
+{
+    state: UseReducerHandle<Machine<S>>,
+}
+
+impl<S> UseStateMachineHandle<S>
+where
+    S: StateMachine,
+{
+    /// Send an event to the machine.
+    ///
+    /// Events which are not valid in the state at the time they are processed are ignored.
+    pub fn send(&self, event: S::Event) {
+        self.state.dispatch(event);
+    }
+
+    /// Check if an event is valid in the current state.
+    pub fn can(&self, event: &S::Event) -> bool {
+        self.state.0.transition(event).is_some()
+    }
+}
+
+impl<S> Clone for UseStateMachineHandle<S>
+where
+    S: StateMachine,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<S> PartialEq for UseStateMachineHandle<S>
+where
+    S: StateMachine,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<S> Deref for UseStateMachineHandle<S>
+where
+    S: StateMachine,
+{
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state.0
+    }
+}
+
+/// Run a finite state machine.
+///
+/// The states, events, and transitions are defined by implementing [`StateMachine`]. The hook
+/// returns a handle, which dereferences to the current state, and allows sending events to the
+/// machine. Events which aren't valid in the current state are rejected, leaving the state
+/// unchanged.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// enum Upload {
+///   Idle,
+///   Uploading { progress: u8, retries: u8 },
+///   Done,
+///   Failed { retries: u8 },
+/// }
+///
+/// enum UploadEvent {
+///   Start,
+///   Progress(u8),
+///   Finish,
+///   Fail,
+/// }
+///
+/// impl StateMachine for Upload {
+///   type Event = UploadEvent;
+///
+///   fn transition(&self, event: &UploadEvent) -> Option<Self> {
+///     match (self, event) {
+///       (Self::Idle, UploadEvent::Start) => Some(Self::Uploading { progress: 0, retries: 0 }),
+///       // retry at most three times
+///       (Self::Failed { retries }, UploadEvent::Start) if *retries < 3 => {
+///         Some(Self::Uploading { progress: 0, retries: *retries })
+///       }
+///       (Self::Uploading { retries, .. }, UploadEvent::Progress(progress)) => {
+///         Some(Self::Uploading { progress: *progress, retries: *retries })
+///       }
+///       (Self::Uploading { .. }, UploadEvent::Finish) => Some(Self::Done),
+///       (Self::Uploading { retries, .. }, UploadEvent::Fail) => {
+///         Some(Self::Failed { retries: retries + 1 })
+///       }
+///       _ => None,
+///     }
+///   }
+/// }
+///
+/// #[function_component(Uploader)]
+/// fn uploader() -> Html {
+///   let upload = use_state_machine(|| Upload::Idle);
+///   let onclick = use_callback(upload.clone(), |_, upload| upload.send(UploadEvent::Start));
+///
+///   html!(
+///     <>
+///       <button {onclick} disabled={!upload.can(&UploadEvent::Start)}>{"Upload"}</button>
+///       if let Upload::Uploading { progress, .. } = &*upload {
+///         <progress max="100" value={progress.to_string()} />
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_state_machine<S, F>(init: F) -> UseStateMachineHandle<S>
+where
+    S: StateMachine,
+    F: FnOnce() -> S,
+{
+    let state = use_reducer(|| Machine(init()));
+    UseStateMachineHandle { state }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    thread_local! {
+        static OPENED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Door {
+        Open,
+        Closed,
+        Locked,
+    }
+
+    enum DoorEvent {
+        Open,
+        Close,
+        Lock,
+        Unlock,
+    }
+
+    impl StateMachine for Door {
+        type Event = DoorEvent;
+
+        fn transition(&self, event: &DoorEvent) -> Option<Self> {
+            match (self, event) {
+                (Self::Closed, DoorEvent::Open) => Some(Self::Open),
+                (Self::Open, DoorEvent::Close) => Some(Self::Closed),
+                (Self::Closed, DoorEvent::Lock) => Some(Self::Locked),
+                (Self::Locked, DoorEvent::Unlock) => Some(Self::Closed),
+                _ => None,
+            }
+        }
+
+        fn on_transition(&self, _previous: &Self, _event: &DoorEvent) {
+            if *self == Self::Open {
+                OPENED.with(|opened| opened.set(opened.get() + 1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_transitions() {
+        let state = Rc::new(Machine(Door::Closed));
+        let state = state.reduce(DoorEvent::Open);
+        assert_eq!(state.0, Door::Open);
+
+        // can't lock an open door
+        let rejected = state.clone().reduce(DoorEvent::Lock);
+        assert!(Rc::ptr_eq(&state, &rejected));
+
+        let state = state.reduce(DoorEvent::Close);
+        let state = state.reduce(DoorEvent::Lock);
+        let state = state.reduce(DoorEvent::Open);
+        assert_eq!(state.0, Door::Locked);
+
+        let state = state.reduce(DoorEvent::Unlock);
+        let state = state.reduce(DoorEvent::Open);
+        assert_eq!(state.0, Door::Open);
+        assert_eq!(OPENED.with(Cell::get), 2);
+    }
+}
@@ -0,0 +1,109 @@
+//! Hooks for sticky elements
+
+use crate::hooks::{use_intersection_observer, UseIntersectionOptions};
+use yew::prelude::*;
+
+/// Options for [`use_sticky_with_options`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UseStickyOptions {
+    /// The scrolling container, defaults to the browser viewport.
+    pub root: Option<NodeRef>,
+    /// The `top` offset of the sticky element, in pixels.
+    pub top: f64,
+}
+
+/// Detect if a sticky element is stuck.
+///
+/// This is [`use_sticky_with_options`] using the default options.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let sentinel = use_node_ref();
+///   let stuck = use_sticky(sentinel.clone());
+///
+///   html!(
+///     <>
+///       <div ref={sentinel} />
+///       <header
+///         class={classes!(stuck.then_some("shadow"))}
+///         style="position: sticky; top: 0;"
+///       >
+///         {"Title"}
+///       </header>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_sticky(sentinel: NodeRef) -> bool {
+    use_sticky_with_options(sentinel, UseStickyOptions::default())
+}
+
+/// Detect if a sticky element is stuck, using options.
+///
+/// A `position: sticky` element itself doesn't report if it currently is stuck. So this hook
+/// observes a sentinel, an empty element which must be placed right before the sticky element.
+/// Once the sentinel scrolled out of view at the top, the sticky element is considered stuck.
+///
+/// If the sticky element uses a `top` offset, the same offset must be provided in the options.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let container = use_node_ref();
+///   let sentinel = use_node_ref();
+///   let stuck = use_sticky_with_options(
+///     sentinel.clone(),
+///     UseStickyOptions {
+///       root: Some(container.clone()),
+///       top: 16f64,
+///     },
+///   );
+///
+///   html!(
+///     <div ref={container} style="overflow: auto; height: 200px;">
+///       <div ref={sentinel} />
+///       <nav style="position: sticky; top: 16px;">{ if stuck { "Pinned" } else { "Menu" } }</nav>
+///     </div>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_sticky_with_options(sentinel: NodeRef, options: UseStickyOptions) -> bool {
+    let stuck = use_state_eq(|| false);
+
+    let callback = use_callback(
+        stuck.clone(),
+        |entry: web_sys::IntersectionObserverEntry, stuck| {
+            let top = entry
+                .root_bounds()
+                .map(|bounds| bounds.top())
+                .unwrap_or_default();
+            // not visible because it is above the root, not below
+            stuck.set(!entry.is_intersecting() && entry.bounding_client_rect().top() < top);
+        },
+    );
+
+    use_intersection_observer(
+        sentinel,
+        UseIntersectionOptions {
+            root: options.root,
+            root_margin: Some(format!("{}px 0px 0px 0px", -options.top)),
+            threshold: vec![0f64],
+        },
+        callback,
+    );
+
+    *stuck
+}
@@ -0,0 +1,223 @@
+//! Hooks for measuring elapsed time
+
+use crate::hooks::use_interval;
+use std::{ops::Deref, rc::Rc, time::Duration};
+use yew::prelude::*;
+
+/// State of a stopwatch.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Stopwatch {
+    /// Milliseconds accumulated by previous runs
+    accumulated: f64,
+    /// Start timestamp of the current run
+    started: Option<f64>,
+    /// Timestamp of the last update
+    now: f64,
+    laps: Vec<Duration>,
+}
+
+impl Stopwatch {
+    /// The elapsed time, as of the last update.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed_at(self.now)
+    }
+
+    /// The recorded laps, as elapsed time at the moment the lap was taken.
+    pub fn laps(&self) -> &[Duration] {
+        &self.laps
+    }
+
+    /// Check if the stopwatch is currently running.
+    pub fn is_running(&self) -> bool {
+        self.started.is_some()
+    }
+
+    fn elapsed_at(&self, now: f64) -> Duration {
+        let running = self
+            .started
+            .map(|started| (now - started).max(0f64))
+            .unwrap_or_default();
+        Duration::from_secs_f64((self.accumulated + running) / 1000f64)
+    }
+}
+
+/// Actions of the [`Stopwatch`] reducer, carrying the current timestamp in milliseconds.
+pub enum StopwatchAction {
+    /// Start, or continue, measuring time.
+    Start(f64),
+    /// Stop measuring time, keeping the elapsed time.
+    Stop(f64),
+    /// Record the elapsed time as a lap.
+    Lap(f64),
+    /// Reset the elapsed time and laps, keeping a running stopwatch running.
+    Reset(f64),
+    /// Update the elapsed time of a running stopwatch.
+    Tick(f64),
+}
+
+impl Reducible for Stopwatch {
+    type Action = StopwatchAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut next = (*self).clone();
+        match action {
+            StopwatchAction::Start(now) => {
+                if next.started.is_none() {
+                    next.started = Some(now);
+                }
+                next.now = now;
+            }
+            StopwatchAction::Stop(now) => {
+                if let Some(started) = next.started.take() {
+                    next.accumulated += (now - started).max(0f64);
+                }
+                next.now = now;
+            }
+            StopwatchAction::Lap(now) => {
+                next.laps.push(next.elapsed_at(now));
+                next.now = now;
+            }
+            StopwatchAction::Reset(now) => {
+                next = Self {
+                    started: next.started.map(|_| now),
+                    now,
+                    ..Default::default()
+                };
+            }
+            StopwatchAction::Tick(now) => {
+                if next.started.is_none() {
+                    return self;
+                }
+                next.now = now;
+            }
+        }
+        Rc::new(next)
+    }
+}
+
+/// State handle for the [`use_stopwatch`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseStopwatchHandle {
+    state: UseReducerHandle<Stopwatch>,
+}
+
+impl UseStopwatchHandle {
+    /// Start (or continue) measuring time.
+    pub fn start(&self) {
+        self.state
+            .dispatch(StopwatchAction::Start(js_sys::Date::now()));
+    }
+
+    /// Stop measuring time, keeping the elapsed time.
+    pub fn stop(&self) {
+        self.state
+            .dispatch(StopwatchAction::Stop(js_sys::Date::now()));
+    }
+
+    /// Record the current elapsed time as a lap.
+    pub fn lap(&self) {
+        self.state
+            .dispatch(StopwatchAction::Lap(js_sys::Date::now()));
+    }
+
+    /// Reset the elapsed time and laps, keeping a running stopwatch running.
+    pub fn reset(&self) {
+        self.state
+            .dispatch(StopwatchAction::Reset(js_sys::Date::now()));
+    }
+}
+
+impl Deref for UseStopwatchHandle {
+    type Target = Stopwatch;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// A stopwatch, measuring elapsed time.
+///
+/// The stopwatch is initially stopped. While running, the component will be re-rendered every
+/// `resolution` milliseconds, updating the elapsed time.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let stopwatch = use_stopwatch(100);
+///
+///   let onclick = use_callback(stopwatch.clone(), |_, stopwatch| match stopwatch.is_running() {
+///     true => stopwatch.stop(),
+///     false => stopwatch.start(),
+///   });
+///
+///   html!(
+///     <button {onclick}>
+///       { format!("{:.1}s", stopwatch.elapsed().as_secs_f64()) }
+///     </button>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_stopwatch(resolution: u32) -> UseStopwatchHandle {
+    let state = use_reducer(Stopwatch::default);
+
+    {
+        let state = state.clone();
+        let running = state.is_running();
+        use_interval(
+            move || state.dispatch(StopwatchAction::Tick(js_sys::Date::now())),
+            running.then_some(resolution),
+        );
+    }
+
+    UseStopwatchHandle { state }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn reduce(state: Rc<Stopwatch>, action: StopwatchAction) -> Rc<Stopwatch> {
+        state.reduce(action)
+    }
+
+    #[test]
+    fn test_accumulate() {
+        let state = Rc::new(Stopwatch::default());
+        let state = reduce(state, StopwatchAction::Start(1_000f64));
+        let state = reduce(state, StopwatchAction::Tick(1_500f64));
+        assert_eq!(state.elapsed(), Duration::from_millis(500));
+
+        let state = reduce(state, StopwatchAction::Stop(2_000f64));
+        assert!(!state.is_running());
+        assert_eq!(state.elapsed(), Duration::from_millis(1_000));
+
+        // ticks are ignored while stopped
+        let state = reduce(state, StopwatchAction::Tick(5_000f64));
+        assert_eq!(state.elapsed(), Duration::from_millis(1_000));
+
+        let state = reduce(state, StopwatchAction::Start(10_000f64));
+        let state = reduce(state, StopwatchAction::Lap(10_250f64));
+        assert_eq!(state.laps(), &[Duration::from_millis(1_250)]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let state = Rc::new(Stopwatch::default());
+        let state = reduce(state, StopwatchAction::Start(1_000f64));
+        let state = reduce(state, StopwatchAction::Lap(2_000f64));
+        // between two ticks
+        let state = reduce(state, StopwatchAction::Reset(2_500f64));
+        assert!(state.is_running());
+        assert!(state.laps().is_empty());
+        assert_eq!(state.elapsed(), Duration::ZERO);
+
+        let state = reduce(state, StopwatchAction::Tick(3_000f64));
+        assert_eq!(state.elapsed(), Duration::from_millis(500));
+    }
+}
@@ -0,0 +1,194 @@
+//! Hooks for detecting swipe gestures
+
+use crate::hooks::use_event_listener;
+use yew::prelude::*;
+
+/// The direction of a swipe gesture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SwipeDirection {
+    /// Towards the left edge.
+    Left,
+    /// Towards the right edge.
+    Right,
+    /// Towards the top edge.
+    Up,
+    /// Towards the bottom edge.
+    Down,
+}
+
+/// A detected swipe gesture.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Swipe {
+    /// The main direction of the gesture.
+    pub direction: SwipeDirection,
+    /// Distance along the direction, in pixels.
+    pub distance: f64,
+    /// Velocity along the direction, in pixels per millisecond.
+    pub velocity: f64,
+    /// Duration of the gesture, in milliseconds.
+    pub duration: f64,
+}
+
+/// Options for [`use_swipe`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UseSwipeOptions {
+    /// The minimum distance, in pixels.
+    pub threshold: f64,
+    /// The minimum velocity, in pixels per millisecond.
+    pub min_velocity: f64,
+    /// Also detect swipes using a mouse.
+    pub mouse: bool,
+}
+
+impl Default for UseSwipeOptions {
+    fn default() -> Self {
+        Self {
+            threshold: 50f64,
+            min_velocity: 0.3,
+            mouse: false,
+        }
+    }
+}
+
+impl Swipe {
+    /// Detect a swipe from the movement of a pointer.
+    fn detect(dx: f64, dy: f64, duration: f64, options: &UseSwipeOptions) -> Option<Self> {
+        let (direction, distance) = if dx.abs() >= dy.abs() {
+            match dx < 0f64 {
+                true => (SwipeDirection::Left, -dx),
+                false => (SwipeDirection::Right, dx),
+            }
+        } else {
+            match dy < 0f64 {
+                true => (SwipeDirection::Up, -dy),
+                false => (SwipeDirection::Down, dy),
+            }
+        };
+
+        let velocity = distance / duration.max(1f64);
+
+        (distance >= options.threshold && velocity >= options.min_velocity).then_some(Self {
+            direction,
+            distance,
+            velocity,
+            duration,
+        })
+    }
+}
+
+/// Start of a gesture.
+struct Start {
+    pointer_id: i32,
+    x: f64,
+    y: f64,
+    time: f64,
+}
+
+/// Detect swipe gestures on an element.
+///
+/// A swipe is a fast movement of a pointer, from pressing it down on the element referenced by
+/// `node` until releasing it. The callback is called with the main direction of the movement, if
+/// the gesture exceeded the distance threshold and minimum velocity of the options.
+///
+/// By default, only touch and pen input is considered. The element should use the CSS
+/// `touch-action` property (e.g. `pan-y` for horizontal swipes), so that the browser doesn't
+/// cancel the gesture for scrolling.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Carousel)]
+/// fn carousel() -> Html {
+///   let node = use_node_ref();
+///   let index = use_state_eq(|| 0usize);
+///
+///   let onswipe = use_callback(index.clone(), |swipe: Swipe, index| match swipe.direction {
+///     SwipeDirection::Left => index.set(**index + 1),
+///     SwipeDirection::Right => index.set(index.saturating_sub(1)),
+///     _ => {}
+///   });
+///   use_swipe(node.clone(), UseSwipeOptions::default(), onswipe);
+///
+///   html!(<div ref={node} style="touch-action: pan-y;">{ format!("Slide {}", *index) }</div>)
+/// }
+/// ```
+#[hook]
+pub fn use_swipe(node: NodeRef, options: UseSwipeOptions, onswipe: Callback<Swipe>) {
+    let start = use_mut_ref(|| None::<Start>);
+
+    let onpointerdown = {
+        let start = start.clone();
+        use_callback(options, move |event: web_sys::PointerEvent, options| {
+            if !event.is_primary() || (!options.mouse && event.pointer_type() == "mouse") {
+                return;
+            }
+            *start.borrow_mut() = Some(Start {
+                pointer_id: event.pointer_id(),
+                x: event.client_x().into(),
+                y: event.client_y().into(),
+                time: event.time_stamp(),
+            });
+        })
+    };
+
+    let onpointerup = {
+        let start = start.clone();
+        use_callback(
+            (options, onswipe),
+            move |event: web_sys::PointerEvent, (options, onswipe)| {
+                let start = {
+                    let mut start = start.borrow_mut();
+                    match &*start {
+                        Some(current) if current.pointer_id == event.pointer_id() => start.take(),
+                        _ => None,
+                    }
+                };
+                let Some(start) = start else {
+                    return;
+                };
+
+                let dx = f64::from(event.client_x()) - start.x;
+                let dy = f64::from(event.client_y()) - start.y;
+                let duration = event.time_stamp() - start.time;
+
+                if let Some(swipe) = Swipe::detect(dx, dy, duration, options) {
+                    onswipe.emit(swipe);
+                }
+            },
+        )
+    };
+
+    let onpointercancel = use_callback((), move |_: web_sys::PointerEvent, ()| {
+        start.borrow_mut().take();
+    });
+
+    use_event_listener(node.clone(), "pointerdown", onpointerdown);
+    use_event_listener(node.clone(), "pointerup", onpointerup);
+    use_event_listener(node, "pointercancel", onpointercancel);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        let options = UseSwipeOptions::default();
+
+        let swipe = Swipe::detect(-120f64, 20f64, 200f64, &options).unwrap();
+        assert_eq!(swipe.direction, SwipeDirection::Left);
+        assert_eq!(swipe.distance, 120f64);
+        assert_eq!(swipe.velocity, 0.6);
+
+        let swipe = Swipe::detect(10f64, 80f64, 100f64, &options).unwrap();
+        assert_eq!(swipe.direction, SwipeDirection::Down);
+
+        // too short
+        assert_eq!(Swipe::detect(30f64, 0f64, 10f64, &options), None);
+        // too slow
+        assert_eq!(Swipe::detect(0f64, -200f64, 2_000f64, &options), None);
+    }
+}
@@ -0,0 +1,314 @@
+//! Hooks for sorting tables
+
+use std::{cmp::Ordering, collections::HashMap, hash::Hash, ops::Deref, rc::Rc};
+use yew::prelude::*;
+
+/// The direction of sorting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Smallest first.
+    Ascending,
+    /// Largest first.
+    Descending,
+}
+
+/// The column a table is sorted by.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sort<C> {
+    /// The column to sort by.
+    pub column: C,
+    /// The direction to sort in.
+    pub direction: SortDirection,
+}
+
+impl<C> Sort<C>
+where
+    C: PartialEq,
+{
+    /// The next sort when toggling a column.
+    ///
+    /// A column which isn't sorted yet gets sorted ascending, then descending, and then the table
+    /// is no longer sorted.
+    pub fn toggle(current: Option<Self>, column: C) -> Option<Self> {
+        match current {
+            Some(Self {
+                column: current,
+                direction: SortDirection::Ascending,
+            }) if current == column => Some(Self {
+                column,
+                direction: SortDirection::Descending,
+            }),
+            Some(Self {
+                column: current,
+                direction: SortDirection::Descending,
+            }) if current == column => None,
+            _ => Some(Self {
+                column,
+                direction: SortDirection::Ascending,
+            }),
+        }
+    }
+}
+
+/// A comparator for the rows of a table.
+pub type Comparator<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+
+/// Comparators for the sortable columns of a table.
+pub struct Comparators<C, T> {
+    comparators: HashMap<C, Comparator<T>>,
+}
+
+impl<C, T> Default for Comparators<C, T> {
+    fn default() -> Self {
+        Self {
+            comparators: HashMap::new(),
+        }
+    }
+}
+
+impl<C, T> Comparators<C, T>
+where
+    C: Eq + Hash,
+{
+    /// Create a set of comparators, without any columns.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the comparator of a column.
+    pub fn with<F>(mut self, column: C, f: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + 'static,
+    {
+        self.comparators.insert(column, Rc::new(f));
+        self
+    }
+
+    /// Add a comparator of a column, comparing a key of the rows.
+    pub fn with_key<K, F>(self, column: C, f: F) -> Self
+    where
+        K: Ord,
+        F: Fn(&T) -> K + 'static,
+    {
+        self.with(column, move |a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Check if a column has a comparator, and so can be sorted.
+    pub fn is_sortable(&self, column: &C) -> bool {
+        self.comparators.contains_key(column)
+    }
+
+    /// Sort the items, keeping the order of equal items.
+    ///
+    /// Items are left unchanged if there is no sort, or there is no comparator for the column.
+    pub fn sort(&self, sort: Option<&Sort<C>>, items: &mut [T]) {
+        let Some(sort) = sort else {
+            return;
+        };
+        let Some(comparator) = self.comparators.get(&sort.column) else {
+            return;
+        };
+        match sort.direction {
+            SortDirection::Ascending => items.sort_by(|a, b| comparator(a, b)),
+            SortDirection::Descending => items.sort_by(|a, b| comparator(b, a)),
+        }
+    }
+}
+
+/// State handle for the [`use_table_sort`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseTableSortHandle<C>
+where
+    C: Clone + PartialEq + 'static,
+{
+    state: UseStateHandle<Option<Sort<C>>>,
+}
+
+impl<C> UseTableSortHandle<C>
+where
+    C: Clone + PartialEq + 'static,
+{
+    /// Cycle the sort of a column through ascending, descending, and not sorted.
+    pub fn toggle(&self, column: C) {
+        self.state.set(Sort::toggle((*self.state).clone(), column));
+    }
+
+    /// Sort by a column, or don't sort at all.
+    pub fn set(&self, sort: Option<Sort<C>>) {
+        self.state.set(sort);
+    }
+
+    /// Stop sorting.
+    pub fn clear(&self) {
+        self.state.set(None);
+    }
+
+    /// The direction a column is sorted in, if the table is sorted by it.
+    pub fn direction(&self, column: &C) -> Option<SortDirection> {
+        self.state
+            .as_ref()
+            .filter(|sort| sort.column == *column)
+            .map(|sort| sort.direction)
+    }
+
+    /// The value of the `aria-sort` attribute for the header of a column.
+    pub fn aria_sort(&self, column: &C) -> &'static str {
+        match self.direction(column) {
+            Some(SortDirection::Ascending) => "ascending",
+            Some(SortDirection::Descending) => "descending",
+            None => "none",
+        }
+    }
+
+    /// Create a click handler for the header of a column, toggling its sort.
+    pub fn ontoggle(&self, column: C) -> Callback<MouseEvent> {
+        let handle = self.clone();
+        Callback::from(move |_| handle.toggle(column.clone()))
+    }
+
+    /// Sort the items, using the comparator of the sorted column.
+    pub fn sort<T>(&self, comparators: &Comparators<C, T>, items: &mut [T])
+    where
+        C: Eq + Hash,
+    {
+        comparators.sort(self.state.as_ref(), items);
+    }
+}
+
+impl<C> Deref for UseTableSortHandle<C>
+where
+    C: Clone + PartialEq + 'static,
+{
+    type Target = Option<Sort<C>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// Sort a table by one of its columns.
+///
+/// The handle dereferences to the current sort, if any. Toggling a column cycles it through
+/// ascending, descending, and not sorted. Sorting the rows is done using [`Comparators`], which
+/// provide a comparator for each sortable column.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct File {
+///   name: String,
+///   size: u64,
+/// }
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+/// enum Column {
+///   Name,
+///   Size,
+/// }
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   files: Vec<File>,
+/// }
+///
+/// #[function_component(Files)]
+/// fn files(props: &Props) -> Html {
+///   let sort = use_table_sort(|| None);
+///
+///   let files = use_memo(((*sort).clone(), props.files.clone()), |(sort, files)| {
+///     let comparators = Comparators::new()
+///       .with(Column::Name, |a: &File, b: &File| a.name.cmp(&b.name))
+///       .with_key(Column::Size, |file: &File| file.size);
+///     let mut files = files.clone();
+///     comparators.sort(sort.as_ref(), &mut files);
+///     files
+///   });
+///
+///   html!(
+///     <table>
+///       <tr>
+///         <th aria-sort={sort.aria_sort(&Column::Name)} onclick={sort.ontoggle(Column::Name)}>
+///           {"Name"}
+///         </th>
+///         <th aria-sort={sort.aria_sort(&Column::Size)} onclick={sort.ontoggle(Column::Size)}>
+///           {"Size"}
+///         </th>
+///       </tr>
+///       { for files.iter().map(|file| html!(
+///         <tr><td>{ &file.name }</td><td>{ file.size }</td></tr>
+///       )) }
+///     </table>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_table_sort<C, F>(init: F) -> UseTableSortHandle<C>
+where
+    C: Clone + PartialEq + 'static,
+    F: FnOnce() -> Option<Sort<C>>,
+{
+    let state = use_state_eq(init);
+    UseTableSortHandle { state }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_toggle() {
+        let sort = Sort::toggle(None, "name");
+        assert_eq!(
+            sort.as_ref().map(|s| s.direction),
+            Some(SortDirection::Ascending)
+        );
+        let sort = Sort::toggle(sort, "name");
+        assert_eq!(
+            sort.as_ref().map(|s| s.direction),
+            Some(SortDirection::Descending)
+        );
+        let other = Sort::toggle(sort.clone(), "size");
+        assert_eq!(
+            other,
+            Some(Sort {
+                column: "size",
+                direction: SortDirection::Ascending
+            })
+        );
+        assert_eq!(Sort::toggle(sort, "name"), None);
+    }
+
+    #[test]
+    fn test_sort() {
+        let comparators = Comparators::new()
+            .with_key("len", |s: &&str| s.len())
+            .with("name", |a: &&str, b: &&str| a.cmp(b));
+        let mut items = vec!["bb", "a", "ccc", "ab"];
+
+        let sort = Sort {
+            column: "len",
+            direction: SortDirection::Descending,
+        };
+        comparators.sort(Some(&sort), &mut items);
+        // equal items keep their order
+        assert_eq!(items, vec!["ccc", "bb", "ab", "a"]);
+
+        let sort = Sort {
+            column: "name",
+            direction: SortDirection::Ascending,
+        };
+        comparators.sort(Some(&sort), &mut items);
+        assert_eq!(items, vec!["a", "ab", "bb", "ccc"]);
+
+        let sort = Sort {
+            column: "unknown",
+            direction: SortDirection::Descending,
+        };
+        comparators.sort(Some(&sort), &mut items);
+        assert_eq!(items, vec!["a", "ab", "bb", "ccc"]);
+    }
+}
@@ -0,0 +1,194 @@
+//! Hooks for throttling values
+
+use gloo_timers::callback::Timeout;
+use std::{cell::RefCell, ops::Deref, rc::Rc};
+use yew::prelude::*;
+
+struct Throttle<T> {
+    last: f64,
+    pending: Option<T>,
+    timeout: Option<Timeout>,
+}
+
+impl<T> Default for Throttle<T> {
+    fn default() -> Self {
+        Self {
+            last: 0f64,
+            pending: None,
+            timeout: None,
+        }
+    }
+}
+
+/// Pass on a value to the setter, at most once every `millis` milliseconds.
+fn throttle<T>(inner: &Rc<RefCell<Throttle<T>>>, value: T, millis: u32, setter: &UseStateSetter<T>)
+where
+    T: 'static,
+{
+    let mut throttle = inner.borrow_mut();
+    let now = js_sys::Date::now();
+    let remaining = throttle.last + millis as f64 - now;
+
+    if remaining <= 0f64 {
+        throttle.last = now;
+        throttle.pending = None;
+        setter.set(value);
+    } else if throttle.pending.replace(value).is_none() {
+        // no trailing update scheduled yet
+        let inner = inner.clone();
+        let setter = setter.clone();
+        throttle.timeout = Some(Timeout::new(remaining.ceil() as u32, move || {
+            let pending = {
+                let mut throttle = inner.borrow_mut();
+                throttle.last = js_sys::Date::now();
+                throttle.pending.take()
+            };
+            if let Some(value) = pending {
+                setter.set(value);
+            }
+        }));
+    }
+}
+
+/// Cancel a pending update when unmounted.
+#[hook]
+fn use_throttle_cleanup<T>(inner: Rc<RefCell<Throttle<T>>>)
+where
+    T: 'static,
+{
+    use_effect_with((), move |()| {
+        move || {
+            inner.borrow_mut().timeout.take();
+        }
+    });
+}
+
+/// Throttle a value.
+///
+/// Returns a copy of the value, which gets updated at most once every `millis` milliseconds. The
+/// first change is passed on right away, further changes during the throttle period are combined
+/// and the latest value is passed on at the end of the period.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let position = use_state_eq(|| (0, 0));
+///   let onpointermove = use_callback(position.clone(), |e: PointerEvent, position| {
+///     position.set((e.client_x(), e.client_y()));
+///   });
+///
+///   // update the expensive view at most 10 times per second
+///   let (x, y) = use_throttle(*position, 100);
+///
+///   html!(<div {onpointermove}>{ format!("{x} / {y}") }</div>)
+/// }
+/// ```
+#[hook]
+pub fn use_throttle<T>(value: T, millis: u32) -> T
+where
+    T: Clone + PartialEq + 'static,
+{
+    let throttled = use_state_eq(|| value.clone());
+    let inner = use_mut_ref(Throttle::default);
+
+    {
+        let setter = throttled.setter();
+        let inner = inner.clone();
+        use_effect_with(value, move |value| {
+            throttle(&inner, value.clone(), millis, &setter);
+        });
+    }
+
+    use_throttle_cleanup(inner);
+
+    (*throttled).clone()
+}
+
+/// State handle for the [`use_throttled_state`] hook.
+#[derive(Clone)]
+pub struct UseThrottledStateHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    state: UseStateHandle<T>,
+    inner: Rc<RefCell<Throttle<T>>>,
+    millis: u32,
+}
+
+impl<T> UseThrottledStateHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// Set a new value, which is passed on once the throttle period allows it.
+    pub fn set(&self, value: T) {
+        throttle(&self.inner, value, self.millis, &self.state.setter());
+    }
+}
+
+impl<T> PartialEq for UseThrottledStateHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && Rc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl<T> Deref for UseThrottledStateHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// State, which is updated at most once per period.
+///
+/// Unlike [`use_throttle`], setting a value doesn't re-render the component by itself. Values are
+/// passed on like with [`use_throttle`], so the component re-renders at most once every `millis`
+/// milliseconds, no matter how often a value is set. This is useful for high-frequency data, like
+/// a stream of ticks feeding a chart.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Ticker)]
+/// fn ticker() -> Html {
+///   let price = use_throttled_state(|| 0f64, 250);
+///
+///   {
+///     let price = price.clone();
+///     use_interval(move || price.set(js_sys::Math::random() * 100f64), 10);
+///   }
+///
+///   html!(<span>{ format!("{:.2}", *price) }</span>)
+/// }
+/// ```
+#[hook]
+pub fn use_throttled_state<T, F>(init: F, millis: u32) -> UseThrottledStateHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+    F: FnOnce() -> T,
+{
+    let state = use_state_eq(init);
+    let inner = use_mut_ref(Throttle::default);
+
+    use_throttle_cleanup(inner.clone());
+
+    UseThrottledStateHandle {
+        state,
+        inner,
+        millis,
+    }
+}
@@ -0,0 +1,307 @@
+//! Hooks for working with timers
+
+use crate::hooks::use_latest;
+use gloo_timers::callback::{Interval, Timeout};
+use std::{
+    cell::RefCell,
+    ops::Deref,
+    rc::Rc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use yew::prelude::*;
+
+/// Run a function periodically.
+///
+/// The function will be called every `millis` milliseconds. Providing `None` or `0` pauses the
+/// interval. Changing the period restarts the interval, while changing the function does not. The
+/// latest function will always be called.
+///
+/// The interval is stopped when the component gets unmounted.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let counter = use_state_eq(|| 0);
+///   let running = use_state_eq(|| true);
+///
+///   {
+///     let counter = counter.clone();
+///     use_interval(move || counter.set(*counter + 1), running.then_some(1_000));
+///   }
+///
+///   let onclick = use_callback(running.clone(), |_, running| running.set(!**running));
+///
+///   html!(<button {onclick}>{ *counter }</button>)
+/// }
+/// ```
+#[hook]
+pub fn use_interval<F>(f: F, millis: impl Into<Option<u32>>)
+where
+    F: Fn() + 'static,
+{
+    let f = use_latest(f);
+    let millis = millis.into().filter(|millis| *millis > 0);
+
+    use_effect_with(millis, move |millis| {
+        let interval = millis.map(|millis| Interval::new(millis, move || (f.borrow())()));
+        move || drop(interval)
+    });
+}
+
+/// State handle for the [`use_timeout`] hook.
+#[derive(Clone)]
+pub struct UseTimeoutHandle {
+    timeout: Rc<RefCell<Option<Timeout>>>,
+    reset: Callback<()>,
+}
+
+impl UseTimeoutHandle {
+    /// Restart the timeout, as if it was just started.
+    ///
+    /// This also re-arms a timeout which was already fired or cancelled.
+    pub fn reset(&self) {
+        self.reset.emit(());
+    }
+
+    /// Cancel the timeout, if it is still pending.
+    pub fn cancel(&self) {
+        if let Some(timeout) = self.timeout.borrow_mut().take() {
+            timeout.cancel();
+        }
+    }
+}
+
+impl PartialEq for UseTimeoutHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.timeout, &other.timeout) && self.reset == other.reset
+    }
+}
+
+/// Run a function once, after a delay.
+///
+/// The timeout is started when the component gets mounted, and restarted when `millis` changes.
+/// Changing the function does not restart the timeout. The latest function will always be called.
+///
+/// The returned handle allows to [`reset`](UseTimeoutHandle::reset) or
+/// [`cancel`](UseTimeoutHandle::cancel) the timeout. A pending timeout is cancelled when the
+/// component gets unmounted.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   ondismiss: Callback<()>,
+/// }
+///
+/// #[function_component(Toast)]
+/// fn toast(props: &Props) -> Html {
+///   let timeout = {
+///     let ondismiss = props.ondismiss.clone();
+///     use_timeout(move || ondismiss.emit(()), 5_000)
+///   };
+///
+///   // keep the toast open while hovered
+///   let onmouseenter = use_callback(timeout.clone(), |_, timeout| timeout.cancel());
+///   let onmouseleave = use_callback(timeout.clone(), |_, timeout| timeout.reset());
+///
+///   html!(<div {onmouseenter} {onmouseleave}>{"Saved"}</div>)
+/// }
+/// ```
+#[hook]
+pub fn use_timeout<F>(f: F, millis: u32) -> UseTimeoutHandle
+where
+    F: Fn() + 'static,
+{
+    let f = use_latest(f);
+    let timeout = use_mut_ref(|| None::<Timeout>);
+
+    let reset = {
+        let timeout = timeout.clone();
+        use_callback(millis, move |(), millis| {
+            let f = f.clone();
+            // replacing an existing timeout will cancel it
+            *timeout.borrow_mut() = Some(Timeout::new(*millis, move || (f.borrow())()));
+        })
+    };
+
+    {
+        let timeout = timeout.clone();
+        use_effect_with(reset.clone(), move |reset| {
+            reset.emit(());
+            move || {
+                timeout.borrow_mut().take();
+            }
+        });
+    }
+
+    UseTimeoutHandle { timeout, reset }
+}
+
+/// The current time, as reported by the browser.
+///
+/// Unlike [`SystemTime::now`], this also works on `wasm32-unknown-unknown`.
+fn now() -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs_f64(js_sys::Date::now() / 1000f64)
+}
+
+/// The current time, updated periodically.
+///
+/// Returns the current time, updating it (and re-rendering the component) every `millis`
+/// milliseconds. This is useful for labels like "5 minutes ago", which need to be refreshed
+/// from time to time.
+///
+/// ## Example
+///
+/// ```rust
+/// use std::time::SystemTime;
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   timestamp: SystemTime,
+/// }
+///
+/// #[function_component(Example)]
+/// fn example(props: &Props) -> Html {
+///   // refresh every minute
+///   let now = use_now(60_000);
+///   let ago = now.duration_since(props.timestamp).unwrap_or_default();
+///
+///   html!(format!("{} minutes ago", ago.as_secs() / 60))
+/// }
+/// ```
+#[hook]
+pub fn use_now(millis: u32) -> SystemTime {
+    let state = use_state_eq(now);
+
+    {
+        let state = state.setter();
+        use_interval(move || state.set(now()), millis);
+    }
+
+    *state
+}
+
+/// State handle for the [`use_timeout_state`] hook.
+pub struct UseTimeoutStateHandle<T> {
+    state: UseStateHandle<T>,
+    initial: Rc<T>,
+    millis: u32,
+    timeout: Rc<RefCell<Option<Timeout>>>,
+}
+
+impl<T> UseTimeoutStateHandle<T>
+where
+    T: Clone + 'static,
+{
+    /// Set a new value, which will revert to the initial value after the delay.
+    ///
+    /// Setting a value while a revert is pending restarts the delay.
+    pub fn set(&self, value: T) {
+        self.state.set(value);
+
+        let state = self.state.setter();
+        let initial = self.initial.clone();
+        // replacing an existing timeout will cancel it
+        *self.timeout.borrow_mut() = Some(Timeout::new(self.millis, move || {
+            state.set((*initial).clone());
+        }));
+    }
+
+    /// Revert to the initial value right away, cancelling a pending revert.
+    pub fn reset(&self) {
+        self.timeout.borrow_mut().take();
+        self.state.set((*self.initial).clone());
+    }
+}
+
+impl<T> Clone for UseTimeoutStateHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            initial: self.initial.clone(),
+            millis: self.millis,
+            timeout: self.timeout.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseTimeoutStateHandle<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && self.millis == other.millis
+    }
+}
+
+impl<T> Deref for UseTimeoutStateHandle<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// State, which reverts to its initial value after a delay.
+///
+/// Every time a new value is set, the state will revert back to its initial value after `millis`
+/// milliseconds. A pending revert is cancelled when the component gets unmounted.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let copied = use_timeout_state(false, 2_000);
+///
+///   let onclick = use_callback(copied.clone(), |_, copied| {
+///     // copy something to the clipboard, then:
+///     copied.set(true);
+///   });
+///
+///   html!(
+///     <button {onclick}>
+///       { if *copied { "Copied!" } else { "Copy" } }
+///     </button>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_timeout_state<T>(initial: T, millis: u32) -> UseTimeoutStateHandle<T>
+where
+    T: Clone + 'static,
+{
+    let initial = use_memo((), |()| initial);
+    let state = use_state(|| (*initial).clone());
+    let timeout = use_mut_ref(|| None::<Timeout>);
+
+    {
+        let timeout = timeout.clone();
+        use_effect_with((), move |()| {
+            move || {
+                timeout.borrow_mut().take();
+            }
+        });
+    }
+
+    UseTimeoutStateHandle {
+        state,
+        initial,
+        millis,
+        timeout,
+    }
+}
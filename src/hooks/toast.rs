@@ -0,0 +1,264 @@
+//! Hooks for showing toast notifications
+//!
+//! A [`ToastProvider`] holds the queue of toasts. Any component below it can raise toasts using
+//! [`use_toasts`], while a single host component renders the visible toasts.
+
+use gloo_timers::callback::Timeout;
+use std::{cell::Cell, collections::HashMap, rc::Rc};
+use yew::prelude::*;
+
+/// The identifier of a toast.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ToastId(usize);
+
+/// The severity of a toast.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastLevel {
+    /// Informational.
+    #[default]
+    Info,
+    /// An operation succeeded.
+    Success,
+    /// Something might need attention.
+    Warning,
+    /// An operation failed.
+    Danger,
+}
+
+/// A toast notification.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Toast {
+    /// The title.
+    pub title: String,
+    /// The optional body, shown below the title.
+    pub body: Html,
+    /// The severity.
+    pub level: ToastLevel,
+    /// The time in milliseconds, after which the toast is dismissed. Defaults to the timeout of
+    /// the provider.
+    pub timeout: Option<u32>,
+    /// Keep the toast until it gets dismissed.
+    pub sticky: bool,
+}
+
+impl Toast {
+    /// Create an informational toast with a title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// The queue of toasts.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Toasts {
+    toasts: Vec<(ToastId, Toast)>,
+}
+
+/// Actions of the [`Toasts`] reducer.
+enum ToastAction {
+    /// Add a toast to the end of the queue.
+    Push(ToastId, Toast),
+    /// Remove a toast, visible or queued.
+    Dismiss(ToastId),
+    /// Remove all toasts.
+    Clear,
+}
+
+impl Reducible for Toasts {
+    type Action = ToastAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut toasts = self.toasts.clone();
+        match action {
+            ToastAction::Push(id, toast) => toasts.push((id, toast)),
+            ToastAction::Dismiss(id) => toasts.retain(|(toast, _)| *toast != id),
+            ToastAction::Clear => toasts.clear(),
+        }
+
+        match toasts.len() == self.toasts.len() {
+            true => self,
+            false => Rc::new(Self { toasts }),
+        }
+    }
+}
+
+/// State handle for the [`use_toasts`] hook.
+#[derive(Clone)]
+pub struct UseToastsHandle {
+    state: UseReducerHandle<Toasts>,
+    next_id: Rc<Cell<usize>>,
+    max_visible: usize,
+}
+
+impl PartialEq for UseToastsHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+            && Rc::ptr_eq(&self.next_id, &other.next_id)
+            && self.max_visible == other.max_visible
+    }
+}
+
+impl UseToastsHandle {
+    /// Raise a toast, which is shown once there is room for it.
+    pub fn push(&self, toast: Toast) -> ToastId {
+        let id = ToastId(self.next_id.replace(self.next_id.get() + 1));
+        self.state.dispatch(ToastAction::Push(id, toast));
+        id
+    }
+
+    /// Dismiss a toast, visible or queued.
+    pub fn dismiss(&self, id: ToastId) {
+        self.state.dispatch(ToastAction::Dismiss(id));
+    }
+
+    /// Dismiss all toasts, including the ones waiting to be shown.
+    pub fn clear(&self) {
+        self.state.dispatch(ToastAction::Clear);
+    }
+
+    /// The toasts to show, oldest first.
+    pub fn visible(&self) -> impl Iterator<Item = (ToastId, &Toast)> {
+        self.state
+            .toasts
+            .iter()
+            .take(self.max_visible)
+            .map(|(id, toast)| (*id, toast))
+    }
+
+    /// The number of toasts waiting to be shown.
+    pub fn queued(&self) -> usize {
+        self.state.toasts.len().saturating_sub(self.max_visible)
+    }
+}
+
+/// Properties of the [`ToastProvider`].
+#[derive(PartialEq, Properties)]
+pub struct ToastProviderProperties {
+    /// The default time in milliseconds, after which a toast is dismissed.
+    #[prop_or(8_000)]
+    pub timeout: u32,
+    /// The number of toasts shown at the same time. Further toasts are queued.
+    #[prop_or(3)]
+    pub max_visible: usize,
+    /// The components, which can raise toasts.
+    #[prop_or_default]
+    pub children: Html,
+}
+
+/// Provide the queue of toasts to the components below it.
+///
+/// Toasts are dismissed after their timeout, which starts once they become visible.
+#[function_component(ToastProvider)]
+pub fn toast_provider(props: &ToastProviderProperties) -> Html {
+    let state = use_reducer(Toasts::default);
+    let next_id = use_memo((), |()| Cell::new(0));
+    let timers = use_mut_ref(HashMap::<ToastId, Timeout>::new);
+
+    let timeouts = state
+        .toasts
+        .iter()
+        .take(props.max_visible)
+        .filter(|(_, toast)| !toast.sticky)
+        .map(|(id, toast)| (*id, toast.timeout.unwrap_or(props.timeout)))
+        .collect::<Vec<_>>();
+
+    {
+        let state = state.clone();
+        use_effect_with(timeouts, move |timeouts| {
+            let mut timers = timers.borrow_mut();
+            timers.retain(|id, _| timeouts.iter().any(|(visible, _)| visible == id));
+            for (id, millis) in timeouts {
+                let id = *id;
+                let state = state.clone();
+                timers.entry(id).or_insert_with(|| {
+                    Timeout::new(*millis, move || state.dispatch(ToastAction::Dismiss(id)))
+                });
+            }
+        });
+    }
+
+    let context = UseToastsHandle {
+        state,
+        next_id,
+        max_visible: props.max_visible,
+    };
+
+    html!(
+        <ContextProvider<UseToastsHandle> {context}>
+            { props.children.clone() }
+        </ContextProvider<UseToastsHandle>>
+    )
+}
+
+/// Raise and render toasts.
+///
+/// This requires a [`ToastProvider`] further up the component tree, and panics otherwise.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(ToastHost)]
+/// fn toast_host() -> Html {
+///   let toasts = use_toasts();
+///
+///   html!(
+///     <ul class="toasts">
+///       { for toasts.visible().map(|(id, toast)| {
+///         let toasts = toasts.clone();
+///         let onclick = Callback::from(move |_| toasts.dismiss(id));
+///         html!(<li>{ &toast.title }<button {onclick}>{"×"}</button></li>)
+///       }) }
+///     </ul>
+///   )
+/// }
+///
+/// #[function_component(Save)]
+/// fn save() -> Html {
+///   let toasts = use_toasts();
+///   let onclick = use_callback(toasts, |_, toasts| {
+///     toasts.push(Toast {
+///       level: ToastLevel::Success,
+///       ..Toast::new("Saved")
+///     });
+///   });
+///   html!(<button {onclick}>{"Save"}</button>)
+/// }
+///
+/// #[function_component(App)]
+/// fn app() -> Html {
+///   html!(
+///     <ToastProvider max_visible=5>
+///       <Save />
+///       <ToastHost />
+///     </ToastProvider>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_toasts() -> UseToastsHandle {
+    use_context::<UseToastsHandle>().expect("use_toasts requires a ToastProvider")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dismiss() {
+        let state = Rc::new(Toasts::default());
+        let state = state.reduce(ToastAction::Push(ToastId(0), Toast::new("foo")));
+        let state = state.reduce(ToastAction::Push(ToastId(1), Toast::new("bar")));
+
+        let unchanged = state.clone().reduce(ToastAction::Dismiss(ToastId(2)));
+        assert!(Rc::ptr_eq(&state, &unchanged));
+
+        let state = state.reduce(ToastAction::Dismiss(ToastId(0)));
+        assert_eq!(state.toasts, vec![(ToastId(1), Toast::new("bar"))]);
+    }
+}
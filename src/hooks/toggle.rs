@@ -0,0 +1,213 @@
+//! Hooks for toggling between values
+
+use std::{ops::Deref, rc::Rc};
+use yew::prelude::*;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+}
+
+enum ToggleAction {
+    Toggle,
+    Set(Side),
+}
+
+impl Reducible for Side {
+    type Action = ToggleAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let next = match (action, *self) {
+            (ToggleAction::Toggle, Side::Left) => Side::Right,
+            (ToggleAction::Toggle, Side::Right) => Side::Left,
+            (ToggleAction::Set(side), _) => side,
+        };
+        match next == *self {
+            true => self,
+            false => Rc::new(next),
+        }
+    }
+}
+
+/// State handle for the [`use_toggle`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseToggleHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    side: UseReducerHandle<Side>,
+    left: T,
+    right: T,
+}
+
+impl<T> UseToggleHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// Switch to the other value.
+    pub fn toggle(&self) {
+        self.side.dispatch(ToggleAction::Toggle);
+    }
+
+    /// Switch to the left value.
+    pub fn set_left(&self) {
+        self.side.dispatch(ToggleAction::Set(Side::Left));
+    }
+
+    /// Switch to the right value.
+    pub fn set_right(&self) {
+        self.side.dispatch(ToggleAction::Set(Side::Right));
+    }
+
+    /// Check if the left value is the current one.
+    pub fn is_left(&self) -> bool {
+        *self.side == Side::Left
+    }
+
+    /// Check if the right value is the current one.
+    pub fn is_right(&self) -> bool {
+        *self.side == Side::Right
+    }
+}
+
+impl<T> Deref for UseToggleHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        match *self.side {
+            Side::Left => &self.left,
+            Side::Right => &self.right,
+        }
+    }
+}
+
+/// Toggle between two values.
+///
+/// The handle dereferences to the current value, which initially is the left one. The values can
+/// change between renders, the handle keeps track of which side is selected.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum View {
+///   Grid,
+///   List,
+/// }
+///
+/// #[function_component(Gallery)]
+/// fn gallery() -> Html {
+///   let view = use_toggle(View::Grid, View::List);
+///   let onclick = use_callback(view.clone(), |_, view| view.toggle());
+///
+///   html!(
+///     <>
+///       <button {onclick}>{ if view.is_left() { "Show as list" } else { "Show as grid" } }</button>
+///       <div class={classes!(match *view { View::Grid => "grid", View::List => "list" })} />
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_toggle<T>(left: T, right: T) -> UseToggleHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    let side = use_reducer(|| Side::Left);
+    UseToggleHandle { side, left, right }
+}
+
+/// State handle for the [`use_boolean`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseBooleanHandle {
+    value: bool,
+    /// Set the value to `true`.
+    pub set_true: Callback<()>,
+    /// Set the value to `false`.
+    pub set_false: Callback<()>,
+    /// Invert the value.
+    pub toggle: Callback<()>,
+}
+
+impl Deref for UseBooleanHandle {
+    type Target = bool;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// Boolean state.
+///
+/// The handle dereferences to the current value, and provides callbacks for changing it. The
+/// callbacks don't change between renders, so they can be passed to other components without
+/// causing them to re-render. Like the methods of [`use_toggle`], they always operate on the
+/// latest value.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct DialogProps {
+///   onclose: Callback<()>,
+/// }
+///
+/// #[function_component(Dialog)]
+/// fn dialog(props: &DialogProps) -> Html {
+///   let onclick = props.onclose.reform(|_| ());
+///   html!(<dialog open=true><button {onclick}>{"Close"}</button></dialog>)
+/// }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let open = use_boolean(false);
+///
+///   html!(
+///     <>
+///       <button onclick={open.set_true.reform(|_| ())}>{"Open"}</button>
+///       if *open {
+///         <Dialog onclose={open.set_false.clone()} />
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_boolean(initial: bool) -> UseBooleanHandle {
+    let side = use_reducer(|| match initial {
+        false => Side::Left,
+        true => Side::Right,
+    });
+
+    let dispatcher = side.dispatcher();
+    let set_true = {
+        let dispatcher = dispatcher.clone();
+        use_callback((), move |(), ()| {
+            dispatcher.dispatch(ToggleAction::Set(Side::Right))
+        })
+    };
+    let set_false = {
+        let dispatcher = dispatcher.clone();
+        use_callback((), move |(), ()| {
+            dispatcher.dispatch(ToggleAction::Set(Side::Left))
+        })
+    };
+    let toggle = use_callback((), move |(), ()| dispatcher.dispatch(ToggleAction::Toggle));
+
+    UseBooleanHandle {
+        value: *side == Side::Right,
+        set_true,
+        set_false,
+        toggle,
+    }
+}
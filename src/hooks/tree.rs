@@ -0,0 +1,473 @@
+//! Hooks for tree views
+
+use std::{collections::HashSet, hash::Hash, ops::Deref, rc::Rc};
+use yew::prelude::*;
+
+/// The expanded nodes of a tree, identified by their keys.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeState<K>
+where
+    K: Eq + Hash,
+{
+    expanded: HashSet<K>,
+}
+
+impl<K> Default for TreeState<K>
+where
+    K: Eq + Hash,
+{
+    fn default() -> Self {
+        Self {
+            expanded: HashSet::new(),
+        }
+    }
+}
+
+impl<K> TreeState<K>
+where
+    K: Eq + Hash,
+{
+    /// The keys of the expanded nodes.
+    pub fn expanded(&self) -> &HashSet<K> {
+        &self.expanded
+    }
+}
+
+/// Actions of the [`TreeState`] reducer.
+pub enum TreeAction<K> {
+    /// Expand a collapsed node, or collapse an expanded one.
+    Toggle(K),
+    /// Expand the nodes.
+    Expand(Vec<K>),
+    /// Collapse a node.
+    Collapse(K),
+    /// Collapse all of the nodes.
+    CollapseAll,
+}
+
+impl<K> Reducible for TreeState<K>
+where
+    K: Clone + Eq + Hash,
+{
+    type Action = TreeAction<K>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut expanded = self.expanded.clone();
+        match action {
+            TreeAction::Toggle(key) => {
+                if !expanded.remove(&key) {
+                    expanded.insert(key);
+                }
+            }
+            TreeAction::Expand(keys) => expanded.extend(keys),
+            TreeAction::Collapse(key) => {
+                expanded.remove(&key);
+            }
+            TreeAction::CollapseAll => expanded.clear(),
+        }
+
+        match expanded == self.expanded {
+            true => self,
+            false => Rc::new(Self { expanded }),
+        }
+    }
+}
+
+/// State handle for the [`use_tree_state`] hook.
+pub struct UseTreeStateHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    state: UseReducerHandle<TreeState<K>>,
+}
+
+impl<K> UseTreeStateHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    /// Check if a node is expanded.
+    pub fn is_expanded(&self, key: &K) -> bool {
+        self.state.expanded.contains(key)
+    }
+
+    /// Expand a collapsed node, or collapse an expanded one.
+    pub fn toggle(&self, key: K) {
+        self.state.dispatch(TreeAction::Toggle(key));
+    }
+
+    /// Expand a node.
+    pub fn expand(&self, key: K) {
+        self.state.dispatch(TreeAction::Expand(vec![key]));
+    }
+
+    /// Collapse a node, keeping the state of its descendants.
+    pub fn collapse(&self, key: K) {
+        self.state.dispatch(TreeAction::Collapse(key));
+    }
+
+    /// Expand all of the nodes.
+    ///
+    /// As the tree isn't known to the hook, all nodes (with children) must be provided.
+    pub fn expand_all<I>(&self, keys: I)
+    where
+        I: IntoIterator<Item = K>,
+    {
+        self.state
+            .dispatch(TreeAction::Expand(keys.into_iter().collect()));
+    }
+
+    /// Collapse all of the nodes.
+    pub fn collapse_all(&self) {
+        self.state.dispatch(TreeAction::CollapseAll);
+    }
+
+    /// Expand the nodes along a path, like for revealing a node.
+    ///
+    /// The path is the list of the ancestors of a node, starting with the root.
+    pub fn expand_path<I>(&self, path: I)
+    where
+        I: IntoIterator<Item = K>,
+    {
+        self.expand_all(path);
+    }
+
+    /// Create a click handler, toggling a node.
+    pub fn ontoggle(&self, key: K) -> Callback<MouseEvent> {
+        let handle = self.clone();
+        Callback::from(move |_| handle.toggle(key.clone()))
+    }
+}
+
+impl<K> Clone for UseTreeStateHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<K> PartialEq for UseTreeStateHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<K> Deref for UseTreeStateHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    type Target = HashSet<K>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state.expanded
+    }
+}
+
+/// Track the expanded nodes of a tree view.
+///
+/// The nodes are identified by their keys. The handle dereferences to the keys of the expanded
+/// nodes, and provides methods for expanding and collapsing them.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(Clone, PartialEq)]
+/// struct Node {
+///   name: String,
+///   children: Vec<Node>,
+/// }
+///
+/// fn render(node: &Node, tree: &UseTreeStateHandle<String>) -> Html {
+///   let expanded = tree.is_expanded(&node.name);
+///   html!(
+///     <li key={node.name.clone()}>
+///       <span onclick={tree.ontoggle(node.name.clone())}>{ &node.name }</span>
+///       if expanded {
+///         <ul>{ for node.children.iter().map(|child| render(child, tree)) }</ul>
+///       }
+///     </li>
+///   )
+/// }
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   root: Node,
+/// }
+///
+/// #[function_component(Tree)]
+/// fn tree(props: &Props) -> Html {
+///   let tree = use_tree_state();
+///   let oncollapse = use_callback(tree.clone(), |_, tree| tree.collapse_all());
+///
+///   html!(
+///     <>
+///       <button onclick={oncollapse}>{"Collapse all"}</button>
+///       <ul>{ render(&props.root, &tree) }</ul>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_tree_state<K>() -> UseTreeStateHandle<K>
+where
+    K: Clone + Eq + Hash + 'static,
+{
+    let state = use_reducer(TreeState::default);
+    UseTreeStateHandle { state }
+}
+
+#[cfg(feature = "async")]
+pub use lazy::*;
+
+#[cfg(feature = "async")]
+mod lazy {
+    use super::UseTreeStateHandle;
+    use crate::hooks::{use_latest, UseAsyncState};
+    use std::{collections::HashMap, future::Future, hash::Hash, rc::Rc};
+    use wasm_bindgen_futures::spawn_local;
+    use yew::prelude::*;
+
+    struct Children<K, T, E> {
+        /// The state of each node, along with the ID of the request loading it.
+        nodes: HashMap<K, (usize, Rc<UseAsyncState<T, E>>)>,
+    }
+
+    enum ChildrenAction<K, T, E> {
+        Loading(K, usize),
+        Loaded(K, usize, Result<T, E>),
+        Invalidate(K),
+    }
+
+    impl<K, T, E> Reducible for Children<K, T, E>
+    where
+        K: Clone + Eq + Hash,
+    {
+        type Action = ChildrenAction<K, T, E>;
+
+        fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+            let mut nodes = self.nodes.clone();
+            match action {
+                ChildrenAction::Loading(key, request) => {
+                    nodes.insert(key, (request, Rc::new(UseAsyncState::Processing)));
+                }
+                // ignore results of invalidated, or reloaded, nodes
+                ChildrenAction::Loaded(key, request, result) => match nodes.get_mut(&key) {
+                    Some((current, state)) if *current == request && state.is_processing() => {
+                        *state = Rc::new(UseAsyncState::Ready(result));
+                    }
+                    _ => return self,
+                },
+                ChildrenAction::Invalidate(key) => {
+                    if nodes.remove(&key).is_none() {
+                        return self;
+                    }
+                }
+            }
+            Rc::new(Self { nodes })
+        }
+    }
+
+    /// State handle for the [`use_lazy_tree_children`] hook.
+    pub struct UseLazyTreeChildrenHandle<K, T, E>
+    where
+        K: Clone + Eq + Hash + 'static,
+        T: 'static,
+        E: 'static,
+    {
+        state: UseReducerHandle<Children<K, T, E>>,
+    }
+
+    impl<K, T, E> UseLazyTreeChildrenHandle<K, T, E>
+    where
+        K: Clone + Eq + Hash + 'static,
+        T: 'static,
+        E: 'static,
+    {
+        /// The state of loading the children of a node, once it was expanded.
+        pub fn get(&self, key: &K) -> Option<&UseAsyncState<T, E>> {
+            self.state.nodes.get(key).map(|(_, state)| &**state)
+        }
+
+        /// Drop the loaded children of a node, loading them again once it is expanded.
+        pub fn invalidate(&self, key: K) {
+            self.state.dispatch(ChildrenAction::Invalidate(key));
+        }
+    }
+
+    impl<K, T, E> Clone for UseLazyTreeChildrenHandle<K, T, E>
+    where
+        K: Clone + Eq + Hash + 'static,
+        T: 'static,
+        E: 'static,
+    {
+        fn clone(&self) -> Self {
+            Self {
+                state: self.state.clone(),
+            }
+        }
+    }
+
+    impl<K, T, E> PartialEq for UseLazyTreeChildrenHandle<K, T, E>
+    where
+        K: Clone + Eq + Hash + 'static,
+        T: 'static,
+        E: 'static,
+    {
+        fn eq(&self, other: &Self) -> bool {
+            self.state == other.state
+        }
+    }
+
+    impl<K, T, E> PartialEq for Children<K, T, E>
+    where
+        K: Eq + Hash,
+    {
+        fn eq(&self, other: &Self) -> bool {
+            self.nodes.len() == other.nodes.len()
+                && self.nodes.iter().all(|(key, (request, state))| {
+                    other.nodes.get(key).is_some_and(|(other_request, other)| {
+                        request == other_request && Rc::ptr_eq(state, other)
+                    })
+                })
+        }
+    }
+
+    /// Load the children of tree nodes, once they get expanded.
+    ///
+    /// When a node of the `tree` gets expanded for the first time, its children are loaded using
+    /// `load`. The results are kept when the node gets collapsed, until they get invalidated.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use yew::prelude::*;
+    /// use yew_more_hooks::prelude::*;
+    ///
+    /// async fn list(path: String) -> Result<Vec<String>, String> {
+    ///   // fetch the entries of the directory
+    ///   Ok(vec![format!("{path}/file")])
+    /// }
+    ///
+    /// #[function_component(Files)]
+    /// fn files() -> Html {
+    ///   let tree = use_tree_state::<String>();
+    ///   let children = use_lazy_tree_children(&tree, list);
+    ///   let root = "/".to_string();
+    ///
+    ///   html!(
+    ///     <ul>
+    ///       <li onclick={tree.ontoggle(root.clone())}>{ &root }</li>
+    ///       if tree.is_expanded(&root) {
+    ///         { match children.get(&root) {
+    ///           Some(UseAsyncState::Ready(Ok(entries))) => html!(
+    ///             <ul>{ for entries.iter().map(|entry| html!(<li>{ entry }</li>)) }</ul>
+    ///           ),
+    ///           Some(UseAsyncState::Ready(Err(err))) => html!({ err }),
+    ///           _ => html!({"Loading…"}),
+    ///         } }
+    ///       }
+    ///     </ul>
+    ///   )
+    /// }
+    /// ```
+    #[hook]
+    pub fn use_lazy_tree_children<K, T, E, F, Fut>(
+        tree: &UseTreeStateHandle<K>,
+        load: F,
+    ) -> UseLazyTreeChildrenHandle<K, T, E>
+    where
+        K: Clone + Eq + Hash + 'static,
+        T: 'static,
+        E: 'static,
+        F: Fn(K) -> Fut + 'static,
+        Fut: Future<Output = Result<T, E>> + 'static,
+    {
+        let state = use_reducer(|| Children {
+            nodes: HashMap::new(),
+        });
+        let load = use_latest(load);
+        let requests = use_mut_ref(|| 0usize);
+
+        {
+            let state = state.clone();
+            use_effect_with((tree.clone(), state.clone()), move |(tree, _)| {
+                for key in tree.iter() {
+                    if state.nodes.contains_key(key) {
+                        continue;
+                    }
+                    let request = {
+                        let mut requests = requests.borrow_mut();
+                        *requests += 1;
+                        *requests
+                    };
+                    state.dispatch(ChildrenAction::Loading(key.clone(), request));
+                    let future = (load.borrow())(key.clone());
+                    let state = state.clone();
+                    let key = key.clone();
+                    spawn_local(async move {
+                        state.dispatch(ChildrenAction::Loaded(key, request, future.await));
+                    });
+                }
+            });
+        }
+
+        UseLazyTreeChildrenHandle { state }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[test]
+        fn test_reload() {
+            let state = Rc::new(Children::<u32, &str, ()> {
+                nodes: HashMap::new(),
+            });
+            let state = state.reduce(ChildrenAction::Loading(1, 1));
+            // invalidated and reloaded, while still loading
+            let state = state.reduce(ChildrenAction::Invalidate(1));
+            let state = state.reduce(ChildrenAction::Loading(1, 2));
+
+            let unchanged = state
+                .clone()
+                .reduce(ChildrenAction::Loaded(1, 1, Ok("stale")));
+            assert!(Rc::ptr_eq(&state, &unchanged));
+
+            let state = state.reduce(ChildrenAction::Loaded(1, 2, Ok("current")));
+            assert!(matches!(
+                *state.nodes[&1].1,
+                UseAsyncState::Ready(Ok("current"))
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_expand() {
+        let state = Rc::new(TreeState::default());
+        let state = state.reduce(TreeAction::Expand(vec![1, 2, 3]));
+        let state = state.reduce(TreeAction::Toggle(2));
+        let mut expanded = state.expanded().iter().copied().collect::<Vec<_>>();
+        expanded.sort();
+        assert_eq!(expanded, vec![1, 3]);
+
+        let unchanged = state.clone().reduce(TreeAction::Collapse(2));
+        assert!(Rc::ptr_eq(&state, &unchanged));
+
+        let state = state.reduce(TreeAction::CollapseAll);
+        assert!(state.expanded().is_empty());
+    }
+}
@@ -0,0 +1,298 @@
+//! Hooks for state with undo and redo
+
+use std::{collections::VecDeque, ops::Deref, rc::Rc};
+use yew::prelude::*;
+
+/// A value, along with its history.
+#[derive(Clone, Debug, PartialEq)]
+pub struct History<T> {
+    past: VecDeque<T>,
+    present: T,
+    future: Vec<T>,
+    limit: Option<usize>,
+}
+
+impl<T> History<T> {
+    /// Create a new history, keeping at most `limit` past values.
+    pub fn new(present: T, limit: Option<usize>) -> Self {
+        Self {
+            past: VecDeque::new(),
+            present,
+            future: vec![],
+            limit,
+        }
+    }
+
+    /// The current value.
+    pub fn present(&self) -> &T {
+        &self.present
+    }
+
+    /// The past values, oldest first.
+    pub fn past(&self) -> impl Iterator<Item = &T> {
+        self.past.iter()
+    }
+
+    /// The undone values, which can be redone, next first.
+    pub fn future(&self) -> impl Iterator<Item = &T> {
+        self.future.iter().rev()
+    }
+
+    /// Check if there is a past value, which can be restored.
+    pub fn can_undo(&self) -> bool {
+        !self.past.is_empty()
+    }
+
+    /// Check if there is an undone value, which can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+}
+
+/// Actions of the [`History`] reducer.
+pub enum HistoryAction<T> {
+    /// Set a new value, recording the current one and dropping the undone values.
+    Set(T),
+    /// Restore the previous value.
+    Undo,
+    /// Restore the last undone value.
+    Redo,
+    /// Set a new value, dropping the history.
+    Reset(T),
+}
+
+impl<T> Reducible for History<T>
+where
+    T: Clone + PartialEq,
+{
+    type Action = HistoryAction<T>;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut next = (*self).clone();
+        match action {
+            HistoryAction::Set(value) => {
+                if value == next.present {
+                    return self;
+                }
+                next.past
+                    .push_back(std::mem::replace(&mut next.present, value));
+                next.future.clear();
+                if let Some(limit) = next.limit {
+                    while next.past.len() > limit {
+                        next.past.pop_front();
+                    }
+                }
+            }
+            HistoryAction::Undo => {
+                let Some(previous) = next.past.pop_back() else {
+                    return self;
+                };
+                next.future
+                    .push(std::mem::replace(&mut next.present, previous));
+            }
+            HistoryAction::Redo => {
+                let Some(undone) = next.future.pop() else {
+                    return self;
+                };
+                next.past
+                    .push_back(std::mem::replace(&mut next.present, undone));
+            }
+            HistoryAction::Reset(value) => {
+                next = Self::new(value, next.limit);
+            }
+        }
+        Rc::new(next)
+    }
+}
+
+/// Options for [`use_undo_redo_with_options`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UseUndoRedoOptions {
+    /// The maximum number of changes which can be undone, unlimited if `None`.
+    pub limit: Option<usize>,
+}
+
+/// State handle for the [`use_undo_redo`] hook.
+pub struct UseUndoRedoHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    state: UseReducerHandle<History<T>>,
+}
+
+impl<T> UseUndoRedoHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    /// Set a new value, which can be undone.
+    ///
+    /// Setting a value equal to the current one is not recorded.
+    pub fn set(&self, value: T) {
+        self.state.dispatch(HistoryAction::Set(value));
+    }
+
+    /// Restore the previous value, which can be redone.
+    pub fn undo(&self) {
+        self.state.dispatch(HistoryAction::Undo);
+    }
+
+    /// Restore the last undone value.
+    pub fn redo(&self) {
+        self.state.dispatch(HistoryAction::Redo);
+    }
+
+    /// Set a new value, clearing the history.
+    pub fn reset(&self, value: T) {
+        self.state.dispatch(HistoryAction::Reset(value));
+    }
+
+    /// Check if there is a past value, which can be restored.
+    pub fn can_undo(&self) -> bool {
+        self.state.can_undo()
+    }
+
+    /// Check if there is an undone value, which can be redone.
+    pub fn can_redo(&self) -> bool {
+        self.state.can_redo()
+    }
+
+    /// The full history.
+    pub fn history(&self) -> &History<T> {
+        &self.state
+    }
+}
+
+impl<T> Clone for UseUndoRedoHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for UseUndoRedoHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<T> Deref for UseUndoRedoHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+{
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.state.present()
+    }
+}
+
+/// State, which records changes to be undone and redone.
+///
+/// See [`use_undo_redo_with_options`] for limiting the history.
+#[hook]
+pub fn use_undo_redo<T, F>(init: F) -> UseUndoRedoHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+    F: FnOnce() -> T,
+{
+    use_undo_redo_with_options(init, UseUndoRedoOptions::default())
+}
+
+/// State, which records changes to be undone and redone, with options.
+///
+/// Works like [`use_state_eq`], but every change is recorded, and can be undone and redone
+/// afterward. Setting a new value drops all undone changes. When a limit is set, the oldest
+/// changes are forgotten once it is reached. The options are only evaluated on the first render.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Editor)]
+/// fn editor() -> Html {
+///   let text = use_undo_redo_with_options(String::new, UseUndoRedoOptions { limit: Some(100) });
+///
+///   let oninput = use_callback(text.clone(), |e: InputEvent, text| {
+///     text.set(e.target_unchecked_into::<web_sys::HtmlTextAreaElement>().value());
+///   });
+///   let onundo = use_callback(text.clone(), |_, text| text.undo());
+///   let onredo = use_callback(text.clone(), |_, text| text.redo());
+///
+///   html!(
+///     <>
+///       <textarea value={(*text).clone()} {oninput} />
+///       <button onclick={onundo} disabled={!text.can_undo()}>{"Undo"}</button>
+///       <button onclick={onredo} disabled={!text.can_redo()}>{"Redo"}</button>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_undo_redo_with_options<T, F>(
+    init: F,
+    options: UseUndoRedoOptions,
+) -> UseUndoRedoHandle<T>
+where
+    T: Clone + PartialEq + 'static,
+    F: FnOnce() -> T,
+{
+    let state = use_reducer(|| History::new(init(), options.limit));
+    UseUndoRedoHandle { state }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn reduce(state: Rc<History<u32>>, action: HistoryAction<u32>) -> Rc<History<u32>> {
+        state.reduce(action)
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let state = Rc::new(History::new(0, None));
+        let state = reduce(state, HistoryAction::Set(1));
+        let state = reduce(state, HistoryAction::Set(2));
+        assert_eq!(state.past().copied().collect::<Vec<_>>(), vec![0, 1]);
+
+        let state = reduce(state, HistoryAction::Undo);
+        let state = reduce(state, HistoryAction::Undo);
+        assert_eq!(*state.present(), 0);
+        assert!(!state.can_undo());
+        assert_eq!(state.future().copied().collect::<Vec<_>>(), vec![1, 2]);
+
+        let state = reduce(state, HistoryAction::Redo);
+        assert_eq!(*state.present(), 1);
+
+        // a new value drops the undone ones
+        let state = reduce(state, HistoryAction::Set(3));
+        assert!(!state.can_redo());
+        assert_eq!(state.past().copied().collect::<Vec<_>>(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_limit() {
+        let mut state = Rc::new(History::new(0, Some(2)));
+        for i in 1..=5 {
+            state = reduce(state, HistoryAction::Set(i));
+        }
+        assert_eq!(state.past().copied().collect::<Vec<_>>(), vec![3, 4]);
+
+        // unchanged values are not recorded
+        let state = reduce(state, HistoryAction::Set(5));
+        assert_eq!(state.past().copied().collect::<Vec<_>>(), vec![3, 4]);
+
+        let state = reduce(state, HistoryAction::Reset(10));
+        assert!(!state.can_undo());
+        assert_eq!(*state.present(), 10);
+    }
+}
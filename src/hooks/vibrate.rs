@@ -0,0 +1,120 @@
+//! Hooks for vibrating the device
+
+use std::{cell::Cell, rc::Rc};
+use yew::prelude::*;
+
+fn is_supported() -> bool {
+    js_sys::Reflect::has(&gloo_utils::window().navigator(), &"vibrate".into()).unwrap_or_default()
+}
+
+/// A vibration pattern.
+///
+/// Alternating durations of vibrating and pausing, in milliseconds.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VibratePattern(pub Vec<u32>);
+
+impl From<u32> for VibratePattern {
+    fn from(duration: u32) -> Self {
+        Self(vec![duration])
+    }
+}
+
+impl From<Vec<u32>> for VibratePattern {
+    fn from(pattern: Vec<u32>) -> Self {
+        Self(pattern)
+    }
+}
+
+impl<const N: usize> From<[u32; N]> for VibratePattern {
+    fn from(pattern: [u32; N]) -> Self {
+        Self(pattern.to_vec())
+    }
+}
+
+/// State handle for the [`use_vibrate`] hook.
+#[derive(Clone, Debug)]
+pub struct UseVibrateHandle {
+    supported: bool,
+    vibrating: Rc<Cell<bool>>,
+}
+
+impl UseVibrateHandle {
+    /// Check if the device supports vibrating.
+    pub fn is_supported(&self) -> bool {
+        self.supported
+    }
+
+    /// Vibrate using a pattern, replacing an ongoing vibration.
+    ///
+    /// Does nothing if vibrating is not supported.
+    pub fn vibrate(&self, pattern: impl Into<VibratePattern>) {
+        if !self.supported {
+            return;
+        }
+
+        let pattern = pattern.into();
+        let array = pattern
+            .0
+            .iter()
+            .map(|duration| wasm_bindgen::JsValue::from(*duration))
+            .collect::<js_sys::Array>();
+        let started = gloo_utils::window()
+            .navigator()
+            .vibrate_with_pattern(&array);
+        self.vibrating
+            .set(started && pattern.0.iter().any(|duration| *duration > 0));
+    }
+
+    /// Cancel an ongoing vibration.
+    pub fn cancel(&self) {
+        if self.supported && self.vibrating.replace(false) {
+            gloo_utils::window().navigator().vibrate_with_duration(0);
+        }
+    }
+}
+
+impl PartialEq for UseVibrateHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.vibrating, &other.vibrating)
+    }
+}
+
+/// Vibrate the device.
+///
+/// Returns a handle for triggering vibrations using the
+/// [Vibration API](https://developer.mozilla.org/en-US/docs/Web/API/Vibration_API). When the
+/// component is unmounted, an ongoing vibration is cancelled.
+///
+/// On devices without support for vibrating, like most desktops, triggering a vibration does
+/// nothing.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let vibrate = use_vibrate();
+///   let onclick = use_callback(vibrate.clone(), |_, vibrate| vibrate.vibrate([200, 100, 200]));
+///
+///   html!(<button {onclick}>{"Buzz"}</button>)
+/// }
+/// ```
+#[hook]
+pub fn use_vibrate() -> UseVibrateHandle {
+    let supported = *use_memo((), |()| is_supported());
+    let vibrating = use_memo((), |()| Cell::new(false));
+    let handle = UseVibrateHandle {
+        supported,
+        vibrating,
+    };
+
+    {
+        let handle = handle.clone();
+        use_effect_with((), move |()| move || handle.cancel());
+    }
+
+    handle
+}
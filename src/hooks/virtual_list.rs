@@ -0,0 +1,245 @@
+//! Hooks for rendering only the visible items of large lists
+
+use crate::hooks::{
+    use_event_listener_with_options, use_raf_state, use_resize_observer, ResizeEntry,
+    UseEventListenerOptions,
+};
+use std::ops::Range;
+use yew::prelude::*;
+
+/// The size of the items of a virtual list, along the scroll axis.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ItemSize {
+    /// All items have the same size, in pixels.
+    Fixed(f64),
+    /// Estimate the size of each item, by its index.
+    Estimate(Callback<usize, f64>),
+}
+
+impl From<f64> for ItemSize {
+    fn from(size: f64) -> Self {
+        Self::Fixed(size)
+    }
+}
+
+/// Options for [`use_virtual_list`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseVirtualListOptions {
+    /// The total number of items.
+    pub item_count: usize,
+    /// The size of the items.
+    pub item_size: ItemSize,
+    /// Number of additional items to render before and after the visible ones.
+    pub overscan: usize,
+}
+
+impl UseVirtualListOptions {
+    /// Create options for a number of items, with the default overscan.
+    pub fn new(item_count: usize, item_size: impl Into<ItemSize>) -> Self {
+        Self {
+            item_count,
+            item_size: item_size.into(),
+            overscan: 3,
+        }
+    }
+}
+
+/// The items of a virtual list to render.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VirtualList {
+    /// Indices of the items to render.
+    pub range: Range<usize>,
+    /// Space to add before the rendered items, in pixels.
+    pub padding_start: f64,
+    /// Space to add after the rendered items, in pixels.
+    pub padding_end: f64,
+    /// The size of all items, in pixels.
+    pub total_size: f64,
+}
+
+/// Positions of the items.
+enum Layout {
+    Fixed {
+        size: f64,
+        count: usize,
+    },
+    /// The start offsets of all items, plus the end of the last one.
+    Offsets(Vec<f64>),
+}
+
+impl Layout {
+    fn new(count: usize, item_size: &ItemSize) -> Self {
+        match item_size {
+            ItemSize::Fixed(size) => Self::Fixed { size: *size, count },
+            ItemSize::Estimate(estimate) => {
+                let mut offsets = Vec::with_capacity(count + 1);
+                let mut offset = 0f64;
+                offsets.push(offset);
+                for index in 0..count {
+                    offset += estimate.emit(index).max(0f64);
+                    offsets.push(offset);
+                }
+                Self::Offsets(offsets)
+            }
+        }
+    }
+
+    fn count(&self) -> usize {
+        match self {
+            Self::Fixed { count, .. } => *count,
+            Self::Offsets(offsets) => offsets.len() - 1,
+        }
+    }
+
+    fn offset(&self, index: usize) -> f64 {
+        match self {
+            Self::Fixed { size, .. } => *size * index as f64,
+            Self::Offsets(offsets) => offsets[index],
+        }
+    }
+
+    /// The index of the item at a position, which is the number of items ending before it.
+    fn index_at(&self, position: f64) -> usize {
+        let index = match self {
+            Self::Fixed { size, .. } if *size > 0f64 => (position.max(0f64) / *size) as usize,
+            Self::Fixed { .. } => 0,
+            Self::Offsets(offsets) => offsets[1..].partition_point(|end| *end <= position),
+        };
+        index.min(self.count())
+    }
+
+    fn compute(&self, scroll: f64, viewport: f64, overscan: usize) -> VirtualList {
+        let count = self.count();
+        let start = self.index_at(scroll).saturating_sub(overscan);
+        let end = (self.index_at(scroll + viewport) + 1 + overscan).min(count);
+        let total_size = self.offset(count);
+
+        VirtualList {
+            range: start..end,
+            padding_start: self.offset(start),
+            padding_end: total_size - self.offset(end),
+            total_size,
+        }
+    }
+}
+
+/// Render only the visible items of a large list.
+///
+/// Tracks the scroll position and size of the scrolling container referenced by `container`, and
+/// calculates which of the items are visible. The returned [`VirtualList`] contains the range of
+/// items to render, and the space to add before and after them, so that the scrollbar reflects
+/// the full list. Updates are limited to one per animation frame.
+///
+/// Items may have a fixed size, or an estimated size per item. Estimated sizes are only
+/// re-calculated when the options change.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let container = use_node_ref();
+///   let list = use_virtual_list(
+///     container.clone(),
+///     UseVirtualListOptions::new(100_000, 24f64),
+///   );
+///
+///   html!(
+///     <div ref={container} style="overflow-y: auto; height: 400px;">
+///       <div style={format!("padding-top: {}px; padding-bottom: {}px;", list.padding_start, list.padding_end)}>
+///         { for list.range.clone().map(|index| html!(
+///           <div key={index} style="height: 24px;">{ format!("Item {index}") }</div>
+///         )) }
+///       </div>
+///     </div>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_virtual_list(container: NodeRef, options: UseVirtualListOptions) -> VirtualList {
+    // scroll position and size of the viewport
+    let viewport = use_raf_state(|| (0f64, 0f64));
+
+    let layout = use_memo(
+        (options.item_count, options.item_size),
+        |(item_count, item_size)| Layout::new(*item_count, item_size),
+    );
+
+    let onscroll = use_callback(
+        (container.clone(), viewport.clone()),
+        |_: web_sys::Event, (container, viewport)| {
+            if let Some(element) = container.cast::<web_sys::Element>() {
+                viewport.set((element.scroll_top().into(), viewport.1));
+            }
+        },
+    );
+    let onresize = use_callback(
+        (container.clone(), viewport.clone()),
+        |entry: ResizeEntry, (container, viewport)| {
+            let scroll = container
+                .cast::<web_sys::Element>()
+                .map(|element| element.scroll_top().into())
+                .unwrap_or(viewport.0);
+            viewport.set((scroll, entry.content_rect.height));
+        },
+    );
+
+    use_event_listener_with_options(
+        container.clone(),
+        "scroll",
+        onscroll,
+        UseEventListenerOptions::enable_passive(),
+    );
+    use_resize_observer(container, onresize);
+
+    let (scroll, height) = *viewport;
+    layout.compute(scroll, height, options.overscan)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fixed() {
+        let layout = Layout::new(100, &ItemSize::Fixed(10f64));
+        let list = layout.compute(0f64, 50f64, 0);
+        assert_eq!(list.range, 0..6);
+        assert_eq!(list.padding_start, 0f64);
+        assert_eq!(list.padding_end, 940f64);
+        assert_eq!(list.total_size, 1_000f64);
+
+        let list = layout.compute(105f64, 50f64, 2);
+        assert_eq!(list.range, 8..18);
+        assert_eq!(list.padding_start, 80f64);
+        assert_eq!(list.padding_end, 820f64);
+
+        let list = layout.compute(990f64, 50f64, 2);
+        assert_eq!(list.range, 97..100);
+        assert_eq!(list.padding_end, 0f64);
+    }
+
+    #[test]
+    fn test_estimate() {
+        // alternating sizes of 10 and 30
+        let size = Callback::from(|index: usize| if index % 2 == 0 { 10f64 } else { 30f64 });
+        let layout = Layout::new(10, &ItemSize::Estimate(size));
+        let list = layout.compute(45f64, 40f64, 0);
+        // items 2 (40..50), 3 (50..80), 4 (80..90)
+        assert_eq!(list.range, 2..5);
+        assert_eq!(list.padding_start, 40f64);
+        assert_eq!(list.padding_end, 110f64);
+        assert_eq!(list.total_size, 200f64);
+    }
+
+    #[test]
+    fn test_empty() {
+        let layout = Layout::new(0, &ItemSize::Fixed(10f64));
+        let list = layout.compute(0f64, 100f64, 3);
+        assert_eq!(list.range, 0..0);
+        assert_eq!(list.total_size, 0f64);
+    }
+}
@@ -0,0 +1,63 @@
+//! Hooks for lazily loading content
+
+use crate::hooks::{
+    use_async, use_element_visibility_with_options, UseAsyncHandle, UseElementVisibilityOptions,
+};
+use std::future::Future;
+use yew::prelude::*;
+
+/// Run an async future, once an element became visible.
+///
+/// The future is run the first time the element referenced by `node` enters the viewport, and
+/// never again. This is useful for loading heavy content, like data for charts, only for sections
+/// the user actually scrolled to. The returned handle works like the one of [`use_async`], which
+/// also allows re-running the future manually.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Chart)]
+/// fn chart() -> Html {
+///   let node = use_node_ref();
+///   let data = use_visible_once(node.clone(), async move { fetch_data().await });
+///
+///   html!(
+///     <section ref={node}>
+///       { match &*data {
+///         UseAsyncState::Pending | UseAsyncState::Processing => html!("Loading…"),
+///         UseAsyncState::Ready(Ok(data)) => html!(format!("{} data points", data.len())),
+///         UseAsyncState::Ready(Err(err)) => html!(err),
+///       } }
+///     </section>
+///   )
+/// }
+///
+/// async fn fetch_data() -> Result<Vec<f64>, String> {
+///   Ok(vec![1.0, 2.0, 3.0])
+/// }
+/// ```
+#[hook]
+pub fn use_visible_once<F, T, E>(node: NodeRef, future: F) -> UseAsyncHandle<T, E>
+where
+    F: Future<Output = Result<T, E>> + 'static,
+    T: 'static,
+    E: 'static,
+{
+    let handle = use_async(future);
+    let visible =
+        use_element_visibility_with_options(node, UseElementVisibilityOptions::enable_once());
+
+    {
+        let handle = handle.clone();
+        use_effect_with(visible, move |visible| {
+            if *visible {
+                handle.run();
+            }
+        });
+    }
+
+    handle
+}
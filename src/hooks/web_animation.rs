@@ -0,0 +1,270 @@
+//! Hooks for the Web Animations API
+
+use crate::hooks::use_latest;
+use std::{borrow::Cow, cell::RefCell, rc::Rc};
+use wasm_bindgen::{prelude::*, JsCast};
+use yew::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(extends = js_sys::Object)]
+    #[derive(Clone, Debug)]
+    type Animation;
+
+    #[wasm_bindgen(extends = js_sys::Object)]
+    type Animatable;
+
+    #[wasm_bindgen(method)]
+    fn animate(this: &Animatable, keyframes: &js_sys::Array, options: &js_sys::Object)
+        -> Animation;
+
+    #[wasm_bindgen(method, catch)]
+    fn play(this: &Animation) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(method, catch)]
+    fn pause(this: &Animation) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(method, catch)]
+    fn reverse(this: &Animation) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(method, catch)]
+    fn finish(this: &Animation) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(method)]
+    fn cancel(this: &Animation);
+
+    #[wasm_bindgen(method, setter)]
+    fn set_onfinish(this: &Animation, value: Option<&js_sys::Function>);
+}
+
+fn set(target: &js_sys::Object, key: &str, value: impl Into<JsValue>) {
+    let _ = js_sys::Reflect::set(target, &key.into(), &value.into());
+}
+
+/// A keyframe of an animation.
+///
+/// Properties use the JavaScript (camel case) names of CSS properties, like `backgroundColor`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Keyframe {
+    properties: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    offset: Option<f64>,
+}
+
+impl Keyframe {
+    /// Create an empty keyframe.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a property to the keyframe.
+    pub fn with(
+        mut self,
+        property: impl Into<Cow<'static, str>>,
+        value: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.properties.push((property.into(), value.into()));
+        self
+    }
+
+    /// Set the offset of the keyframe, in the range of `0..=1`.
+    pub fn offset(mut self, offset: f64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    fn to_js(&self) -> js_sys::Object {
+        let result = js_sys::Object::new();
+        for (property, value) in &self.properties {
+            set(&result, property, value.as_ref());
+        }
+        if let Some(offset) = self.offset {
+            set(&result, "offset", offset);
+        }
+        result
+    }
+}
+
+/// Options for [`use_web_animation`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseWebAnimationOptions {
+    /// Duration of one iteration, in milliseconds.
+    pub duration: f64,
+    /// Delay before starting, in milliseconds.
+    pub delay: f64,
+    /// Number of iterations, may be [`f64::INFINITY`].
+    pub iterations: f64,
+    /// The easing function, like `ease-in-out`.
+    pub easing: Cow<'static, str>,
+    /// The fill mode, like `forwards`.
+    pub fill: Cow<'static, str>,
+    /// The direction, like `alternate`.
+    pub direction: Cow<'static, str>,
+    /// Start playing once the animation got created.
+    pub autoplay: bool,
+}
+
+impl Default for UseWebAnimationOptions {
+    fn default() -> Self {
+        Self {
+            duration: 300f64,
+            delay: 0f64,
+            iterations: 1f64,
+            easing: "linear".into(),
+            fill: "auto".into(),
+            direction: "normal".into(),
+            autoplay: true,
+        }
+    }
+}
+
+impl UseWebAnimationOptions {
+    fn to_js(&self) -> js_sys::Object {
+        let result = js_sys::Object::new();
+        set(&result, "duration", self.duration);
+        set(&result, "delay", self.delay);
+        set(&result, "iterations", self.iterations);
+        set(&result, "easing", self.easing.as_ref());
+        set(&result, "fill", self.fill.as_ref());
+        set(&result, "direction", self.direction.as_ref());
+        result
+    }
+}
+
+/// State handle for the [`use_web_animation`] hook.
+#[derive(Clone)]
+pub struct UseWebAnimationHandle {
+    animation: Rc<RefCell<Option<Animation>>>,
+}
+
+impl UseWebAnimationHandle {
+    fn with(&self, f: impl FnOnce(&Animation)) {
+        if let Some(animation) = &*self.animation.borrow() {
+            f(animation);
+        }
+    }
+
+    /// Start or resume playing the animation.
+    pub fn play(&self) {
+        self.with(|animation| {
+            let _ = animation.play();
+        });
+    }
+
+    /// Pause the animation.
+    pub fn pause(&self) {
+        self.with(|animation| {
+            let _ = animation.pause();
+        });
+    }
+
+    /// Reverse the playback direction, playing the animation.
+    pub fn reverse(&self) {
+        self.with(|animation| {
+            let _ = animation.reverse();
+        });
+    }
+
+    /// Seek to the end of the animation.
+    pub fn finish(&self) {
+        self.with(|animation| {
+            let _ = animation.finish();
+        });
+    }
+
+    /// Cancel the animation, removing its effects.
+    pub fn cancel(&self) {
+        self.with(Animation::cancel);
+    }
+}
+
+impl PartialEq for UseWebAnimationHandle {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.animation, &other.animation)
+    }
+}
+
+/// Animate an element, using the Web Animations API.
+///
+/// This creates an animation for the element referenced by `node`, using
+/// [`Element.animate()`](https://developer.mozilla.org/en-US/docs/Web/API/Element/animate).
+/// The animation is re-created when the keyframes or options change, and cancelled when the
+/// component gets unmounted. The latest `onfinish` callback is called when the animation finishes.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let node = use_node_ref();
+///   let animation = use_web_animation(
+///     node.clone(),
+///     vec![
+///       Keyframe::new().with("opacity", "0"),
+///       Keyframe::new().with("opacity", "1"),
+///     ],
+///     UseWebAnimationOptions {
+///       duration: 1_000f64,
+///       autoplay: false,
+///       ..Default::default()
+///     },
+///     None,
+///   );
+///
+///   let onclick = use_callback(animation.clone(), |_, animation| animation.play());
+///
+///   html!(<div ref={node} {onclick}>{"Fade in"}</div>)
+/// }
+/// ```
+#[hook]
+pub fn use_web_animation(
+    node: NodeRef,
+    keyframes: Vec<Keyframe>,
+    options: UseWebAnimationOptions,
+    onfinish: Option<Callback<()>>,
+) -> UseWebAnimationHandle {
+    let onfinish = use_latest(onfinish);
+    let animation = use_mut_ref(|| None::<Animation>);
+
+    {
+        let animation = animation.clone();
+        use_effect_with(
+            (node, keyframes, options),
+            move |(node, keyframes, options)| {
+                let closure = node.cast::<web_sys::Element>().map(|element| {
+                    let keyframes: js_sys::Array = keyframes.iter().map(Keyframe::to_js).collect();
+                    let next = element
+                        .unchecked_ref::<Animatable>()
+                        .animate(&keyframes, &options.to_js());
+
+                    let closure = Closure::<dyn Fn()>::new(move || {
+                        let onfinish = onfinish.borrow().clone();
+                        if let Some(onfinish) = onfinish {
+                            onfinish.emit(());
+                        }
+                    });
+                    next.set_onfinish(Some(closure.as_ref().unchecked_ref()));
+
+                    if !options.autoplay {
+                        let _ = next.pause();
+                    }
+
+                    *animation.borrow_mut() = Some(next);
+                    closure
+                });
+
+                move || {
+                    if let Some(animation) = animation.borrow_mut().take() {
+                        animation.set_onfinish(None);
+                        animation.cancel();
+                    }
+                    drop(closure);
+                }
+            },
+        );
+    }
+
+    UseWebAnimationHandle { animation }
+}
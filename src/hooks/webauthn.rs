@@ -0,0 +1,442 @@
+//! Hooks for authenticating using WebAuthn
+
+use crate::hooks::UseAsyncState;
+use std::{
+    cell::RefCell,
+    fmt::{Display, Formatter},
+    ops::Deref,
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::PublicKeyCredentialType;
+use yew::prelude::*;
+
+/// An error creating or getting a credential.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WebAuthnError {
+    /// WebAuthn is not supported by the browser.
+    Unsupported,
+    /// The user cancelled the operation, it timed out, or the browser didn't allow it.
+    NotAllowed,
+    /// The authenticator already contains one of the excluded credentials.
+    InvalidState,
+    /// The operation was aborted.
+    Aborted,
+    /// The operation failed for another reason.
+    Failed(String),
+}
+
+impl Display for WebAuthnError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported => f.write_str("WebAuthn is not supported"),
+            Self::NotAllowed => f.write_str("The operation was cancelled or not allowed"),
+            Self::InvalidState => f.write_str("The authenticator is already registered"),
+            Self::Aborted => f.write_str("The operation was aborted"),
+            Self::Failed(msg) => write!(f, "WebAuthn failed: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for WebAuthnError {}
+
+impl From<JsValue> for WebAuthnError {
+    fn from(err: JsValue) -> Self {
+        match err.dyn_ref::<web_sys::DomException>() {
+            Some(err) => match err.name().as_str() {
+                "NotAllowedError" => Self::NotAllowed,
+                "InvalidStateError" => Self::InvalidState,
+                "AbortError" => Self::Aborted,
+                _ => Self::Failed(err.message()),
+            },
+            None => Self::Failed(format!("{err:?}")),
+        }
+    }
+}
+
+/// The relying party, the site the credential is for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RelyingParty {
+    /// The domain, defaults to the domain of the page.
+    pub id: Option<String>,
+    /// The human readable name of the site.
+    pub name: String,
+}
+
+impl RelyingParty {
+    /// Create a relying party for the domain of the page.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            name: name.into(),
+        }
+    }
+}
+
+/// The user a credential is created for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebAuthnUser {
+    /// An opaque handle of the user, which must not contain personal information.
+    pub id: Vec<u8>,
+    /// The name of the account, like the username or email address.
+    pub name: String,
+    /// The human readable name of the user.
+    pub display_name: String,
+}
+
+/// Options for creating a credential.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateCredentialOptions {
+    /// The challenge, generated by the server.
+    pub challenge: Vec<u8>,
+    /// The relying party the credential is for.
+    pub rp: RelyingParty,
+    /// The user the credential is created for.
+    pub user: WebAuthnUser,
+    /// The COSE identifiers of the supported algorithms, in order of preference.
+    pub algorithms: Vec<i32>,
+    /// The IDs of credentials the user already registered.
+    pub exclude_credentials: Vec<Vec<u8>>,
+    /// The timeout, in milliseconds.
+    pub timeout: Option<u32>,
+}
+
+impl CreateCredentialOptions {
+    /// Create options, supporting ES256 and RS256.
+    pub fn new(challenge: impl Into<Vec<u8>>, rp: RelyingParty, user: WebAuthnUser) -> Self {
+        Self {
+            challenge: challenge.into(),
+            rp,
+            user,
+            algorithms: vec![-7, -257],
+            exclude_credentials: vec![],
+            timeout: None,
+        }
+    }
+}
+
+/// Options for getting a credential.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GetCredentialOptions {
+    /// The challenge, generated by the server.
+    pub challenge: Vec<u8>,
+    /// The domain of the relying party, defaults to the domain of the page.
+    pub rp_id: Option<String>,
+    /// The IDs of the credentials allowed, any discoverable credential if empty.
+    pub allow_credentials: Vec<Vec<u8>>,
+    /// The timeout, in milliseconds.
+    pub timeout: Option<u32>,
+}
+
+impl GetCredentialOptions {
+    /// Create options, allowing any discoverable credential.
+    pub fn new(challenge: impl Into<Vec<u8>>) -> Self {
+        Self {
+            challenge: challenge.into(),
+            rp_id: None,
+            allow_credentials: vec![],
+            timeout: None,
+        }
+    }
+}
+
+/// The response of the authenticator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CredentialResponse {
+    /// The response of creating a credential.
+    Attestation {
+        /// The CBOR encoded attestation object.
+        attestation_object: Vec<u8>,
+    },
+    /// The response of getting a credential.
+    Assertion {
+        /// The data of the authenticator, which is signed.
+        authenticator_data: Vec<u8>,
+        /// The signature of the authenticator data and the hash of the client data.
+        signature: Vec<u8>,
+        /// The handle of the user, as provided when creating the credential.
+        user_handle: Option<Vec<u8>>,
+    },
+}
+
+/// A credential created or returned by the authenticator, to be verified by the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebAuthnCredential {
+    /// The base64url encoded ID.
+    pub id: String,
+    /// The raw ID.
+    pub raw_id: Vec<u8>,
+    /// The JSON encoded client data, containing the challenge.
+    pub client_data_json: Vec<u8>,
+    /// The response of the authenticator.
+    pub response: CredentialResponse,
+}
+
+fn bytes(buffer: &js_sys::ArrayBuffer) -> Vec<u8> {
+    js_sys::Uint8Array::new(buffer).to_vec()
+}
+
+impl From<web_sys::PublicKeyCredential> for WebAuthnCredential {
+    fn from(credential: web_sys::PublicKeyCredential) -> Self {
+        let response = credential.response();
+        let client_data_json = bytes(&response.client_data_json());
+        let response = match response.dyn_into::<web_sys::AuthenticatorAssertionResponse>() {
+            Ok(response) => CredentialResponse::Assertion {
+                authenticator_data: bytes(&response.authenticator_data()),
+                signature: bytes(&response.signature()),
+                user_handle: response.user_handle().as_ref().map(bytes),
+            },
+            Err(response) => CredentialResponse::Attestation {
+                attestation_object: bytes(
+                    &response
+                        .unchecked_into::<web_sys::AuthenticatorAttestationResponse>()
+                        .attestation_object(),
+                ),
+            },
+        };
+        Self {
+            id: credential.id(),
+            raw_id: bytes(&credential.raw_id()),
+            client_data_json,
+            response,
+        }
+    }
+}
+
+fn descriptors(ids: &[Vec<u8>]) -> js_sys::Array {
+    ids.iter()
+        .map(|id| {
+            web_sys::PublicKeyCredentialDescriptor::new_with_u8_array(
+                &js_sys::Uint8Array::from(id.as_slice()),
+                PublicKeyCredentialType::PublicKey,
+            )
+        })
+        .collect()
+}
+
+fn create(
+    options: &CreateCredentialOptions,
+    signal: &web_sys::AbortSignal,
+) -> Result<js_sys::Promise, JsValue> {
+    let rp = web_sys::PublicKeyCredentialRpEntity::new(&options.rp.name);
+    if let Some(id) = &options.rp.id {
+        rp.set_id(id);
+    }
+    let user = web_sys::PublicKeyCredentialUserEntity::new_with_u8_array(
+        &options.user.name,
+        &options.user.display_name,
+        &js_sys::Uint8Array::from(options.user.id.as_slice()),
+    );
+    let params = options
+        .algorithms
+        .iter()
+        .map(|alg| {
+            web_sys::PublicKeyCredentialParameters::new(*alg, PublicKeyCredentialType::PublicKey)
+        })
+        .collect::<js_sys::Array>();
+
+    let public_key = web_sys::PublicKeyCredentialCreationOptions::new_with_u8_array(
+        &js_sys::Uint8Array::from(options.challenge.as_slice()),
+        &params,
+        &rp,
+        &user,
+    );
+    public_key.set_exclude_credentials(&descriptors(&options.exclude_credentials));
+    if let Some(timeout) = options.timeout {
+        public_key.set_timeout(timeout);
+    }
+
+    let request = web_sys::CredentialCreationOptions::new();
+    request.set_public_key(&public_key);
+    request.set_signal(signal);
+    gloo_utils::window()
+        .navigator()
+        .credentials()
+        .create_with_options(&request)
+}
+
+fn get(
+    options: &GetCredentialOptions,
+    signal: &web_sys::AbortSignal,
+) -> Result<js_sys::Promise, JsValue> {
+    let public_key = web_sys::PublicKeyCredentialRequestOptions::new_with_u8_array(
+        &js_sys::Uint8Array::from(options.challenge.as_slice()),
+    );
+    if let Some(rp_id) = &options.rp_id {
+        public_key.set_rp_id(rp_id);
+    }
+    public_key.set_allow_credentials(&descriptors(&options.allow_credentials));
+    if let Some(timeout) = options.timeout {
+        public_key.set_timeout(timeout);
+    }
+
+    let request = web_sys::CredentialRequestOptions::new();
+    request.set_public_key(&public_key);
+    request.set_signal(signal);
+    gloo_utils::window()
+        .navigator()
+        .credentials()
+        .get_with_options(&request)
+}
+
+fn is_supported() -> bool {
+    js_sys::Reflect::has(&gloo_utils::window(), &"PublicKeyCredential".into()).unwrap_or_default()
+}
+
+/// State handle for the [`use_webauthn`] hook.
+#[derive(Clone)]
+pub struct UseWebAuthnHandle {
+    state: UseStateHandle<UseAsyncState<WebAuthnCredential, WebAuthnError>>,
+    controller: Rc<RefCell<Option<web_sys::AbortController>>>,
+    supported: bool,
+}
+
+impl UseWebAuthnHandle {
+    /// Check if the browser supports WebAuthn.
+    pub fn is_supported(&self) -> bool {
+        self.supported
+    }
+
+    /// Create a new credential, registering an authenticator.
+    pub fn create(&self, options: CreateCredentialOptions) {
+        self.run(move |signal| create(&options, signal));
+    }
+
+    /// Get a credential, authenticating the user.
+    pub fn get(&self, options: GetCredentialOptions) {
+        self.run(move |signal| get(&options, signal));
+    }
+
+    /// Abort the current operation.
+    pub fn abort(&self) {
+        if let Some(controller) = self.controller.borrow_mut().take() {
+            controller.abort();
+            self.state
+                .set(UseAsyncState::Ready(Err(WebAuthnError::Aborted)));
+        }
+    }
+
+    fn run<F>(&self, f: F)
+    where
+        F: FnOnce(&web_sys::AbortSignal) -> Result<js_sys::Promise, JsValue>,
+    {
+        if !self.supported {
+            self.state
+                .set(UseAsyncState::Ready(Err(WebAuthnError::Unsupported)));
+            return;
+        }
+
+        let Ok(controller) = web_sys::AbortController::new() else {
+            return;
+        };
+        let signal = controller.signal();
+        if let Some(previous) = self.controller.replace(Some(controller)) {
+            previous.abort();
+        }
+
+        let promise = match f(&signal) {
+            Ok(promise) => promise,
+            Err(err) => {
+                self.state.set(UseAsyncState::Ready(Err(err.into())));
+                return;
+            }
+        };
+
+        self.state.set(UseAsyncState::Processing);
+        let state = self.state.clone();
+        spawn_local(async move {
+            let result = JsFuture::from(promise).await;
+            if signal.aborted() {
+                // replaced by another operation, aborted, or unmounted
+                return;
+            }
+            state.set(UseAsyncState::Ready(
+                result
+                    .map(|credential| {
+                        credential
+                            .unchecked_into::<web_sys::PublicKeyCredential>()
+                            .into()
+                    })
+                    .map_err(WebAuthnError::from),
+            ));
+        });
+    }
+}
+
+impl PartialEq for UseWebAuthnHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && Rc::ptr_eq(&self.controller, &other.controller)
+    }
+}
+
+impl Deref for UseWebAuthnHandle {
+    type Target = UseAsyncState<WebAuthnCredential, WebAuthnError>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+/// Create and get credentials using WebAuthn.
+///
+/// Returns a handle for registering an authenticator, or authenticating a user, using the
+/// [Web Authentication API](https://developer.mozilla.org/en-US/docs/Web/API/Web_Authentication_API),
+/// which dereferences to the state of the current operation. The resulting credential must be sent
+/// to the server for verification.
+///
+/// Starting an operation aborts the previous one. An operation still running is aborted when the
+/// component is unmounted.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(PartialEq, Properties)]
+/// struct Props {
+///   challenge: Vec<u8>,
+/// }
+///
+/// #[function_component(Login)]
+/// fn login(props: &Props) -> Html {
+///   let webauthn = use_webauthn();
+///   let onclick = use_callback(
+///     (webauthn.clone(), props.challenge.clone()),
+///     |_, (webauthn, challenge)| webauthn.get(GetCredentialOptions::new(challenge.clone())),
+///   );
+///
+///   html!(
+///     <>
+///       <button {onclick} disabled={!webauthn.is_supported()}>{"Sign in with a passkey"}</button>
+///       if let Some(WebAuthnError::NotAllowed) = webauthn.error() {
+///         <p>{"Sign in was cancelled"}</p>
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_webauthn() -> UseWebAuthnHandle {
+    let state = use_state_eq(UseAsyncState::<WebAuthnCredential, WebAuthnError>::default);
+    let controller = use_mut_ref(|| None::<web_sys::AbortController>);
+    let supported = *use_memo((), |()| is_supported());
+
+    {
+        let controller = controller.clone();
+        use_effect_with((), move |()| {
+            move || {
+                if let Some(controller) = controller.borrow_mut().take() {
+                    controller.abort();
+                }
+            }
+        });
+    }
+
+    UseWebAuthnHandle {
+        state,
+        controller,
+        supported,
+    }
+}
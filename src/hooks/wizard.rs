@@ -0,0 +1,238 @@
+//! Hooks for multi-step flows
+
+use std::ops::Deref;
+use yew::prelude::*;
+
+/// The steps of a wizard, and which of them are complete.
+///
+/// Steps are counted from zero. A step can only be entered once all steps before it are complete.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Wizard {
+    current: usize,
+    completed: Vec<bool>,
+}
+
+impl Wizard {
+    /// Create a wizard of a number of steps, limiting the current step to the reachable steps.
+    pub fn new(current: usize, mut completed: Vec<bool>, steps: usize) -> Self {
+        completed.resize(steps, false);
+        let mut wizard = Self {
+            current: 0,
+            completed,
+        };
+        wizard.current = current.min(wizard.reachable());
+        wizard
+    }
+
+    /// The current step.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// The number of steps.
+    pub fn steps(&self) -> usize {
+        self.completed.len()
+    }
+
+    /// Check if the current step is the first one.
+    pub fn is_first(&self) -> bool {
+        self.current == 0
+    }
+
+    /// Check if the current step is the last one.
+    pub fn is_last(&self) -> bool {
+        self.current + 1 >= self.steps()
+    }
+
+    /// Check if a step is complete.
+    pub fn is_complete(&self, step: usize) -> bool {
+        self.completed.get(step).copied().unwrap_or_default()
+    }
+
+    /// Check if all steps are complete.
+    pub fn is_all_complete(&self) -> bool {
+        self.completed.iter().all(|completed| *completed)
+    }
+
+    /// The last step which can be entered.
+    fn reachable(&self) -> usize {
+        let reachable = self
+            .completed
+            .iter()
+            .position(|completed| !completed)
+            .unwrap_or(self.steps());
+        reachable.min(self.steps().saturating_sub(1))
+    }
+
+    /// Check if a step can be entered, as all steps before it are complete.
+    pub fn can_goto(&self, step: usize) -> bool {
+        step < self.steps() && step <= self.reachable()
+    }
+
+    /// Check if the next step can be entered.
+    pub fn can_next(&self) -> bool {
+        !self.is_last() && self.can_goto(self.current + 1)
+    }
+}
+
+/// State handle for the [`use_wizard`] hook.
+#[derive(Clone, PartialEq)]
+pub struct UseWizardHandle {
+    wizard: Wizard,
+    /// Stores a new current step and completion of the steps.
+    store: Callback<(usize, Vec<bool>)>,
+}
+
+impl UseWizardHandle {
+    fn store(&self, wizard: Wizard) {
+        if wizard != self.wizard {
+            self.store.emit((wizard.current, wizard.completed));
+        }
+    }
+
+    /// Enter a step, if all steps before it are complete.
+    pub fn goto(&self, step: usize) {
+        if self.can_goto(step) {
+            self.store(Wizard {
+                current: step,
+                ..self.wizard.clone()
+            });
+        }
+    }
+
+    /// Enter the next step, if the current step is complete.
+    pub fn next(&self) {
+        self.goto(self.current + 1);
+    }
+
+    /// Go back to the previous step.
+    pub fn back(&self) {
+        self.goto(self.current.saturating_sub(1));
+    }
+
+    /// Mark a step as complete, or incomplete.
+    ///
+    /// Marking a step as incomplete goes back to it, if the current step is after it.
+    pub fn set_complete(&self, step: usize, complete: bool) {
+        let mut completed = self.completed.clone();
+        if let Some(completed) = completed.get_mut(step) {
+            *completed = complete;
+        }
+        self.store(Wizard::new(self.current, completed, self.steps()));
+    }
+
+    /// Mark the current step as complete, or incomplete.
+    pub fn set_current_complete(&self, complete: bool) {
+        self.set_complete(self.current, complete);
+    }
+
+    /// Go back to the first step, with no step being complete.
+    pub fn reset(&self) {
+        self.store(Wizard::new(0, vec![], self.steps()));
+    }
+}
+
+impl Deref for UseWizardHandle {
+    type Target = Wizard;
+
+    fn deref(&self) -> &Self::Target {
+        &self.wizard
+    }
+}
+
+/// A wizard, guiding the user through a number of steps.
+///
+/// The handle dereferences to the current [`Wizard`] state. Steps are marked as complete by the
+/// components of the steps, like once their input is valid, which allows entering the next step.
+/// Going back is always possible.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Checkout)]
+/// fn checkout() -> Html {
+///   let wizard = use_wizard(3);
+///   let address = use_state_eq(String::new);
+///
+///   use_effect_with((wizard.clone(), address.is_empty()), |(wizard, empty)| {
+///     wizard.set_complete(0, !empty);
+///   });
+///
+///   let onback = use_callback(wizard.clone(), |_, wizard| wizard.back());
+///   let onnext = use_callback(wizard.clone(), |_, wizard| wizard.next());
+///
+///   html!(
+///     <>
+///       <ol>
+///         { for ["Address", "Payment", "Confirm"].into_iter().enumerate().map(|(step, title)| html!(
+///           <li class={classes!((step == wizard.current()).then_some("current"))}>{ title }</li>
+///         )) }
+///       </ol>
+///       <button onclick={onback} disabled={wizard.is_first()}>{"Back"}</button>
+///       <button onclick={onnext} disabled={!wizard.can_next()}>{"Next"}</button>
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_wizard(steps: usize) -> UseWizardHandle {
+    let state = use_state_eq(|| (0, vec![]));
+    let store = use_callback(state.setter(), |value, setter| setter.set(value));
+    let (current, completed) = (*state).clone();
+
+    UseWizardHandle {
+        wizard: Wizard::new(current, completed, steps),
+        store,
+    }
+}
+
+/// A wizard, keeping its progress in the state of the current page.
+///
+/// This works like [`use_wizard`], but stores the current step and the completed steps using
+/// [`use_page_state`](crate::hooks::use_page_state), so that reloading or navigating back to the
+/// page keeps the place of the user.
+#[cfg(feature = "page_state")]
+#[hook]
+pub fn use_wizard_with_page_state(steps: usize) -> UseWizardHandle {
+    let state = crate::hooks::use_page_state(|| (0usize, Vec::<bool>::new()));
+    let store = use_callback(state.clone(), |value, state| state.set(value));
+    let (current, completed) = (*state).clone();
+
+    UseWizardHandle {
+        wizard: Wizard::new(current, completed, steps),
+        store,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gates() {
+        let wizard = Wizard::new(2, vec![true], 3);
+        // the second step isn't complete, so the third can't be entered
+        assert_eq!(wizard.current(), 1);
+        assert!(wizard.can_goto(0));
+        assert!(!wizard.can_goto(2));
+        assert!(!wizard.can_next());
+
+        let wizard = Wizard::new(2, vec![true, true], 3);
+        assert_eq!(wizard.current(), 2);
+        assert!(wizard.is_last());
+        assert!(!wizard.can_next());
+        assert!(!wizard.is_all_complete());
+
+        let wizard = Wizard::new(0, vec![true; 5], 3);
+        assert_eq!(wizard.steps(), 3);
+        assert!(wizard.is_all_complete());
+        assert!(!wizard.can_goto(3));
+
+        let empty = Wizard::new(1, vec![], 0);
+        assert_eq!(empty.current(), 0);
+        assert!(!empty.can_goto(0));
+    }
+}
@@ -0,0 +1,501 @@
+//! Hooks for exchanging messages with web workers
+//!
+//! Messages are exchanged as JSON compatible JavaScript values, using `serde`.
+
+use gloo_events::EventListener;
+use gloo_utils::format::JsValueSerdeExt;
+use std::{
+    cell::RefCell,
+    fmt::{Display, Formatter},
+    marker::PhantomData,
+    ops::Deref,
+    rc::Rc,
+};
+use wasm_bindgen::{JsCast, JsValue};
+use yew::prelude::*;
+
+/// An error communicating with a worker.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WorkerError {
+    /// Creating the worker failed, e.g. because of an invalid URL.
+    Create(String),
+    /// Serializing a message failed.
+    Serialize(String),
+    /// Deserializing a received message failed.
+    Deserialize(String),
+    /// The worker reported an error.
+    Worker(String),
+}
+
+impl Display for WorkerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Create(msg) => write!(f, "Failed to create worker: {msg}"),
+            Self::Serialize(msg) => write!(f, "Failed to serialize message: {msg}"),
+            Self::Deserialize(msg) => write!(f, "Failed to deserialize message: {msg}"),
+            Self::Worker(msg) => write!(f, "Worker error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for WorkerError {}
+
+fn js_error(err: &JsValue) -> String {
+    if let Some(err) = err.dyn_ref::<web_sys::ErrorEvent>() {
+        return err.message();
+    }
+    if let Some(err) = err.dyn_ref::<js_sys::Error>() {
+        return err.message().into();
+    }
+    err.as_string().unwrap_or_else(|| format!("{err:?}"))
+}
+
+fn serialize<I>(message: &I) -> Result<JsValue, WorkerError>
+where
+    I: serde::Serialize,
+{
+    JsValue::from_serde(message).map_err(|err| WorkerError::Serialize(err.to_string()))
+}
+
+/// Receive messages, and errors, from targets, reporting them to the state.
+fn receive<O>(
+    target: &web_sys::EventTarget,
+    error_target: &web_sys::EventTarget,
+    response: UseStateHandle<Option<O>>,
+    error: UseStateHandle<Option<WorkerError>>,
+) -> Vec<EventListener>
+where
+    for<'de> O: serde::Deserialize<'de> + 'static,
+{
+    let onmessage = {
+        let error = error.clone();
+        EventListener::new(target, "message", move |event| {
+            let event = event.unchecked_ref::<web_sys::MessageEvent>();
+            match event.data().into_serde::<O>() {
+                Ok(data) => response.set(Some(data)),
+                Err(err) => error.set(Some(WorkerError::Deserialize(err.to_string()))),
+            }
+        })
+    };
+    let onmessageerror = {
+        let error = error.clone();
+        EventListener::new(target, "messageerror", move |_| {
+            error.set(Some(WorkerError::Deserialize(
+                "Message could not be cloned".into(),
+            )))
+        })
+    };
+    let onerror = EventListener::new(error_target, "error", move |event| {
+        error.set(Some(WorkerError::Worker(js_error(event))))
+    });
+
+    vec![onmessage, onmessageerror, onerror]
+}
+
+/// Options for [`use_web_worker_with_options`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UseWebWorkerOptions {
+    /// Load the script as an ES module, rather than a classic script.
+    pub module: bool,
+}
+
+/// State handle for the [`use_web_worker`] hook.
+pub struct UseWebWorkerHandle<I, O> {
+    worker: Rc<RefCell<Option<web_sys::Worker>>>,
+    response: UseStateHandle<Option<O>>,
+    error: UseStateHandle<Option<WorkerError>>,
+    _marker: PhantomData<I>,
+}
+
+impl<I, O> UseWebWorkerHandle<I, O>
+where
+    I: serde::Serialize,
+{
+    /// Post a message to the worker.
+    ///
+    /// Messages posted before the worker was started are dropped.
+    pub fn post(&self, message: &I) {
+        let Some(worker) = &*self.worker.borrow() else {
+            return;
+        };
+        let result = serialize(message).and_then(|message| {
+            worker
+                .post_message(&message)
+                .map_err(|err| WorkerError::Serialize(js_error(&err)))
+        });
+        if let Err(err) = result {
+            self.error.set(Some(err));
+        }
+    }
+
+    /// The last error, if any.
+    pub fn error(&self) -> Option<&WorkerError> {
+        self.error.as_ref()
+    }
+}
+
+impl<I, O> Clone for UseWebWorkerHandle<I, O> {
+    fn clone(&self) -> Self {
+        Self {
+            worker: self.worker.clone(),
+            response: self.response.clone(),
+            error: self.error.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<I, O> PartialEq for UseWebWorkerHandle<I, O>
+where
+    O: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.worker, &other.worker)
+            && self.response == other.response
+            && self.error == other.error
+    }
+}
+
+impl<I, O> Deref for UseWebWorkerHandle<I, O> {
+    type Target = Option<O>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.response
+    }
+}
+
+/// Run a web worker, exchanging messages with it.
+///
+/// This is [`use_web_worker_with_options`] using the default options.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(serde::Serialize)]
+/// struct Request { n: u64 }
+///
+/// #[derive(PartialEq, serde::Deserialize)]
+/// struct Response { result: u64 }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let worker = use_web_worker::<Request, Response>("/fibonacci.js");
+///   let onclick = use_callback(worker.clone(), |_, worker| worker.post(&Request { n: 42 }));
+///
+///   html!(
+///     <>
+///       <button {onclick}>{"Calculate"}</button>
+///       if let Some(response) = &*worker {
+///         <p>{ response.result }</p>
+///       }
+///     </>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_web_worker<I, O>(url: impl Into<AttrValue>) -> UseWebWorkerHandle<I, O>
+where
+    I: serde::Serialize + 'static,
+    for<'de> O: serde::Deserialize<'de> + 'static,
+{
+    use_web_worker_with_options(url, UseWebWorkerOptions::default())
+}
+
+/// Run a web worker, exchanging messages with it, using options.
+///
+/// Starts a [`Worker`](https://developer.mozilla.org/en-US/docs/Web/API/Worker) from the script
+/// at `url`, which is restarted when the URL or options change, and terminated when the component
+/// is unmounted. Messages are posted using the returned handle, which dereferences to the latest
+/// message received from the worker.
+///
+/// Errors, like failing to start the worker, or receiving a message which can't be deserialized,
+/// are reported through `error` of the handle.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let worker = use_web_worker_with_options::<String, String>(
+///     "/echo.js",
+///     UseWebWorkerOptions { module: true },
+///   );
+///
+///   html!(
+///     if let Some(err) = worker.error() {
+///       <p class="error">{ err.to_string() }</p>
+///     }
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_web_worker_with_options<I, O>(
+    url: impl Into<AttrValue>,
+    options: UseWebWorkerOptions,
+) -> UseWebWorkerHandle<I, O>
+where
+    I: serde::Serialize + 'static,
+    for<'de> O: serde::Deserialize<'de> + 'static,
+{
+    let worker = use_mut_ref(|| None::<web_sys::Worker>);
+    let response = use_state(|| None::<O>);
+    let error = use_state_eq(|| None::<WorkerError>);
+
+    {
+        let worker = worker.clone();
+        let response = response.clone();
+        let error = error.clone();
+        use_effect_with((url.into(), options), move |(url, options)| {
+            let worker_options = web_sys::WorkerOptions::new();
+            if options.module {
+                worker_options.set_type(web_sys::WorkerType::Module);
+            }
+
+            let listeners = match web_sys::Worker::new_with_options(url, &worker_options) {
+                Ok(created) => {
+                    let listeners = receive(&created, &created, response, error.clone());
+                    error.set(None);
+                    *worker.borrow_mut() = Some(created);
+                    listeners
+                }
+                Err(err) => {
+                    error.set(Some(WorkerError::Create(js_error(&err))));
+                    vec![]
+                }
+            };
+
+            move || {
+                drop(listeners);
+                if let Some(worker) = worker.borrow_mut().take() {
+                    worker.terminate();
+                }
+            }
+        });
+    }
+
+    UseWebWorkerHandle {
+        worker,
+        response,
+        error,
+        _marker: PhantomData,
+    }
+}
+
+/// Options for [`use_shared_worker_with_options`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UseSharedWorkerOptions {
+    /// The name of the worker. Workers with the same URL and name are shared.
+    pub name: Option<String>,
+    /// Load the script as an ES module, rather than a classic script.
+    pub module: bool,
+}
+
+/// State handle for the [`use_shared_worker`] hook.
+pub struct UseSharedWorkerHandle<I, O> {
+    port: Rc<RefCell<Option<web_sys::MessagePort>>>,
+    response: UseStateHandle<Option<O>>,
+    error: UseStateHandle<Option<WorkerError>>,
+    _marker: PhantomData<I>,
+}
+
+impl<I, O> UseSharedWorkerHandle<I, O>
+where
+    I: serde::Serialize,
+{
+    /// Post a message to the worker.
+    ///
+    /// Messages posted while not being connected are dropped.
+    pub fn post(&self, message: &I) {
+        let Some(port) = &*self.port.borrow() else {
+            return;
+        };
+        let result = serialize(message).and_then(|message| {
+            port.post_message(&message)
+                .map_err(|err| WorkerError::Serialize(js_error(&err)))
+        });
+        if let Err(err) = result {
+            self.error.set(Some(err));
+        }
+    }
+
+    /// Check if connected to the worker.
+    pub fn is_connected(&self) -> bool {
+        self.port.borrow().is_some()
+    }
+
+    /// The last error, if any.
+    pub fn error(&self) -> Option<&WorkerError> {
+        self.error.as_ref()
+    }
+}
+
+impl<I, O> Clone for UseSharedWorkerHandle<I, O> {
+    fn clone(&self) -> Self {
+        Self {
+            port: self.port.clone(),
+            response: self.response.clone(),
+            error: self.error.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<I, O> PartialEq for UseSharedWorkerHandle<I, O>
+where
+    O: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.port, &other.port)
+            && self.response == other.response
+            && self.error == other.error
+    }
+}
+
+impl<I, O> Deref for UseSharedWorkerHandle<I, O> {
+    type Target = Option<O>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.response
+    }
+}
+
+/// Connect to a shared worker, exchanging messages with it.
+///
+/// This is [`use_shared_worker_with_options`] using the default options.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[derive(serde::Serialize)]
+/// enum Request { Increment }
+///
+/// #[derive(PartialEq, serde::Deserialize)]
+/// struct Counter { value: u64 }
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let worker = use_shared_worker::<Request, Counter>("/counter.js");
+///   let onclick = use_callback(worker.clone(), |_, worker| worker.post(&Request::Increment));
+///
+///   html!(
+///     <button {onclick} disabled={!worker.is_connected()}>
+///       { format!("Shared across tabs: {}", worker.as_ref().map(|c| c.value).unwrap_or_default()) }
+///     </button>
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_shared_worker<I, O>(url: impl Into<AttrValue>) -> UseSharedWorkerHandle<I, O>
+where
+    I: serde::Serialize + 'static,
+    for<'de> O: serde::Deserialize<'de> + 'static,
+{
+    use_shared_worker_with_options(url, UseSharedWorkerOptions::default())
+}
+
+/// Connect to a shared worker, exchanging messages with it, using options.
+///
+/// Connects to a [`SharedWorker`](https://developer.mozilla.org/en-US/docs/Web/API/SharedWorker)
+/// running the script at `url`, which is shared by all components, tabs, and windows of the same
+/// origin using the same URL and name. Each connection gets its own port, which is closed when
+/// the URL or options change, or the component is unmounted. The worker keeps running as long as
+/// there are other connections to it.
+///
+/// Messages are posted using the returned handle, which dereferences to the latest message
+/// received through the port. Errors are reported through `error` of the handle.
+///
+/// ## Example
+///
+/// ```rust
+/// use yew::prelude::*;
+/// use yew_more_hooks::prelude::*;
+///
+/// #[function_component(Example)]
+/// fn example() -> Html {
+///   let worker = use_shared_worker_with_options::<String, String>(
+///     "/chat.js",
+///     UseSharedWorkerOptions {
+///       name: Some("chat".into()),
+///       ..Default::default()
+///     },
+///   );
+///
+///   html!(
+///     if let Some(message) = &*worker {
+///       <p>{ message }</p>
+///     }
+///   )
+/// }
+/// ```
+#[hook]
+pub fn use_shared_worker_with_options<I, O>(
+    url: impl Into<AttrValue>,
+    options: UseSharedWorkerOptions,
+) -> UseSharedWorkerHandle<I, O>
+where
+    I: serde::Serialize + 'static,
+    for<'de> O: serde::Deserialize<'de> + 'static,
+{
+    let port = use_mut_ref(|| None::<web_sys::MessagePort>);
+    let response = use_state(|| None::<O>);
+    let error = use_state_eq(|| None::<WorkerError>);
+    // trigger a render when the connection changes
+    let connected = use_state_eq(|| false);
+
+    {
+        let port = port.clone();
+        let response = response.clone();
+        let error = error.clone();
+        use_effect_with((url.into(), options), move |(url, options)| {
+            let worker_options = web_sys::WorkerOptions::new();
+            if let Some(name) = &options.name {
+                worker_options.set_name(name);
+            }
+            if options.module {
+                worker_options.set_type(web_sys::WorkerType::Module);
+            }
+
+            let listeners =
+                match web_sys::SharedWorker::new_with_worker_options(url, &worker_options) {
+                    Ok(worker) => {
+                        let created = worker.port();
+                        let listeners = receive(&created, &worker, response, error.clone());
+                        // required when not using `onmessage`
+                        created.start();
+                        error.set(None);
+                        *port.borrow_mut() = Some(created);
+                        connected.set(true);
+                        listeners
+                    }
+                    Err(err) => {
+                        error.set(Some(WorkerError::Create(js_error(&err))));
+                        vec![]
+                    }
+                };
+
+            move || {
+                drop(listeners);
+                if let Some(port) = port.borrow_mut().take() {
+                    port.close();
+                }
+                connected.set(false);
+            }
+        });
+    }
+
+    UseSharedWorkerHandle {
+        port,
+        response,
+        error,
+        _marker: PhantomData,
+    }
+}